@@ -674,20 +674,42 @@ impl Convert<MigrationInfo, Balance> for DivideBalanceByBlocks {
 }
 parameter_types! {
 	pub PolimecParaId: ParaId = 3344u32.into();
-	pub GenesisMoment: BlockNumber = 0u32;
 	pub const MaxMigrations: u128 = 100000;
 }
 
+impl pallet_linear_release::Config for Runtime {
+	type Balance = Balance;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkReason = BenchmarkReason;
+	type BlockNumberToBalance = ConvertInto;
+	type Currency = Balances;
+	type MinVestedTransfer = MinVestedTransfer;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type WeightInfo = ();
+
+	const MAX_VESTING_SCHEDULES: u32 = 100;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+parameter_types! {
+	pub BenchmarkReason: RuntimeHoldReason = RuntimeHoldReason::PolimecReceiver(polimec_receiver::HoldReason::Contribution);
+}
+
 impl polimec_receiver::Config for Runtime {
 	type Balance = Balance;
-	type Balances = Balances;
-	type GenesisMoment = GenesisMoment;
 	type MaxMigrations = MaxMigrations;
-	type MigrationInfoToPerBlockBalance = DivideBalanceByBlocks;
+	type MigrationReceiver = polimec_receiver::receivers::ReleaseScheduleReceiver<
+		LinearRelease,
+		RuntimeHoldReason,
+		polimec_receiver::receivers::ParticipationHoldReason<RuntimeHoldReason>,
+		DivideBalanceByBlocks,
+		System,
+	>;
 	type PolimecParaId = PolimecParaId;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeOrigin = RuntimeOrigin;
-	type Vesting = Vesting;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -722,6 +744,7 @@ construct_runtime!(
 		Assets: pallet_assets::<Instance1> = 50,
 		ForeignAssets: pallet_assets::<Instance2> = 51,
 		Vesting: pallet_vesting = 52,
+		LinearRelease: pallet_linear_release = 53,
 
 		PolimecReceiver: polimec_receiver = 69,
 
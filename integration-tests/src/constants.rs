@@ -525,6 +525,10 @@ pub mod polimec {
 					(usdc_asset_id, TreasuryAccount::get(), 0_0_010_000_000u128),
 				],
 			},
+			funding: polimec_runtime::FundingConfig {
+				funding_assets: polimec_runtime::xcm_config::InitialFundingAssets::get(),
+				..Default::default()
+			},
 			parachain_info: polimec_runtime::ParachainInfoConfig { parachain_id: PARA_ID.into(), ..Default::default() },
 			session: polimec_runtime::SessionConfig {
 				keys: collators::invulnerables()
@@ -64,6 +64,7 @@ decl_test_parachains! {
 			Balances: penpal_runtime::Balances,
 			ParachainSystem: penpal_runtime::ParachainSystem,
 			ParachainInfo: penpal_runtime::ParachainInfo,
+			LinearRelease: penpal_runtime::LinearRelease,
 		}
 	},
 	pub struct AssetHub {
@@ -138,6 +139,7 @@ pub mod shortcuts {
 	pub type PolimecFunding = <PolimecNet as PolimecParaPallet>::Funding;
 	pub type PolimecDispenser = <PolimecNet as PolimecParaPallet>::Dispenser;
 	pub type PolimecVesting = <PolimecNet as PolimecParaPallet>::Vesting;
+	pub type PenpalLinearRelease = <PenNet as PenpalParaPallet>::LinearRelease;
 
 	pub type PolkadotXcmPallet = <PolkaNet as PolkadotRelayRelayPallet>::XcmPallet;
 	pub type PenpalXcmPallet = <PenNet as PenpalParaPallet>::PolkadotXcm;
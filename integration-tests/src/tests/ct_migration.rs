@@ -15,13 +15,19 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{constants::PricesBuilder, *};
-use frame_support::traits::{fungible::Mutate, fungibles::Inspect};
+use frame_support::traits::{
+	fungible::{InspectHold, Mutate},
+	fungibles::Inspect,
+};
 use itertools::Itertools;
 use pallet_funding::{assert_close_enough, types::*, ProjectId, WeightInfo};
-use polimec_common::migration_types::{MigrationStatus, Migrations, ParticipationType};
+use polimec_common::{
+	migration_types::{MigrationStatus, Migrations, ParticipationType},
+	ReleaseSchedule,
+};
 use polimec_runtime::{Funding, RuntimeOrigin};
 use polkadot_service::chain_spec::get_account_id_from_seed;
-use sp_runtime::Perquintill;
+use sp_runtime::{traits::ConvertInto, Perquintill};
 use std::collections::HashMap;
 use tests::defaults::*;
 use xcm_executor::traits::WeightBounds;
@@ -88,6 +94,14 @@ fn send_migrations(project_id: ProjectId, accounts: Vec<AccountId>) {
 	}
 }
 
+fn penpal_hold_reason(participation_type: ParticipationType) -> penpal_runtime::RuntimeHoldReason {
+	penpal_runtime::RuntimeHoldReason::PolimecReceiver(match participation_type {
+		ParticipationType::Evaluation => polimec_receiver::HoldReason::Evaluation,
+		ParticipationType::Bid => polimec_receiver::HoldReason::Bid,
+		ParticipationType::Contribution => polimec_receiver::HoldReason::Contribution,
+	})
+}
+
 fn migrations_are_executed(project_id: ProjectId, accounts: Vec<AccountId>) {
 	let user_migrations = get_migrations_for_participants(project_id, accounts.clone());
 	for account in accounts.into_iter() {
@@ -95,21 +109,30 @@ fn migrations_are_executed(project_id: ProjectId, accounts: Vec<AccountId>) {
 		PenNet::execute_with(|| {
 			let (_, migrations) = user_migrations.get(&account).unwrap();
 
-			assert_close_enough!(user_info.free, migrations.total_ct_amount(), Perquintill::from_float(0.99));
-
-			let vest_scheduled_cts = migrations
-				.clone()
-				.inner()
-				.iter()
-				.filter_map(|migration| {
-					if migration.info.vesting_time > 1 {
-						Some(migration.info.contribution_token_amount)
-					} else {
-						None
-					}
-				})
-				.sum::<u128>();
-			assert_close_enough!(user_info.frozen, vest_scheduled_cts, Perquintill::from_float(0.99));
+			assert_close_enough!(
+				user_info.free + user_info.reserved,
+				migrations.total_ct_amount(),
+				Perquintill::from_float(0.99)
+			);
+
+			for participation_type in
+				[ParticipationType::Evaluation, ParticipationType::Bid, ParticipationType::Contribution]
+			{
+				let reason = penpal_hold_reason(participation_type);
+				let scheduled_cts = migrations
+					.clone()
+					.inner()
+					.iter()
+					.filter(|migration| migration.origin.participation_type == participation_type)
+					.map(|migration| migration.info.contribution_token_amount)
+					.sum::<u128>();
+
+				assert_eq!(PenpalBalances::balance_on_hold(&reason, &account), scheduled_cts);
+				assert_eq!(
+					<PenpalLinearRelease as ReleaseSchedule<_, _>>::total_scheduled_amount(&account, reason),
+					(scheduled_cts > 0).then_some(scheduled_cts)
+				);
+			}
 		});
 	}
 }
@@ -128,29 +151,33 @@ fn migrations_are_confirmed(project_id: u32, accounts: Vec<AccountId>) {
 	});
 }
 
-fn vest_migrations(project_id: u32, accounts: Vec<AccountId>) {
-	let user_migrations = get_migrations_for_participants(project_id, accounts.clone());
-	let biggest_time =
-		user_migrations.iter().map(|(_, (_, migrations))| migrations.biggest_vesting_time()).max().unwrap();
-
+fn vest_migrations(accounts: Vec<AccountId>) {
 	PenNet::execute_with(|| {
-		PenpalSystem::set_block_number(biggest_time as u32 + 1u32);
-	});
-	for account in accounts {
-		let user_info = PenNet::account_data_of(account.clone());
-		PenNet::execute_with(|| {
-			if user_info.frozen > 0 {
-				assert_ok!(pallet_vesting::Pallet::<PenpalRuntime>::vest(PenpalOrigin::signed(account)));
+		let last_ending_block = pallet_linear_release::Vesting::<PenpalRuntime>::iter_values()
+			.flatten()
+			.map(|schedule| schedule.ending_block_as_balance::<ConvertInto>())
+			.max()
+			.unwrap();
+		PenpalSystem::set_block_number(last_ending_block as u32);
+
+		for account in accounts {
+			for participation_type in
+				[ParticipationType::Evaluation, ParticipationType::Bid, ParticipationType::Contribution]
+			{
+				let reason = penpal_hold_reason(participation_type);
+				if PenpalBalances::balance_on_hold(&reason, &account) > 0 {
+					assert_ok!(PenpalLinearRelease::vest(PenpalOrigin::signed(account.clone()), reason));
+				}
 			}
-		});
-	}
+		}
+	});
 }
 
 fn migrations_are_vested(project_id: u32, accounts: Vec<AccountId>) {
 	let user_migrations = get_migrations_for_participants(project_id, accounts.clone());
 	user_migrations.iter().for_each(|(user, (_, migrations))| {
 		let user_info = PenNet::account_data_of(user.clone());
-		assert_eq!(user_info.frozen, 0);
+		assert_eq!(user_info.reserved, 0);
 		assert_eq!(user_info.free, migrations.clone().total_ct_amount());
 	});
 }
@@ -205,7 +232,7 @@ fn full_pallet_migration_test() {
 
 	migrations_are_confirmed(project_id, participants.clone());
 
-	vest_migrations(project_id, participants.clone());
+	vest_migrations(participants.clone());
 
 	migrations_are_vested(project_id, participants.clone());
 }
@@ -17,8 +17,8 @@ use crate::PolimecRuntime;
 use frame_support::BoundedVec;
 pub use pallet_funding::instantiator::{BidParams, ContributionParams, UserToUSDBalance};
 use pallet_funding::{
-	AcceptedFundingAsset, BiddingTicketSizes, ContributingTicketSizes, CurrencyMetadata, PriceProviderOf,
-	ProjectMetadata, ProjectMetadataOf, TicketSize,
+	AcceptedFundingAsset, BiddingTicketSizes, BucketCurve, ContributingTicketSizes, CurrencyMetadata, PriceKind,
+	PriceProviderOf, ProjectMetadata, ProjectMetadataOf, TicketSize,
 };
 use sp_arithmetic::{FixedPointNumber, Percent};
 
@@ -68,6 +68,9 @@ pub fn default_project_metadata(issuer: AccountId) -> ProjectMetadataOf<polimec_
 		mainnet_token_max_supply: 8_000_000 * CT_UNIT,
 		total_allocation_size: 1_000_000 * CT_UNIT,
 		auction_round_allocation_percentage: Percent::from_percent(50u8),
+		candle_auction_closing_phase: None,
+		bucket_curve: BucketCurve::default(),
+		price_kind: PriceKind::Spot,
 		minimum_price: PriceProviderOf::<PolimecRuntime>::calculate_decimals_aware_price(
 			sp_runtime::FixedU128::from_float(10.0),
 			USD_DECIMALS,
@@ -87,6 +90,7 @@ pub fn default_project_metadata(issuer: AccountId) -> ProjectMetadataOf<polimec_
 		},
 		participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 		funding_destination_account: issuer,
+		milestones: Default::default(),
 		policy_ipfs_cid: Some(ipfs_hash()),
 	}
 }
@@ -52,6 +52,9 @@ pub fn excel_project() -> ProjectMetadataOf<PolimecRuntime> {
 		// Total Allocation of Contribution Tokens Available for the Funding Round
 		total_allocation_size: 100_000 * CT_UNIT,
 		auction_round_allocation_percentage: Percent::from_percent(50u8),
+		candle_auction_closing_phase: None,
+		bucket_curve: BucketCurve::default(),
+		price_kind: PriceKind::Spot,
 
 		// Minimum Price per Contribution Token (in USDT)
 		minimum_price: PriceProviderOf::<PolimecRuntime>::calculate_decimals_aware_price(
@@ -73,6 +76,7 @@ pub fn excel_project() -> ProjectMetadataOf<PolimecRuntime> {
 		},
 		participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 		funding_destination_account: ISSUER.into(),
+		milestones: Default::default(),
 		policy_ipfs_cid: Some(metadata_hash),
 	}
 }
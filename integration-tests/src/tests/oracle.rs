@@ -107,6 +107,37 @@ fn data_is_correctly_combined() {
 	})
 }
 
+#[test]
+fn data_deviating_too_much_from_the_previous_value_is_rejected() {
+	let mut inst = IntegrationInstantiator::new(None);
+	PolimecNet::execute_with(|| {
+		let feeders = [ALICE, BOB, CHARLIE].map(PolimecNet::account_id_of);
+
+		inst.advance_time(1u32);
+		for feeder in feeders.clone() {
+			assert_ok!(Oracle::feed_values(RuntimeOrigin::signed(feeder), values([4.84, 1.0, 1.0, 0.4])));
+		}
+
+		// DOT moves 50%, USDC 1% and PLMC 25%. Only DOT goes beyond its maximum deviation.
+		inst.advance_time(1u32);
+		for feeder in feeders.clone() {
+			assert_ok!(Oracle::feed_values(RuntimeOrigin::signed(feeder), values([7.26, 1.01, 1.0, 0.5])));
+		}
+
+		let expected_values = HashMap::from([
+			(10u32, FixedU128::from_float(4.84)),
+			(1337u32, FixedU128::from_float(1.01)),
+			(1984u32, FixedU128::from_float(1.0)),
+			(3344u32, FixedU128::from_float(0.5)),
+		]);
+
+		for (key, value) in Oracle::get_all_values() {
+			assert!(value.is_some());
+			assert_eq!(expected_values.get(&key).unwrap(), &value.unwrap().value);
+		}
+	})
+}
+
 #[test]
 fn pallet_funding_works() {
 	let mut inst = IntegrationInstantiator::new(None);
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::*;
+use crate::{constants::PricesBuilder, *};
 use frame_support::{
 	traits::{
 		fungible::{Inspect as FungibleInspect, Unbalanced},
@@ -92,6 +92,8 @@ fn get_asset_hub_balances(asset_id: u32, user_account: AccountId, polimec_accoun
 /// Test the reserve based transfer from asset_hub to Polimec. Depending of the asset_id we
 /// transfer either USDT, USDC and DOT.
 fn test_reserve_to_polimec(asset_id: u32) {
+	// Polimec charges the execution fees in the transferred asset, converted from USD with its oracle price
+	polimec::set_prices(PricesBuilder::default());
 	create_asset_on_asset_hub(asset_id);
 	let asset_hub_asset_id: Location = match asset_id {
 		10 => Parent.into(),
@@ -1,4 +1,7 @@
-use crate::{PolimecAccountId, PolimecBalances, PolimecCall, PolimecForeignAssets, PolimecNet, PolimecRuntime, ALICE};
+use crate::{
+	constants::PricesBuilder, polimec, PolimecAccountId, PolimecBalances, PolimecCall, PolimecForeignAssets,
+	PolimecNet, PolimecRuntime, ALICE,
+};
 use parity_scale_codec::Encode;
 use polimec_runtime::{xcm_config::SupportedAssets, TreasuryAccount};
 use sp_runtime::traits::MaybeEquivalence;
@@ -10,6 +13,9 @@ pub fn fake_message_hash<T>(message: &Xcm<T>) -> XcmHash {
 
 #[test]
 fn execution_fees_go_to_treasury() {
+	// Execution paid with a funding asset is converted from USD with the oracle price of the asset
+	polimec::set_prices(PricesBuilder::default());
+
 	let dot_amount = Asset { id: AssetId(Location::parent()), fun: Fungible(100_0_000_000_000) };
 	let usdt_amount = Asset {
 		id: AssetId(Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)])),
@@ -144,6 +144,9 @@ pub fn genesis_config(genesis_config_params: GenesisConfigParams) -> serde_json:
 				(dot_id, funding_assets_owner.clone(), 1000000000000u64)
 			],
 		},
+		"funding": {
+			"fundingAssets": polimec_runtime::xcm_config::InitialFundingAssets::get()
+		},
 		"parachainStaking": {
 			"candidates": staking_candidates,
 			"inflationConfig": polimec_inflation_config(),
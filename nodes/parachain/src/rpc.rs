@@ -18,31 +18,45 @@
 
 #![warn(missing_docs)]
 
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
-use polimec_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use pallet_linear_release::runtime_api::LinearReleaseApi;
+use pallet_oracle_ocw::{
+	traits::PriceSources,
+	types::{is_valid_pair, AssetName, ParserKind, SourceConfig, SourceEndpoint, SourceName},
+	SOURCES_CONFIG_KEY,
+};
+use parity_scale_codec::{Decode, Encode};
+use parking_lot::RwLock;
+use polimec_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce, Runtime, RuntimeHoldReason};
+use serde::{Deserialize, Serialize};
 
 pub use sc_rpc::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_core::{offchain::OffchainStorage, Bytes};
+use sp_runtime::{traits::Block as BlockT, PerThing, Percent};
 
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
 
 /// Full client dependencies
-pub struct FullDeps<C, P> {
+pub struct FullDeps<C, P, S> {
 	/// The client instance to use.
 	pub client: Arc<C>,
 	/// Transaction pool instance.
 	pub pool: Arc<P>,
 	/// Whether to deny unsafe calls
 	pub deny_unsafe: DenyUnsafe,
+	/// The offchain local storage, if offchain workers are enabled.
+	pub offchain_storage: Option<S>,
 }
 
 /// Instantiate all RPC extensions.
-pub fn create_full<C, P>(deps: FullDeps<C, P>) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
+pub fn create_full<C, P, S>(deps: FullDeps<C, P, S>) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
 where
 	C: ProvideRuntimeApi<Block>
 		+ HeaderBackend<Block>
@@ -53,15 +67,338 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: LinearReleaseApi<Block, Runtime>,
 	P: TransactionPool + Sync + Send + 'static,
+	S: OffchainStorage + 'static,
 {
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
 	let mut module = RpcExtension::new(());
-	let FullDeps { client, pool, deny_unsafe } = deps;
+	let FullDeps { client, pool, deny_unsafe, offchain_storage } = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(LinearRelease::new(client).into_rpc())?;
+	if let Some(storage) = offchain_storage {
+		module.merge(OracleOcw::new(storage, deny_unsafe).into_rpc())?;
+	}
 	Ok(module)
 }
+
+/// A vesting schedule of `pallet-linear-release`, evaluated at a given block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VestingSchedule {
+	/// Amount locked at schedule creation.
+	pub total: Balance,
+	/// Amount released every block after `starting_block`.
+	pub per_block: Balance,
+	/// Block at which the release starts.
+	pub starting_block: BlockNumber,
+	/// First block at which nothing is left locked.
+	pub ending_block: BlockNumber,
+	/// Amount still locked at the evaluated block.
+	pub locked: Balance,
+	/// Amount released up to the evaluated block.
+	pub releasable: Balance,
+}
+
+/// All the vesting schedules of an account under one hold reason.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReasonSchedules {
+	/// SCALE-encoded `RuntimeHoldReason`.
+	pub reason: Bytes,
+	/// The schedules held under `reason`.
+	pub schedules: Vec<VestingSchedule>,
+}
+
+/// RPC methods of `pallet-linear-release`.
+#[rpc(client, server)]
+pub trait LinearReleaseRpcApi<BlockHash> {
+	/// Get every vesting schedule of `account`, evaluated at block number `at`.
+	/// `at` defaults to the block number of the queried block.
+	#[method(name = "linearRelease_vestingSchedules")]
+	fn vesting_schedules(
+		&self,
+		account: AccountId,
+		at: Option<BlockNumber>,
+		hash: Option<BlockHash>,
+	) -> RpcResult<Vec<ReasonSchedules>>;
+
+	/// Get the projected amount locked under the SCALE-encoded hold `reason`, as `(block, locked)` points
+	/// starting at block number `at`. The locked amount is linear between two consecutive points.
+	#[method(name = "linearRelease_unlockCurve")]
+	fn unlock_curve(
+		&self,
+		account: AccountId,
+		reason: Bytes,
+		at: Option<BlockNumber>,
+		hash: Option<BlockHash>,
+	) -> RpcResult<Vec<(BlockNumber, Balance)>>;
+}
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+/// Error code returned when the request parameters cannot be decoded.
+const DECODE_ERROR: i32 = 2;
+
+/// Implementation of [`LinearReleaseRpcApiServer`].
+pub struct LinearRelease<C> {
+	client: Arc<C>,
+}
+
+impl<C> LinearRelease<C> {
+	/// Create a new `LinearRelease` RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+
+	fn resolve(
+		&self,
+		hash: Option<<Block as BlockT>::Hash>,
+		at: Option<BlockNumber>,
+	) -> (<Block as BlockT>::Hash, BlockNumber)
+	where
+		C: HeaderBackend<Block>,
+	{
+		let info = self.client.info();
+		let hash = hash.unwrap_or(info.best_hash);
+		let number = self.client.number(hash).ok().flatten().unwrap_or(info.best_number);
+		(hash, at.unwrap_or(number))
+	}
+}
+
+fn runtime_error(err: impl std::fmt::Debug) -> ErrorObject<'static> {
+	ErrorObject::owned(RUNTIME_ERROR, "Unable to query vesting schedules.", Some(format!("{:?}", err)))
+}
+
+impl<C> LinearReleaseRpcApiServer<<Block as BlockT>::Hash> for LinearRelease<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: LinearReleaseApi<Block, Runtime>,
+{
+	fn vesting_schedules(
+		&self,
+		account: AccountId,
+		at: Option<BlockNumber>,
+		hash: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ReasonSchedules>> {
+		let (hash, at) = self.resolve(hash, at);
+		let schedules = self.client.runtime_api().vesting_schedules(hash, account, at).map_err(runtime_error)?;
+
+		Ok(schedules
+			.into_iter()
+			.map(|(reason, statuses)| ReasonSchedules {
+				reason: reason.encode().into(),
+				schedules: statuses
+					.into_iter()
+					.map(|status| VestingSchedule {
+						total: status.schedule.locked(),
+						per_block: status.schedule.per_block(),
+						starting_block: status.schedule.starting_block(),
+						ending_block: status.ending_block,
+						locked: status.locked,
+						releasable: status.releasable,
+					})
+					.collect(),
+			})
+			.collect())
+	}
+
+	fn unlock_curve(
+		&self,
+		account: AccountId,
+		reason: Bytes,
+		at: Option<BlockNumber>,
+		hash: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(BlockNumber, Balance)>> {
+		let reason = RuntimeHoldReason::decode(&mut &reason[..]).map_err(|e| {
+			ErrorObject::owned(DECODE_ERROR, "Unable to decode the hold reason.", Some(format!("{:?}", e)))
+		})?;
+		let (hash, at) = self.resolve(hash, at);
+		self.client.runtime_api().unlock_curve(hash, account, reason, at).map_err(runtime_error)
+	}
+}
+
+/// Settings of a price source of the oracle offchain worker.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceSourceSettings {
+	/// Whether prices are fetched from the source.
+	pub enabled: bool,
+	/// Percentage of the volume reported by the source counted when combining prices.
+	pub weight: u8,
+	/// Trading pair queried for each asset, e.g. `{ "DOT": "DOTUSD" }`.
+	pub pairs: BTreeMap<String, String>,
+	/// Where the candles are fetched from. Only set for sources that are not part of the runtime.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub endpoint: Option<PriceSourceEndpoint>,
+}
+
+/// Endpoint of a price source added by the node.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceSourceEndpoint {
+	/// Url of the candles endpoint, with `{pair}` in place of the trading pair,
+	/// e.g. `https://api.kraken.com/0/public/OHLC?pair={pair}&interval=1`.
+	pub url_template: String,
+	/// Format of the responses, named after the runtime source answering in it, e.g. `kraken`.
+	pub parser: String,
+}
+
+/// RPC methods configuring the price sources of `pallet-oracle-ocw`.
+#[rpc(server)]
+pub trait OracleOcwRpcApi {
+	/// Get the settings of every price source known to the runtime of the node, keyed by source name.
+	#[method(name = "oracleOcw_priceSources")]
+	fn price_sources(&self) -> RpcResult<BTreeMap<String, PriceSourceSettings>>;
+
+	/// Override the settings of the price source `name`, or add it if the runtime does not know it,
+	/// in which case the settings need an endpoint. Passing no settings restores the defaults of a
+	/// runtime source and removes any other. The change is picked up by the next offchain worker run.
+	#[method(name = "oracleOcw_setPriceSource")]
+	fn set_price_source(&self, name: String, settings: Option<PriceSourceSettings>) -> RpcResult<()>;
+}
+
+/// Error code returned when the price source settings are invalid.
+const INVALID_SETTINGS_ERROR: i32 = 3;
+
+fn invalid_settings(message: String) -> ErrorObject<'static> {
+	ErrorObject::owned(INVALID_SETTINGS_ERROR, "Invalid price source settings.", Some(message))
+}
+
+impl From<SourceConfig> for PriceSourceSettings {
+	fn from(config: SourceConfig) -> Self {
+		Self {
+			enabled: config.enabled,
+			weight: config.weight.deconstruct(),
+			pairs: config
+				.pairs
+				.into_iter()
+				.map(|(asset, pair)| (asset.ticker().to_string(), String::from_utf8_lossy(&pair).into_owned()))
+				.collect(),
+			endpoint: config.endpoint.map(|endpoint| PriceSourceEndpoint {
+				url_template: String::from_utf8_lossy(&endpoint.url_template).into_owned(),
+				parser: String::from_utf8_lossy(endpoint.parser.source_name()).into_owned(),
+			}),
+		}
+	}
+}
+
+impl TryFrom<PriceSourceEndpoint> for SourceEndpoint {
+	type Error = ErrorObject<'static>;
+
+	fn try_from(endpoint: PriceSourceEndpoint) -> Result<Self, Self::Error> {
+		if !endpoint.url_template.starts_with("https://") ||
+			!endpoint.url_template.contains(SourceEndpoint::PAIR_PLACEHOLDER)
+		{
+			return Err(invalid_settings(format!(
+				"url template {} is not an https url containing {}",
+				endpoint.url_template,
+				SourceEndpoint::PAIR_PLACEHOLDER
+			)));
+		}
+		let parser = ParserKind::from_source_name(endpoint.parser.as_bytes())
+			.ok_or_else(|| invalid_settings(format!("unknown parser {}", endpoint.parser)))?;
+		Ok(SourceEndpoint { url_template: endpoint.url_template.into_bytes(), parser })
+	}
+}
+
+impl TryFrom<PriceSourceSettings> for SourceConfig {
+	type Error = ErrorObject<'static>;
+
+	fn try_from(settings: PriceSourceSettings) -> Result<Self, Self::Error> {
+		if settings.weight > 100 {
+			return Err(invalid_settings(format!("weight {} is above 100", settings.weight)));
+		}
+		let pairs = settings
+			.pairs
+			.into_iter()
+			.map(|(name, pair)| {
+				let asset = AssetName::try_from_ticker(&name)
+					.ok_or_else(|| invalid_settings(format!("invalid asset ticker {}", name)))?;
+				if !is_valid_pair(&pair) {
+					return Err(invalid_settings(format!("invalid trading pair {:?} for {}", pair, name)));
+				}
+				Ok((asset, pair.into_bytes()))
+			})
+			.collect::<Result<_, _>>()?;
+		let endpoint = settings.endpoint.map(SourceEndpoint::try_from).transpose()?;
+		Ok(SourceConfig { enabled: settings.enabled, weight: Percent::from_percent(settings.weight), pairs, endpoint })
+	}
+}
+
+/// Implementation of [`OracleOcwRpcApiServer`].
+pub struct OracleOcw<S> {
+	storage: Arc<RwLock<S>>,
+	deny_unsafe: DenyUnsafe,
+}
+
+impl<S: OffchainStorage> OracleOcw<S> {
+	/// Create a new `OracleOcw` RPC handler.
+	pub fn new(storage: S, deny_unsafe: DenyUnsafe) -> Self {
+		Self { storage: Arc::new(RwLock::new(storage)), deny_unsafe }
+	}
+
+	fn stored_configs(storage: &S) -> BTreeMap<SourceName, SourceConfig> {
+		storage
+			.get(sp_offchain::STORAGE_PREFIX, SOURCES_CONFIG_KEY)
+			.and_then(|encoded| Decode::decode(&mut &encoded[..]).ok())
+			.unwrap_or_default()
+	}
+}
+
+impl<S: OffchainStorage + 'static> OracleOcwRpcApiServer for OracleOcw<S> {
+	fn price_sources(&self) -> RpcResult<BTreeMap<String, PriceSourceSettings>> {
+		let mut configs = <<Runtime as pallet_oracle_ocw::Config>::PriceSources as PriceSources>::default_configs();
+		for (name, config) in Self::stored_configs(&self.storage.read()) {
+			match configs.get_mut(&name) {
+				Some(default) => *default = SourceConfig { endpoint: None, ..config },
+				None if config.endpoint.is_some() => {
+					configs.insert(name, config);
+				},
+				None => (),
+			}
+		}
+		Ok(configs
+			.into_iter()
+			.map(|(name, config)| (String::from_utf8_lossy(&name).into_owned(), config.into()))
+			.collect())
+	}
+
+	fn set_price_source(&self, name: String, settings: Option<PriceSourceSettings>) -> RpcResult<()> {
+		self.deny_unsafe.check_if_safe()?;
+		let name = name.into_bytes();
+		let is_runtime_source =
+			<<Runtime as pallet_oracle_ocw::Config>::PriceSources as PriceSources>::default_configs()
+				.contains_key(&name);
+		let config = settings.map(SourceConfig::try_from).transpose()?;
+		match &config {
+			Some(config) if is_runtime_source && config.endpoint.is_some() => {
+				return Err(invalid_settings(format!(
+					"the endpoint of the runtime price source {} cannot be changed",
+					String::from_utf8_lossy(&name)
+				)));
+			},
+			Some(config) if !is_runtime_source && config.endpoint.is_none() => {
+				return Err(invalid_settings(format!(
+					"price source {} is not part of the runtime and needs an endpoint",
+					String::from_utf8_lossy(&name)
+				)));
+			},
+			_ => (),
+		}
+
+		// Hold the lock from reading to writing, so concurrent calls do not drop each other's changes
+		let mut storage = self.storage.write();
+		let mut configs = Self::stored_configs(&storage);
+		match config {
+			Some(config) => configs.insert(name, config),
+			None => configs.remove(&name),
+		};
+		storage.set(sp_offchain::STORAGE_PREFIX, SOURCES_CONFIG_KEY, &configs.encode());
+		Ok(())
+	}
+}
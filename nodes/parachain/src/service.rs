@@ -312,9 +312,15 @@ pub async fn start_parachain_node(
 	let rpc_builder = {
 		let client = client.clone();
 		let transaction_pool = transaction_pool.clone();
+		let backend = backend.clone();
 
 		Box::new(move |deny_unsafe, _| {
-			let deps = crate::rpc::FullDeps { client: client.clone(), pool: transaction_pool.clone(), deny_unsafe };
+			let deps = crate::rpc::FullDeps {
+				client: client.clone(),
+				pool: transaction_pool.clone(),
+				deny_unsafe,
+				offchain_storage: backend.offchain_storage(),
+			};
 
 			crate::rpc::create_full(deps).map_err(Into::into)
 		})
@@ -18,19 +18,46 @@
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 
+use crate::extensions::CheckDispenseRate;
 #[allow(unused)]
 use crate::Pallet as Dispenser;
 use frame_benchmarking::v2::*;
-use frame_support::traits::{EnsureOrigin, Get};
+use frame_support::{
+	dispatch::DispatchInfo,
+	traits::{EnsureOrigin, Get, IsSubType},
+};
 use frame_system::RawOrigin;
 use polimec_common_test_utils::{generate_did_from_account, get_mock_jwt_with_cid};
-use sp_runtime::traits::One;
+use sp_runtime::{
+	traits::{Dispatchable, Hash, One, SignedExtension, Zero},
+	Saturating,
+};
+use sp_std::vec;
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
-#[benchmarks]
+fn default_campaign<T: Config>() -> CampaignOf<T> {
+	DispenseCampaign {
+		policy: T::WhitelistedPolicy::get(),
+		investor_types: vec![InvestorType::Retail, InvestorType::Professional, InvestorType::Institutional]
+			.try_into()
+			.unwrap(),
+		amount: T::InitialDispenseAmount::get(),
+		lock_period: T::LockPeriod::get(),
+		vest_period: T::VestPeriod::get(),
+		start: Zero::zero(),
+		end: 100u32.into(),
+		budget: T::InitialDispenseAmount::get().saturating_mul(10u32.into()),
+	}
+}
+
+#[benchmarks(
+	where
+		T: Send + Sync,
+		<T as frame_system::Config>::RuntimeCall: Dispatchable<Info = DispatchInfo> + IsSubType<Call<T>> + From<Call<T>>,
+)]
 mod benchmarks {
 	use super::*;
 	#[benchmark]
@@ -70,5 +97,106 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn create_campaign() -> Result<(), BenchmarkError> {
+		let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let campaign = default_campaign::<T>();
+
+		#[extrinsic_call]
+		create_campaign(origin as T::RuntimeOrigin, campaign.clone());
+
+		assert_eq!(Campaigns::<T>::get(0), Some(campaign.clone()));
+		assert_last_event::<T>(Event::<T>::CampaignCreated { campaign_id: 0, campaign }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn close_campaign() -> Result<(), BenchmarkError> {
+		let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Campaigns::<T>::insert(0, default_campaign::<T>());
+
+		#[extrinsic_call]
+		close_campaign(origin as T::RuntimeOrigin, 0);
+
+		assert_eq!(Campaigns::<T>::get(0), None);
+		assert_eq!(ClaimsToClear::<T>::get(0), Some(ClaimsCursor::default()));
+		assert_last_event::<T>(Event::<T>::CampaignClosed { campaign_id: 0 }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clear_campaign_claims(c: Linear<1, 1_000>) {
+		let caller: T::AccountId = whitelisted_caller();
+		for account in 0..c {
+			CampaignClaims::<T>::insert(0, generate_did_from_account(account), ());
+		}
+		ClaimsToClear::<T>::insert(0, ClaimsCursor::default());
+
+		#[extrinsic_call]
+		clear_campaign_claims(RawOrigin::Signed(caller), 0, c);
+
+		assert_eq!(CampaignClaims::<T>::iter_prefix(0).count(), 0);
+		assert_eq!(ClaimsToClear::<T>::get(0), None);
+		assert_last_event::<T>(Event::<T>::CampaignClaimsCleared { campaign_id: 0, cleared: c, complete: true }.into());
+	}
+
+	#[benchmark]
+	fn dispense_from_campaign() {
+		let caller: T::AccountId = whitelisted_caller();
+		let did = generate_did_from_account(1);
+		let campaign = default_campaign::<T>();
+		Campaigns::<T>::insert(0, campaign.clone());
+		let _imbalance = CurrencyOf::<T>::deposit_creating(&Dispenser::<T>::dispense_account(), campaign.amount);
+
+		let jwt = get_mock_jwt_with_cid(caller.clone(), InvestorType::Retail, did.clone(), campaign.policy);
+		#[extrinsic_call]
+		dispense_from_campaign(RawOrigin::Signed(caller.clone()), jwt, 0);
+
+		assert_eq!(CampaignClaims::<T>::get(0, did.clone()), Some(()));
+		assert_eq!(CampaignDispensed::<T>::get(0), campaign.amount);
+		assert_last_event::<T>(
+			Event::<T>::CampaignDispensed {
+				campaign_id: 0,
+				dispensed_to_did: did,
+				dispensed_to: caller,
+				amount: campaign.amount,
+			}
+			.into(),
+		);
+	}
+
+	// Worst case: the window of the issuer holds as many slots as the limits allow, which are all read and rewritten.
+	#[benchmark]
+	fn check_dispense_rate() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let jwt = get_mock_jwt_with_cid(
+			caller.clone(),
+			InvestorType::Retail,
+			generate_did_from_account(1),
+			T::WhitelistedPolicy::get(),
+		);
+		let call: <T as frame_system::Config>::RuntimeCall = Call::<T>::dispense { jwt }.into();
+
+		// The issuer of the mock credentials.
+		let issuer = T::Hashing::hash(b"verifier");
+		let slot_length = Dispenser::<T>::dispense_rate_slot_length();
+		let filled_slots = DISPENSE_RATE_SLOTS.min(T::MaxDispensesPerIssuer::get()).saturating_sub(1);
+		for slot in 0..filled_slots {
+			frame_system::Pallet::<T>::set_block_number(slot_length.saturating_mul(slot.into()));
+			Dispenser::<T>::note_dispense(&issuer);
+		}
+		frame_system::Pallet::<T>::set_block_number(slot_length.saturating_mul(filled_slots.into()));
+
+		#[block]
+		{
+			CheckDispenseRate::<T>::new()
+				.pre_dispatch(&caller, &call, &DispatchInfo::default(), 0)
+				.map_err(|_| BenchmarkError::Stop("Dispense call was rate limited"))?;
+		}
+
+		assert_eq!(Dispenser::<T>::dispenses_by_issuer(&issuer), filled_slots + 1);
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Dispenser, crate::mock::ExtBuilder::default().build(), crate::mock::Test);
 }
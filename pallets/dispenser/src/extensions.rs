@@ -16,7 +16,7 @@
 
 // If you feel like getting in touch with us, you can do so at info@polimec.org
 
-use crate::{Call, Config};
+use crate::{weights::WeightInfo, Call, CampaignClaims, Config, Dispensed, EnsureOriginWithCredentials, Pallet};
 use frame_support::{
 	dispatch::{CheckIfFeeless, DispatchInfo},
 	pallet_prelude::*,
@@ -24,7 +24,10 @@ use frame_support::{
 };
 use parity_scale_codec::{Decode, Encode};
 use scale_info::{StaticTypeInfo, TypeInfo};
-use sp_runtime::traits::{DispatchInfoOf, Dispatchable, One, PostDispatchInfoOf, SignedExtension, Zero};
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, Hash, One, PostDispatchInfoOf, SignedExtension, Zero},
+	Perbill,
+};
 use sp_std::vec;
 /// Custom CheckNonce signed extension for Polimec Blockchain. Based on the CheckNonce signed extension from the FRAME.
 /// Removing the providers and sufficients checks for the `dispense` extrinsic, so a new account
@@ -85,7 +88,8 @@ where
 		let mut account = frame_system::Account::<T>::get(who);
 		if account.providers.is_zero() && account.sufficients.is_zero() {
 			match call.is_sub_type() {
-				Some(call) if matches!(call, &Call::<T>::dispense { .. }) => {},
+				Some(call)
+					if matches!(call, &Call::<T>::dispense { .. } | &Call::<T>::dispense_from_campaign { .. }) => {},
 				_ => return Err(InvalidTransaction::Payment.into()),
 			}
 		}
@@ -109,7 +113,8 @@ where
 		let account = frame_system::Account::<T>::get(who);
 		if account.providers.is_zero() && account.sufficients.is_zero() {
 			match call.is_sub_type() {
-				Some(call) if matches!(call, &Call::<T>::dispense { .. }) => {},
+				Some(call)
+					if matches!(call, &Call::<T>::dispense { .. } | &Call::<T>::dispense_from_campaign { .. }) => {},
 				_ => return Err(InvalidTransaction::Payment.into()),
 			}
 		}
@@ -219,3 +224,131 @@ where
 		Ok(())
 	}
 }
+
+/// Custom [`InvalidTransaction`] code of a dispense call whose credential issuer reached its cap for the
+/// current rate limit window.
+pub const ISSUER_RATE_LIMITED: u8 = 1;
+
+/// Custom [`InvalidTransaction`] code of a dispense call whose DID already received its tokens.
+pub const ALREADY_DISPENSED: u8 = 2;
+
+/// Rate limits the feeless dispense calls, both in the transaction pool and during block building.
+/// At most `MaxDispensesPerBlock` of them are included in a block, and at most `MaxDispensesPerIssuer`
+/// with credentials from the same issuer within a sliding `DispenseRateWindow`. The priority of a dispense call
+/// decays as the window of its issuer fills up.
+///
+/// The JWT is verified in `validate`, so transactions with forged, expired or someone else's credentials, and
+/// credentials whose DID already claimed, never reach a block nor count towards the limits.
+/// The weight of the checks done in `pre_dispatch` is added to the block weight.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckDispenseRate<T: Config>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> CheckDispenseRate<T> {
+	/// utility constructor. Used only in client/factory code.
+	pub fn new() -> Self {
+		Self(sp_std::marker::PhantomData)
+	}
+
+	/// Returns the hashed credential issuer of a dispense call signed by `who`, or `None` if it is not a
+	/// dispense call.
+	fn dispense_issuer(
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+	) -> Result<Option<T::Hash>, TransactionValidityError>
+	where
+		<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+	{
+		let (jwt, campaign_id) = match call.is_sub_type() {
+			Some(Call::<T>::dispense { jwt }) => (jwt, None),
+			Some(Call::<T>::dispense_from_campaign { jwt, campaign_id }) => (jwt, Some(*campaign_id)),
+			_ => return Ok(None),
+		};
+		// Same checks as the dispatch: the credential is valid, unexpired and issued to `who`.
+		let origin = <T as frame_system::Config>::RuntimeOrigin::signed(who.clone());
+		let (_, did, _, _) = T::InvestorOrigin::ensure_origin(origin, jwt, T::VerifierPublicKey::get())
+			.map_err(|_| InvalidTransaction::BadProof)?;
+		let already_dispensed = match campaign_id {
+			Some(campaign_id) => CampaignClaims::<T>::contains_key(campaign_id, &did),
+			None => Dispensed::<T>::contains_key(&did),
+		};
+		ensure!(!already_dispensed, InvalidTransaction::Custom(ALREADY_DISPENSED));
+
+		let token = T::InvestorOrigin::verify_token(jwt, T::VerifierPublicKey::get())
+			.map_err(|_| InvalidTransaction::BadProof)?;
+		Ok(Some(T::Hashing::hash(token.claims().custom.issuer.as_bytes())))
+	}
+
+	/// Checks the rate limits of a dispense call, and returns its decayed priority.
+	fn check_rate(issuer: &T::Hash) -> Result<TransactionPriority, TransactionValidityError> {
+		ensure!(
+			Pallet::<T>::dispenses_in_block() < T::MaxDispensesPerBlock::get(),
+			InvalidTransaction::ExhaustsResources
+		);
+
+		let cap = T::MaxDispensesPerIssuer::get();
+		let issuer_dispenses = Pallet::<T>::dispenses_by_issuer(issuer);
+		ensure!(issuer_dispenses < cap, InvalidTransaction::Custom(ISSUER_RATE_LIMITED));
+
+		Ok(Perbill::from_rational(cap - issuer_dispenses, cap) * T::DispensePriority::get())
+	}
+}
+
+impl<T: Config> Default for CheckDispenseRate<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config> sp_std::fmt::Debug for CheckDispenseRate<T> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckDispenseRate")
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckDispenseRate<T>
+where
+	<T as frame_system::Config>::RuntimeCall: Dispatchable<Info = DispatchInfo> + IsSubType<Call<T>>,
+{
+	type AccountId = T::AccountId;
+	type AdditionalSigned = ();
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type Pre = ();
+
+	const IDENTIFIER: &'static str = "CheckDispenseRate";
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<(), TransactionValidityError> {
+		if let Some(issuer) = Self::dispense_issuer(who, call)? {
+			Self::check_rate(&issuer)?;
+			Pallet::<T>::note_dispense(&issuer);
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::WeightInfo::check_dispense_rate(),
+				info.class,
+			);
+		}
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		match Self::dispense_issuer(who, call)? {
+			Some(issuer) => Ok(ValidTransaction { priority: Self::check_rate(&issuer)?, ..Default::default() }),
+			None => Ok(ValidTransaction::default()),
+		}
+	}
+}
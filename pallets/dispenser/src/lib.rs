@@ -28,9 +28,17 @@ pub use frame_support::traits::{
 	tokens::{currency::VestingSchedule, Balance},
 	Currency, ExistenceRequirement,
 };
-pub use polimec_common::credentials::{Cid, Did, EnsureOriginWithCredentials, InvestorType, UntrustedToken};
+pub use polimec_common::credentials::{
+	Cid, Did, EnsureOriginWithCredentials, InvestorType, SampleClaims, UntrustedToken,
+};
 pub use sp_runtime::traits::Convert;
 
+use frame_support::{traits::ConstU32, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
 pub mod extensions;
 
 #[cfg(test)]
@@ -47,18 +55,52 @@ mod benchmarking;
 pub type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type CurrencyOf<T> = <<T as Config>::VestingSchedule as VestingSchedule<AccountIdOf<T>>>::Currency;
+pub type CampaignId = u32;
+pub type CampaignOf<T> = DispenseCampaign<BalanceOf<T>, BlockNumberFor<T>>;
+/// Number of slots the `DispenseRateWindow` of each credential issuer is split into.
+/// The window slides forward one slot at a time.
+pub const DISPENSE_RATE_SLOTS: u32 = 10;
+pub type DispenseRateSlotsOf<T> = BoundedVec<(BlockNumberFor<T>, u32), ConstU32<DISPENSE_RATE_SLOTS>>;
+/// Length of the longest `CampaignClaims` storage key: the pallet and storage prefixes, the hashed
+/// campaign id and the hashed DID.
+pub const MAX_CLAIMS_CURSOR_LEN: u32 = 32 + 12 + 16 + 58;
+/// The storage key of the `CampaignClaims` entry from which clearing a closed campaign resumes.
+pub type ClaimsCursor = BoundedVec<u8, ConstU32<MAX_CLAIMS_CURSOR_LEN>>;
+
+/// A token drop created by the admin, with its own eligibility rules, vesting parameters and budget.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DispenseCampaign<Balance, BlockNumber> {
+	/// The policy that the users' credentials should have to be eligible for the campaign.
+	pub policy: Cid,
+	/// The investor types that can claim from the campaign.
+	pub investor_types: BoundedVec<InvestorType, ConstU32<3>>,
+	/// The amount of tokens dispensed to each DID.
+	pub amount: Balance,
+	/// The period of time that the dispensed funds are locked.
+	pub lock_period: BlockNumber,
+	/// The period of time that the dispensed funds are in a vesting schedule, starting after the lock period.
+	pub vest_period: BlockNumber,
+	/// The first block in which tokens can be claimed.
+	pub start: BlockNumber,
+	/// The block from which tokens can no longer be claimed.
+	pub end: BlockNumber,
+	/// The total amount of tokens the campaign can dispense.
+	pub budget: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	#[allow(clippy::wildcard_imports)]
 	use super::*;
 	use crate::weights::WeightInfo;
 	use frame_support::{
+		dispatch::PostDispatchInfo,
 		pallet_prelude::{ValueQuery, *},
 		PalletId,
 	};
 	use frame_system::pallet_prelude::*;
 	use sp_runtime::{
-		traits::{AccountIdConversion, CheckedDiv},
+		traits::{AccountIdConversion, CheckedDiv, One},
 		Saturating,
 	};
 
@@ -84,6 +126,7 @@ pub mod pallet {
 		type InvestorOrigin: EnsureOriginWithCredentials<
 			<Self as frame_system::Config>::RuntimeOrigin,
 			Success = (AccountIdOf<Self>, Did, InvestorType, Cid),
+			Claims = SampleClaims<AccountIdOf<Self>>,
 		>;
 
 		/// The period of time that the dispensed funds are locked. Used to calculate the
@@ -91,6 +134,24 @@ pub mod pallet {
 		#[pallet::constant]
 		type LockPeriod: Get<BlockNumberFor<Self>>;
 
+		/// The maximum number of dispense calls that can be included in a single block.
+		#[pallet::constant]
+		type MaxDispensesPerBlock: Get<u32>;
+
+		/// The maximum number of dispense calls with credentials from the same issuer within a
+		/// `DispenseRateWindow`.
+		#[pallet::constant]
+		type MaxDispensesPerIssuer: Get<u32>;
+
+		/// The length of the window in which the dispense calls of each credential issuer are counted.
+		#[pallet::constant]
+		type DispenseRateWindow: Get<BlockNumberFor<Self>>;
+
+		/// The transaction priority of a dispense call from an issuer with an empty window. It decays
+		/// linearly as the issuer's window fills up.
+		#[pallet::constant]
+		type DispensePriority: Get<TransactionPriority>;
+
 		/// The dispenser's pallet id, used for deriving its sovereign account ID.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -127,11 +188,61 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Dispensed<T> = StorageMap<_, Blake2_128Concat, Did, ()>;
 
+	#[pallet::storage]
+	pub type NextCampaignId<T> = StorageValue<_, CampaignId, ValueQuery>;
+
+	#[pallet::storage]
+	pub type Campaigns<T> = StorageMap<_, Twox64Concat, CampaignId, CampaignOf<T>>;
+
+	/// The amount of tokens dispensed so far by each campaign.
+	#[pallet::storage]
+	pub type CampaignDispensed<T> = StorageMap<_, Twox64Concat, CampaignId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	pub type CampaignClaims<T> = StorageDoubleMap<_, Twox64Concat, CampaignId, Blake2_128Concat, Did, ()>;
+
+	/// The closed campaigns whose claims are not cleared yet, with the cursor to resume clearing them from.
+	/// An empty cursor means that the clearing did not start.
+	#[pallet::storage]
+	pub type ClaimsToClear<T> = StorageMap<_, Twox64Concat, CampaignId, ClaimsCursor>;
+
+	/// The block of the last included dispense call, and the number of dispense calls included in it.
+	#[pallet::storage]
+	pub type DispensesInBlock<T> = StorageValue<_, (BlockNumberFor<T>, u32), ValueQuery>;
+
+	/// The dispense calls of each credential issuer (keyed by the hash of the issuer) within its sliding
+	/// rate limit window, counted per slot. Each entry holds the first block of a slot and its number of calls.
+	#[pallet::storage]
+	pub type IssuerDispenses<T: Config> =
+		StorageMap<_, Identity, <T as frame_system::Config>::Hash, DispenseRateSlotsOf<T>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		Dispensed { dispensed_to_did: Did, dispensed_to: T::AccountId, amount: BalanceOf<T> },
+		Dispensed {
+			dispensed_to_did: Did,
+			dispensed_to: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 		DispenseAmountChanged(BalanceOf<T>),
+		CampaignCreated {
+			campaign_id: CampaignId,
+			campaign: CampaignOf<T>,
+		},
+		CampaignClosed {
+			campaign_id: CampaignId,
+		},
+		CampaignClaimsCleared {
+			campaign_id: CampaignId,
+			cleared: u32,
+			complete: bool,
+		},
+		CampaignDispensed {
+			campaign_id: CampaignId,
+			dispensed_to_did: Did,
+			dispensed_to: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -144,6 +255,18 @@ pub mod pallet {
 		DispenseAmountTooLow,
 		/// The origin does not have the required credentials.
 		InvalidCredential,
+		/// The campaign does not exist or was closed.
+		CampaignNotFound,
+		/// The campaign's start and end blocks, budget or investor types are inconsistent.
+		InvalidCampaign,
+		/// The current block is outside the campaign's claiming period.
+		CampaignNotActive,
+		/// The campaign does not allow the investor type of the credential.
+		InvestorTypeNotAllowed,
+		/// The campaign has not enough budget left to dispense its amount once more.
+		CampaignBudgetExhausted,
+		/// The campaign is not closed, or its claims were already cleared.
+		NoClaimsToClear,
 	}
 
 	#[pallet::call]
@@ -164,29 +287,7 @@ pub mod pallet {
 			ensure!(whitelisted_policy == T::WhitelistedPolicy::get(), Error::<T>::InvalidCredential);
 
 			let amount = DispenseAmount::<T>::get();
-			ensure!(CurrencyOf::<T>::free_balance(&Self::dispense_account()) >= amount, Error::<T>::DispenserDepleted);
-
-			let current_block = <frame_system::Pallet<T>>::block_number();
-			let length_as_balance = T::BlockNumberToBalance::convert(T::VestPeriod::get());
-			let locked_amount = amount.saturating_sub(T::FreeDispenseAmount::get());
-			let per_block = locked_amount
-				.checked_div(&length_as_balance.max(sp_runtime::traits::One::one()))
-				.ok_or(DispatchError::Arithmetic(sp_runtime::ArithmeticError::Underflow))?;
-
-			T::VestingSchedule::can_add_vesting_schedule(
-				&who,
-				locked_amount,
-				per_block,
-				current_block + T::LockPeriod::get(),
-			)?;
-
-			<CurrencyOf<T>>::transfer(&Self::dispense_account(), &who, amount, ExistenceRequirement::AllowDeath)?;
-			T::VestingSchedule::add_vesting_schedule(
-				&who,
-				locked_amount,
-				per_block,
-				current_block + T::LockPeriod::get(),
-			)?;
+			Self::do_dispense(&who, amount, T::LockPeriod::get(), T::VestPeriod::get())?;
 
 			Dispensed::<T>::insert(did.clone(), ());
 			Self::deposit_event(Event::Dispensed { dispensed_to_did: did, dispensed_to: who, amount });
@@ -203,11 +304,200 @@ pub mod pallet {
 			Self::deposit_event(Event::DispenseAmountChanged(amount));
 			Ok(Pays::No.into())
 		}
+
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::create_campaign())]
+		pub fn create_campaign(origin: OriginFor<T>, campaign: CampaignOf<T>) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(campaign.amount > T::FreeDispenseAmount::get(), Error::<T>::DispenseAmountTooLow);
+			ensure!(
+				campaign.start < campaign.end &&
+					campaign.budget >= campaign.amount &&
+					!campaign.investor_types.is_empty(),
+				Error::<T>::InvalidCampaign
+			);
+
+			let campaign_id = NextCampaignId::<T>::get();
+			Campaigns::<T>::insert(campaign_id, campaign.clone());
+			NextCampaignId::<T>::put(campaign_id.saturating_add(1));
+			Self::deposit_event(Event::CampaignCreated { campaign_id, campaign });
+			Ok(Pays::No.into())
+		}
+
+		/// Stops a campaign from dispensing any more tokens. Its remaining budget stays in the dispenser account.
+		/// Its claims are left to be removed with `clear_campaign_claims`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::close_campaign())]
+		pub fn close_campaign(origin: OriginFor<T>, campaign_id: CampaignId) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(Campaigns::<T>::contains_key(campaign_id), Error::<T>::CampaignNotFound);
+			Campaigns::<T>::remove(campaign_id);
+			CampaignDispensed::<T>::remove(campaign_id);
+			ClaimsToClear::<T>::insert(campaign_id, ClaimsCursor::default());
+			Self::deposit_event(Event::CampaignClosed { campaign_id });
+			Ok(Pays::No.into())
+		}
+
+		#[pallet::feeless_if( | origin: &OriginFor<T>, jwt: &UntrustedToken, campaign_id: &CampaignId | -> bool {
+            if let Ok((_, did, investor_type, policy)) = T::InvestorOrigin::ensure_origin(origin.clone(), jwt, T::VerifierPublicKey::get()) {
+                Self::claimable_campaign(*campaign_id, &did, &investor_type, &policy).is_ok()
+            } else {
+                false
+            }
+        })]
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::dispense_from_campaign())]
+		pub fn dispense_from_campaign(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			campaign_id: CampaignId,
+		) -> DispatchResultWithPostInfo {
+			let (who, did, investor_type, policy) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			let campaign = Self::claimable_campaign(campaign_id, &did, &investor_type, &policy)?;
+
+			Self::do_dispense(&who, campaign.amount, campaign.lock_period, campaign.vest_period)?;
+
+			CampaignClaims::<T>::insert(campaign_id, did.clone(), ());
+			CampaignDispensed::<T>::mutate(campaign_id, |dispensed| {
+				*dispensed = dispensed.saturating_add(campaign.amount)
+			});
+			Self::deposit_event(Event::CampaignDispensed {
+				campaign_id,
+				dispensed_to_did: did,
+				dispensed_to: who,
+				amount: campaign.amount,
+			});
+
+			Ok(Pays::No.into())
+		}
+
+		/// Removes up to `limit` claims of a closed campaign, resuming from where the previous call stopped.
+		/// Free of charge if any claim was removed.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::clear_campaign_claims(*limit))]
+		pub fn clear_campaign_claims(
+			origin: OriginFor<T>,
+			campaign_id: CampaignId,
+			limit: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let cursor = ClaimsToClear::<T>::get(campaign_id).ok_or(Error::<T>::NoClaimsToClear)?;
+			let maybe_cursor = if cursor.is_empty() { None } else { Some(cursor.as_slice()) };
+
+			let result = CampaignClaims::<T>::clear_prefix(campaign_id, limit, maybe_cursor);
+			let complete = result.maybe_cursor.is_none();
+			match result.maybe_cursor {
+				// A cursor that doesn't fit is dropped. Clearing then restarts from the first claim left, which
+				// only works from the next block on.
+				Some(cursor) => {
+					ClaimsToClear::<T>::insert(campaign_id, ClaimsCursor::try_from(cursor).unwrap_or_default())
+				},
+				None => ClaimsToClear::<T>::remove(campaign_id),
+			}
+			Self::deposit_event(Event::CampaignClaimsCleared { campaign_id, cleared: result.unique, complete });
+
+			let pays_fee = if result.unique == 0 { Pays::Yes } else { Pays::No };
+			Ok(PostDispatchInfo { actual_weight: Some(T::WeightInfo::clear_campaign_claims(result.loops)), pays_fee })
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		pub fn dispense_account() -> T::AccountId {
 			T::PalletId::get().into_account_truncating()
 		}
+
+		/// Returns the campaign if the DID can claim from it with a credential of the given investor type and policy.
+		pub fn claimable_campaign(
+			campaign_id: CampaignId,
+			did: &Did,
+			investor_type: &InvestorType,
+			policy: &Cid,
+		) -> Result<CampaignOf<T>, DispatchError> {
+			let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(campaign.start <= now && now < campaign.end, Error::<T>::CampaignNotActive);
+			ensure!(*policy == campaign.policy, Error::<T>::InvalidCredential);
+			ensure!(campaign.investor_types.contains(investor_type), Error::<T>::InvestorTypeNotAllowed);
+			ensure!(!CampaignClaims::<T>::contains_key(campaign_id, did), Error::<T>::DispensedAlreadyToDid);
+			let dispensed = CampaignDispensed::<T>::get(campaign_id);
+			ensure!(dispensed.saturating_add(campaign.amount) <= campaign.budget, Error::<T>::CampaignBudgetExhausted);
+			Ok(campaign)
+		}
+
+		/// The number of dispense calls included in the current block.
+		pub fn dispenses_in_block() -> u32 {
+			let (block, count) = DispensesInBlock::<T>::get();
+			if block == <frame_system::Pallet<T>>::block_number() {
+				count
+			} else {
+				0
+			}
+		}
+
+		/// The number of dispense calls included in the rate limit window of `issuer` that ends with the current block.
+		pub fn dispenses_by_issuer(issuer: &T::Hash) -> u32 {
+			let now = <frame_system::Pallet<T>>::block_number();
+			IssuerDispenses::<T>::get(issuer)
+				.iter()
+				.filter(|(slot_start, _)| Self::is_slot_in_window(*slot_start, now))
+				.fold(0u32, |total, (_, count)| total.saturating_add(*count))
+		}
+
+		/// Counts a dispense call with credentials of `issuer` towards the rate limits. Slots that left the
+		/// window are dropped.
+		pub(crate) fn note_dispense(issuer: &T::Hash) {
+			let now = <frame_system::Pallet<T>>::block_number();
+			DispensesInBlock::<T>::put((now, Self::dispenses_in_block().saturating_add(1)));
+
+			let slot_length = Self::dispense_rate_slot_length();
+			let current_slot = now.saturating_sub(now % slot_length);
+			IssuerDispenses::<T>::mutate(issuer, |slots| {
+				slots.retain(|(slot_start, _)| Self::is_slot_in_window(*slot_start, now));
+				match slots.last_mut() {
+					Some((slot_start, count)) if *slot_start == current_slot => *count = count.saturating_add(1),
+					// Can't fail, since at most `DISPENSE_RATE_SLOTS` slots fit in a window.
+					_ => {
+						let _ = slots.try_push((current_slot, 1));
+					},
+				}
+			});
+		}
+
+		/// The number of blocks in each slot of the rate limit window. Rounded up, so the window never holds more
+		/// than `DISPENSE_RATE_SLOTS` slots.
+		pub fn dispense_rate_slot_length() -> BlockNumberFor<T> {
+			let slots: BlockNumberFor<T> = DISPENSE_RATE_SLOTS.into();
+			let slot_length = T::DispenseRateWindow::get().saturating_add(slots.saturating_sub(One::one())) / slots;
+			slot_length.max(One::one())
+		}
+
+		fn is_slot_in_window(slot_start: BlockNumberFor<T>, now: BlockNumberFor<T>) -> bool {
+			now < slot_start.saturating_add(T::DispenseRateWindow::get())
+		}
+
+		/// Transfers `amount` from the dispenser account to `who`, and vests all of it but the free dispense amount.
+		fn do_dispense(
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+			lock_period: BlockNumberFor<T>,
+			vest_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			ensure!(CurrencyOf::<T>::free_balance(&Self::dispense_account()) >= amount, Error::<T>::DispenserDepleted);
+
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			let length_as_balance = T::BlockNumberToBalance::convert(vest_period);
+			let locked_amount = amount.saturating_sub(T::FreeDispenseAmount::get());
+			let per_block = locked_amount
+				.checked_div(&length_as_balance.max(sp_runtime::traits::One::one()))
+				.ok_or(DispatchError::Arithmetic(sp_runtime::ArithmeticError::Underflow))?;
+
+			T::VestingSchedule::can_add_vesting_schedule(who, locked_amount, per_block, current_block + lock_period)?;
+
+			<CurrencyOf<T>>::transfer(&Self::dispense_account(), who, amount, ExistenceRequirement::AllowDeath)?;
+			T::VestingSchedule::add_vesting_schedule(who, locked_amount, per_block, current_block + lock_period)?;
+
+			Ok(())
+		}
 	}
 }
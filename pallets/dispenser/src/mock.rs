@@ -82,6 +82,10 @@ parameter_types! {
 		195, 18, 202, 111, 55, 39, 48, 123, 17, 101, 78, 215, 94,
 	];
 	pub WhitelistedPolicy: Cid = generate_cid_from_string(IPFS_CID);
+	pub const MaxDispensesPerBlock: u32 = 2;
+	pub const MaxDispensesPerIssuer: u32 = 4;
+	pub const DispenseRateWindow: u64 = 10;
+	pub const DispensePriority: u64 = 1_000;
 }
 
 ord_parameter_types! {
@@ -91,10 +95,14 @@ ord_parameter_types! {
 impl crate::Config for Test {
 	type AdminOrigin = EnsureSignedBy<Admin, AccountId>;
 	type BlockNumberToBalance = ConvertInto;
+	type DispensePriority = DispensePriority;
+	type DispenseRateWindow = DispenseRateWindow;
 	type FreeDispenseAmount = FreeDispenseAmount;
 	type InitialDispenseAmount = InitialDispenseAmount;
 	type InvestorOrigin = EnsureInvestor<Test>;
 	type LockPeriod = LockPeriod;
+	type MaxDispensesPerBlock = MaxDispensesPerBlock;
+	type MaxDispensesPerIssuer = MaxDispensesPerIssuer;
 	type PalletId = DispenserPalletId;
 	type RuntimeEvent = RuntimeEvent;
 	type VerifierPublicKey = VerifierPublicKey;
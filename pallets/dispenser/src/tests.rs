@@ -19,7 +19,7 @@
 use super::*;
 use crate as pallet_dispenser;
 use crate::mock::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, dispatch::Pays};
 use polimec_common::credentials::InvestorType;
 use polimec_common_test_utils::{generate_cid_from_string, generate_did_from_account, get_mock_jwt_with_cid};
 use sp_runtime::DispatchError;
@@ -175,3 +175,405 @@ mod dispense {
 		});
 	}
 }
+
+mod campaigns {
+	use super::*;
+
+	const PARTNER_CID: &str = "QmPartnerKycProviderPolicy1111111111111111111111";
+
+	pub(super) fn partner_campaign() -> CampaignOf<Test> {
+		DispenseCampaign {
+			policy: generate_cid_from_string(PARTNER_CID),
+			investor_types: vec![InvestorType::Retail].try_into().unwrap(),
+			amount: 45,
+			lock_period: 5,
+			vest_period: 20,
+			start: 1,
+			end: 11,
+			budget: 90,
+		}
+	}
+
+	pub(super) fn partner_jwt(account: u64, investor_type: InvestorType) -> UntrustedToken {
+		get_mock_jwt_with_cid(
+			account,
+			investor_type,
+			generate_did_from_account(account),
+			generate_cid_from_string(PARTNER_CID),
+		)
+	}
+
+	#[test]
+	fn only_admin_can_create_and_close_campaigns() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(
+				Dispenser::create_campaign(RuntimeOrigin::signed(1), partner_campaign()),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), partner_campaign()));
+			assert_ok!(Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), partner_campaign()));
+			assert_eq!(Campaigns::<Test>::get(0), Some(partner_campaign()));
+			assert_eq!(Campaigns::<Test>::get(1), Some(partner_campaign()));
+			assert_eq!(NextCampaignId::<Test>::get(), 2);
+
+			assert_noop!(Dispenser::close_campaign(RuntimeOrigin::signed(1), 0), DispatchError::BadOrigin);
+			assert_ok!(Dispenser::close_campaign(RuntimeOrigin::signed(Admin::get()), 0));
+			assert_eq!(Campaigns::<Test>::get(0), None);
+			assert_noop!(
+				Dispenser::close_campaign(RuntimeOrigin::signed(Admin::get()), 0),
+				Error::<Test>::CampaignNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn inconsistent_campaigns_are_rejected() {
+		ExtBuilder::default().build().execute_with(|| {
+			let admin = RuntimeOrigin::signed(Admin::get());
+			let free_amount = <Test as pallet_dispenser::Config>::FreeDispenseAmount::get();
+
+			let mut campaign = partner_campaign();
+			campaign.amount = free_amount;
+			assert_noop!(Dispenser::create_campaign(admin.clone(), campaign), Error::<Test>::DispenseAmountTooLow);
+
+			let mut campaign = partner_campaign();
+			campaign.end = campaign.start;
+			assert_noop!(Dispenser::create_campaign(admin.clone(), campaign), Error::<Test>::InvalidCampaign);
+
+			let mut campaign = partner_campaign();
+			campaign.budget = campaign.amount - 1;
+			assert_noop!(Dispenser::create_campaign(admin.clone(), campaign), Error::<Test>::InvalidCampaign);
+
+			let mut campaign = partner_campaign();
+			campaign.investor_types = Default::default();
+			assert_noop!(Dispenser::create_campaign(admin, campaign), Error::<Test>::InvalidCampaign);
+		});
+	}
+
+	#[test]
+	fn campaign_dispenses_its_own_amount_and_vesting() {
+		ExtBuilder::default().dispense_account(2).build().execute_with(|| {
+			assert_ok!(Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), partner_campaign()));
+			assert_ok!(Dispenser::dispense_from_campaign(
+				RuntimeOrigin::signed(1),
+				partner_jwt(1, InvestorType::Retail),
+				0
+			));
+
+			let free_amount = <Test as pallet_dispenser::Config>::FreeDispenseAmount::get();
+			assert_eq!(Balances::free_balance(1), 45);
+			assert_eq!(Balances::usable_balance(1), free_amount);
+			assert_eq!(Vesting::vesting_balance(&1), Some(45 - free_amount));
+			assert_eq!(CampaignDispensed::<Test>::get(0), 45);
+			assert_eq!(CampaignClaims::<Test>::get(0, generate_did_from_account(1)), Some(()));
+
+			// Nothing unlocks during the campaign's lock period, and everything is unlocked after its vest period.
+			System::set_block_number(1 + 5);
+			assert_eq!(Vesting::vesting_balance(&1), Some(45 - free_amount));
+			System::set_block_number(1 + 5 + 20);
+			assert_eq!(Vesting::vesting_balance(&1), Some(0));
+		});
+	}
+
+	#[test]
+	fn claims_are_tracked_per_campaign() {
+		ExtBuilder::default().dispense_account(2).build().execute_with(|| {
+			assert_ok!(Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), partner_campaign()));
+			assert_ok!(Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), partner_campaign()));
+			assert_ok!(Dispenser::dispense_from_campaign(
+				RuntimeOrigin::signed(1),
+				partner_jwt(1, InvestorType::Retail),
+				0
+			));
+			assert_noop!(
+				Dispenser::dispense_from_campaign(RuntimeOrigin::signed(1), partner_jwt(1, InvestorType::Retail), 0),
+				Error::<Test>::DispensedAlreadyToDid
+			);
+			assert_ok!(Dispenser::dispense_from_campaign(
+				RuntimeOrigin::signed(1),
+				partner_jwt(1, InvestorType::Retail),
+				1
+			));
+
+			// Campaign claims are independent from the default dispense.
+			let jwt =
+				get_mock_jwt_with_cid(1, InvestorType::Retail, generate_did_from_account(1), WhitelistedPolicy::get());
+			assert_ok!(Dispenser::dispense(RuntimeOrigin::signed(1), jwt));
+		});
+	}
+
+	#[test]
+	fn credentials_must_match_the_campaign() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), partner_campaign()));
+
+			let jwt =
+				get_mock_jwt_with_cid(1, InvestorType::Retail, generate_did_from_account(1), WhitelistedPolicy::get());
+			assert_noop!(
+				Dispenser::dispense_from_campaign(RuntimeOrigin::signed(1), jwt, 0),
+				Error::<Test>::InvalidCredential
+			);
+			assert_noop!(
+				Dispenser::dispense_from_campaign(
+					RuntimeOrigin::signed(1),
+					partner_jwt(1, InvestorType::Institutional),
+					0
+				),
+				Error::<Test>::InvestorTypeNotAllowed
+			);
+			assert_noop!(
+				Dispenser::dispense_from_campaign(RuntimeOrigin::signed(1), partner_jwt(1, InvestorType::Retail), 1),
+				Error::<Test>::CampaignNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn campaigns_can_only_be_claimed_between_start_and_end() {
+		ExtBuilder::default().build().execute_with(|| {
+			let mut campaign = partner_campaign();
+			campaign.start = 5;
+			assert_ok!(Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), campaign.clone()));
+
+			assert_noop!(
+				Dispenser::dispense_from_campaign(RuntimeOrigin::signed(1), partner_jwt(1, InvestorType::Retail), 0),
+				Error::<Test>::CampaignNotActive
+			);
+			System::set_block_number(campaign.end);
+			assert_noop!(
+				Dispenser::dispense_from_campaign(RuntimeOrigin::signed(1), partner_jwt(1, InvestorType::Retail), 0),
+				Error::<Test>::CampaignNotActive
+			);
+			System::set_block_number(campaign.end - 1);
+			assert_ok!(Dispenser::dispense_from_campaign(
+				RuntimeOrigin::signed(1),
+				partner_jwt(1, InvestorType::Retail),
+				0
+			));
+		});
+	}
+
+	#[test]
+	fn campaign_stops_dispensing_when_budget_is_spent() {
+		ExtBuilder::default().dispense_account(10).build().execute_with(|| {
+			assert_ok!(Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), partner_campaign()));
+			for account in 1..=2 {
+				assert_ok!(Dispenser::dispense_from_campaign(
+					RuntimeOrigin::signed(account),
+					partner_jwt(account, InvestorType::Retail),
+					0
+				));
+			}
+			assert_noop!(
+				Dispenser::dispense_from_campaign(RuntimeOrigin::signed(3), partner_jwt(3, InvestorType::Retail), 0),
+				Error::<Test>::CampaignBudgetExhausted
+			);
+			assert_eq!(CampaignDispensed::<Test>::get(0), partner_campaign().budget);
+		});
+	}
+
+	#[test]
+	fn claims_of_closed_campaigns_are_cleared_in_batches() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), partner_campaign()));
+			assert_ok!(Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), partner_campaign()));
+			for account in 1..=3 {
+				CampaignClaims::<Test>::insert(0, generate_did_from_account(account), ());
+				CampaignClaims::<Test>::insert(1, generate_did_from_account(account), ());
+			}
+			assert_noop!(
+				Dispenser::clear_campaign_claims(RuntimeOrigin::signed(1), 0, 10),
+				Error::<Test>::NoClaimsToClear
+			);
+
+			assert_ok!(Dispenser::close_campaign(RuntimeOrigin::signed(Admin::get()), 0));
+			let post_info = Dispenser::clear_campaign_claims(RuntimeOrigin::signed(1), 0, 2).unwrap();
+			assert_eq!(post_info.pays_fee, Pays::No);
+			assert_eq!(CampaignClaims::<Test>::iter_prefix(0).count(), 1);
+			assert!(!ClaimsToClear::<Test>::get(0).unwrap().is_empty());
+			System::assert_last_event(
+				Event::<Test>::CampaignClaimsCleared { campaign_id: 0, cleared: 2, complete: false }.into(),
+			);
+
+			// The cursor lets the next batch resume within the same block.
+			assert_ok!(Dispenser::clear_campaign_claims(RuntimeOrigin::signed(1), 0, 2));
+			assert_eq!(CampaignClaims::<Test>::iter_prefix(0).count(), 0);
+			assert_eq!(ClaimsToClear::<Test>::get(0), None);
+			System::assert_last_event(
+				Event::<Test>::CampaignClaimsCleared { campaign_id: 0, cleared: 1, complete: true }.into(),
+			);
+			assert_noop!(
+				Dispenser::clear_campaign_claims(RuntimeOrigin::signed(1), 0, 2),
+				Error::<Test>::NoClaimsToClear
+			);
+
+			// The claims of other campaigns are left untouched.
+			assert_eq!(CampaignClaims::<Test>::iter_prefix(1).count(), 3);
+		});
+	}
+}
+
+mod rate_limit {
+	use super::*;
+	use crate::extensions::{CheckDispenseRate, ALREADY_DISPENSED, ISSUER_RATE_LIMITED};
+	use frame_support::dispatch::GetDispatchInfo;
+	use polimec_common::credentials::{alg::SigningKey, AlgorithmExt, Claims, Ed25519, Header, SampleClaims};
+	use sp_runtime::{
+		traits::SignedExtension,
+		transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction},
+	};
+
+	fn dispense_call(account: u64) -> RuntimeCall {
+		let jwt = get_mock_jwt_with_cid(
+			account,
+			InvestorType::Retail,
+			generate_did_from_account(account),
+			WhitelistedPolicy::get(),
+		);
+		RuntimeCall::Dispenser(pallet_dispenser::Call::dispense { jwt })
+	}
+
+	fn validate(account: u64, call: &RuntimeCall) -> TransactionValidity {
+		CheckDispenseRate::<Test>::new().validate(&account, call, &call.get_dispatch_info(), 0)
+	}
+
+	fn pre_dispatch(account: u64, call: &RuntimeCall) -> Result<(), TransactionValidityError> {
+		CheckDispenseRate::<Test>::new().pre_dispatch(&account, call, &call.get_dispatch_info(), 0)
+	}
+
+	#[test]
+	fn other_calls_are_not_limited() {
+		ExtBuilder::default().build().execute_with(|| {
+			let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+			for _ in 0..MaxDispensesPerBlock::get() + 1 {
+				assert_eq!(validate(1, &call), Ok(ValidTransaction::default()));
+				assert_ok!(pre_dispatch(1, &call));
+			}
+		});
+	}
+
+	#[test]
+	fn dispenses_are_capped_per_block() {
+		ExtBuilder::default().build().execute_with(|| {
+			for account in 1..=MaxDispensesPerBlock::get() as u64 {
+				assert_ok!(validate(account, &dispense_call(account)));
+				assert_ok!(pre_dispatch(account, &dispense_call(account)));
+			}
+			let call = dispense_call(10);
+			assert_eq!(validate(10, &call), Err(InvalidTransaction::ExhaustsResources.into()));
+			assert_eq!(pre_dispatch(10, &call), Err(InvalidTransaction::ExhaustsResources.into()));
+
+			System::set_block_number(2);
+			assert_ok!(validate(10, &call));
+			assert_ok!(pre_dispatch(10, &call));
+		});
+	}
+
+	#[test]
+	fn dispenses_are_capped_per_sliding_issuer_window_with_decaying_priority() {
+		ExtBuilder::default().build().execute_with(|| {
+			let cap = MaxDispensesPerIssuer::get() as u64;
+			for account in 1..=cap {
+				let call = dispense_call(account);
+				let expected_priority = DispensePriority::get() * (cap - account + 1) / cap;
+				assert_eq!(validate(account, &call).unwrap().priority, expected_priority);
+				assert_ok!(pre_dispatch(account, &call));
+				// Stay below the per block cap.
+				System::set_block_number(System::block_number() + 1);
+			}
+
+			let call = dispense_call(cap + 1);
+			let issuer_rate_limited = InvalidTransaction::Custom(ISSUER_RATE_LIMITED).into();
+			assert_eq!(validate(cap + 1, &call), Err(issuer_rate_limited));
+			assert_eq!(pre_dispatch(cap + 1, &call), Err(issuer_rate_limited));
+
+			// The window slides, so only the first dispense left it.
+			System::set_block_number(1 + DispenseRateWindow::get());
+			assert_eq!(validate(cap + 1, &call).unwrap().priority, DispensePriority::get() / cap);
+			assert_ok!(pre_dispatch(cap + 1, &call));
+			let call = dispense_call(cap + 2);
+			assert_eq!(validate(cap + 2, &call), Err(issuer_rate_limited));
+		});
+	}
+
+	#[test]
+	fn pre_dispatch_registers_the_weight_of_the_checks() {
+		ExtBuilder::default().build().execute_with(|| {
+			let weight_before = System::block_weight().total();
+			assert_ok!(pre_dispatch(1, &dispense_call(1)));
+			assert_eq!(
+				System::block_weight().total(),
+				weight_before + <() as crate::weights::WeightInfo>::check_dispense_rate()
+			);
+		});
+	}
+
+	#[test]
+	fn credentials_of_other_accounts_are_rejected_and_not_counted() {
+		ExtBuilder::default().build().execute_with(|| {
+			let call = dispense_call(1);
+			assert_eq!(validate(2, &call), Err(InvalidTransaction::BadProof.into()));
+			assert_eq!(pre_dispatch(2, &call), Err(InvalidTransaction::BadProof.into()));
+			assert_eq!(Dispenser::dispenses_in_block(), 0);
+		});
+	}
+
+	#[test]
+	fn expired_credentials_are_rejected_and_not_counted() {
+		ExtBuilder::default().build().execute_with(|| {
+			// The mock JWTs expire at the start of 2030.
+			Timestamp::set_timestamp(1_900_000_000_000);
+			let call = dispense_call(1);
+			assert_eq!(validate(1, &call), Err(InvalidTransaction::BadProof.into()));
+			assert_eq!(pre_dispatch(1, &call), Err(InvalidTransaction::BadProof.into()));
+			assert_eq!(Dispenser::dispenses_in_block(), 0);
+		});
+	}
+
+	#[test]
+	fn already_claimed_credentials_are_rejected_and_not_counted() {
+		ExtBuilder::default().dispense_account(3).build().execute_with(|| {
+			let jwt =
+				get_mock_jwt_with_cid(1, InvestorType::Retail, generate_did_from_account(1), WhitelistedPolicy::get());
+			assert_ok!(Dispenser::dispense(RuntimeOrigin::signed(1), jwt));
+			let call = dispense_call(1);
+			let already_dispensed = InvalidTransaction::Custom(ALREADY_DISPENSED).into();
+			assert_eq!(validate(1, &call), Err(already_dispensed));
+			assert_eq!(pre_dispatch(1, &call), Err(already_dispensed));
+
+			let jwt = campaigns::partner_jwt(1, InvestorType::Retail);
+			assert_ok!(Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), campaigns::partner_campaign()));
+			let call = RuntimeCall::Dispenser(pallet_dispenser::Call::dispense_from_campaign {
+				jwt: jwt.clone(),
+				campaign_id: 0,
+			});
+			assert_ok!(validate(1, &call));
+			assert_ok!(Dispenser::dispense_from_campaign(RuntimeOrigin::signed(1), jwt, 0));
+			assert_eq!(validate(1, &call), Err(already_dispensed));
+			assert_eq!(pre_dispatch(1, &call), Err(already_dispensed));
+
+			assert_eq!(Dispenser::dispenses_in_block(), 0);
+		});
+	}
+
+	#[test]
+	fn forged_credentials_are_rejected_in_the_pool() {
+		ExtBuilder::default().build().execute_with(|| {
+			// Same claims as the mock JWTs, but signed with a key that does not match the verifier's.
+			let forged_key = SigningKey::from_slice([7u8; 32].as_ref()).unwrap();
+			let claims = Claims::new(SampleClaims {
+				subject: 1u64,
+				investor_type: InvestorType::Retail,
+				issuer: "verifier".to_string(),
+				did: generate_did_from_account(1),
+				ipfs_cid: WhitelistedPolicy::get(),
+			});
+			let token = Ed25519.token(&Header::empty(), &claims, &forged_key).unwrap();
+			let jwt = UntrustedToken::new(&token).unwrap();
+			let call = RuntimeCall::Dispenser(pallet_dispenser::Call::dispense { jwt });
+
+			assert_eq!(validate(1, &call), Err(InvalidTransaction::BadProof.into()));
+			assert_eq!(pre_dispatch(1, &call), Err(InvalidTransaction::BadProof.into()));
+		});
+	}
+}
@@ -55,6 +55,11 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn dispense() -> Weight;
 	fn set_dispense_amount() -> Weight;
+	fn create_campaign() -> Weight;
+	fn close_campaign() -> Weight;
+	fn clear_campaign_claims(c: u32, ) -> Weight;
+	fn dispense_from_campaign() -> Weight;
+	fn check_dispense_rate() -> Weight;
 }
 
 /// Weights for `pallet_dispenser` using the Substrate node and recommended hardware.
@@ -93,6 +98,90 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(3_580_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Dispenser::NextCampaignId` (r:1 w:1)
+	/// Proof: `Dispenser::NextCampaignId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::Campaigns` (r:0 w:1)
+	/// Proof: `Dispenser::Campaigns` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	fn create_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 9_120_000 picoseconds.
+		Weight::from_parts(9_480_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Dispenser::Campaigns` (r:1 w:1)
+	/// Proof: `Dispenser::Campaigns` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::CampaignDispensed` (r:0 w:1)
+	/// Proof: `Dispenser::CampaignDispensed` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::ClaimsToClear` (r:0 w:1)
+	/// Proof: `Dispenser::ClaimsToClear` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn close_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `323`
+		//  Estimated: `3631`
+		// Minimum execution time: 12_430_000 picoseconds.
+		Weight::from_parts(12_960_000, 3631)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Dispenser::ClaimsToClear` (r:1 w:1)
+	/// Proof: `Dispenser::ClaimsToClear` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::CampaignClaims` (r:0 w:1000)
+	/// Proof: `Dispenser::CampaignClaims` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 1000]`.
+	fn clear_campaign_claims(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `158 + c * (86 ±0)`
+		//  Estimated: `3597 + c * (2561 ±0)`
+		// Minimum execution time: 17_860_000 picoseconds.
+		Weight::from_parts(13_204_117, 3597)
+			// Standard Error: 1_912
+			.saturating_add(Weight::from_parts(1_471_806, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2561).saturating_mul(c.into()))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::Campaigns` (r:1 w:0)
+	/// Proof: `Dispenser::Campaigns` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::CampaignClaims` (r:1 w:1)
+	/// Proof: `Dispenser::CampaignClaims` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::CampaignDispensed` (r:1 w:1)
+	/// Proof: `Dispenser::CampaignDispensed` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(481), added: 2956, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	fn dispense_from_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `457`
+		//  Estimated: `4764`
+		// Minimum execution time: 186_340_000 picoseconds.
+		Weight::from_parts(188_720_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Dispenser::DispensesInBlock` (r:1 w:1)
+	/// Proof: `Dispenser::DispensesInBlock` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::IssuerDispenses` (r:1 w:1)
+	/// Proof: `Dispenser::IssuerDispenses` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn check_dispense_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `172`
+		//  Estimated: `3578`
+		// Minimum execution time: 64_810_000 picoseconds.
+		Weight::from_parts(66_270_000, 3578)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -130,4 +219,88 @@ impl WeightInfo for () {
 		Weight::from_parts(3_580_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Dispenser::NextCampaignId` (r:1 w:1)
+	/// Proof: `Dispenser::NextCampaignId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::Campaigns` (r:0 w:1)
+	/// Proof: `Dispenser::Campaigns` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	fn create_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 9_120_000 picoseconds.
+		Weight::from_parts(9_480_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Dispenser::Campaigns` (r:1 w:1)
+	/// Proof: `Dispenser::Campaigns` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::CampaignDispensed` (r:0 w:1)
+	/// Proof: `Dispenser::CampaignDispensed` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::ClaimsToClear` (r:0 w:1)
+	/// Proof: `Dispenser::ClaimsToClear` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn close_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `323`
+		//  Estimated: `3631`
+		// Minimum execution time: 12_430_000 picoseconds.
+		Weight::from_parts(12_960_000, 3631)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Dispenser::ClaimsToClear` (r:1 w:1)
+	/// Proof: `Dispenser::ClaimsToClear` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::CampaignClaims` (r:0 w:1000)
+	/// Proof: `Dispenser::CampaignClaims` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 1000]`.
+	fn clear_campaign_claims(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `158 + c * (86 ±0)`
+		//  Estimated: `3597 + c * (2561 ±0)`
+		// Minimum execution time: 17_860_000 picoseconds.
+		Weight::from_parts(13_204_117, 3597)
+			// Standard Error: 1_912
+			.saturating_add(Weight::from_parts(1_471_806, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2561).saturating_mul(c.into()))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::Campaigns` (r:1 w:0)
+	/// Proof: `Dispenser::Campaigns` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::CampaignClaims` (r:1 w:1)
+	/// Proof: `Dispenser::CampaignClaims` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::CampaignDispensed` (r:1 w:1)
+	/// Proof: `Dispenser::CampaignDispensed` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(481), added: 2956, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	fn dispense_from_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `457`
+		//  Estimated: `4764`
+		// Minimum execution time: 186_340_000 picoseconds.
+		Weight::from_parts(188_720_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Dispenser::DispensesInBlock` (r:1 w:1)
+	/// Proof: `Dispenser::DispensesInBlock` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::IssuerDispenses` (r:1 w:1)
+	/// Proof: `Dispenser::IssuerDispenses` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn check_dispense_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `172`
+		//  Estimated: `3578`
+		// Minimum execution time: 64_810_000 picoseconds.
+		Weight::from_parts(66_270_000, 3578)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
\ No newline at end of file
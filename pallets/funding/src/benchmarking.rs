@@ -29,7 +29,7 @@ use frame_support::{
 	dispatch::RawOrigin,
 	traits::{
 		fungibles::{metadata::MetadataDeposit, Inspect},
-		OriginTrait,
+		ConstU32, OriginTrait,
 	},
 	Parameter,
 };
@@ -37,7 +37,7 @@ use itertools::Itertools;
 use parity_scale_codec::{Decode, Encode};
 use polimec_common::{credentials::InvestorType, ReleaseSchedule, USD_DECIMALS, USD_UNIT};
 use polimec_common_test_utils::{generate_did_from_account, get_mock_jwt_with_cid};
-use sp_arithmetic::Percent;
+use sp_arithmetic::{Percent, Perquintill};
 use sp_core::H256;
 use sp_io::hashing::blake2_256;
 use sp_runtime::traits::{Get, Member, TrailingZeroInput, Zero};
@@ -65,6 +65,9 @@ where
 		mainnet_token_max_supply: 1_000_000u128 * CT_UNIT,
 		total_allocation_size: 1_000_000u128 * CT_UNIT,
 		auction_round_allocation_percentage: Percent::from_percent(50u8),
+		candle_auction_closing_phase: None,
+		bucket_curve: BucketCurve::default(),
+		price_kind: PriceKind::Spot,
 		minimum_price: PriceProviderOf::<T>::calculate_decimals_aware_price(10u128.into(), USD_DECIMALS, CT_DECIMALS)
 			.unwrap(),
 
@@ -81,10 +84,46 @@ where
 		},
 		participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 		funding_destination_account: issuer,
+		milestones: Default::default(),
 		policy_ipfs_cid: Some(metadata_hash.into()),
 	}
 }
 
+pub fn default_milestones() -> BoundedVec<Milestone<Cid>, ConstU32<MAX_MILESTONES>> {
+	let deliverable_cid: Cid = BoundedVec::try_from(IPFS_CID.as_bytes().to_vec()).unwrap();
+	vec![
+		Milestone { share: Percent::from_percent(50u8), deliverable_cid: deliverable_cid.clone() },
+		Milestone { share: Percent::from_percent(50u8), deliverable_cid },
+	]
+	.try_into()
+	.unwrap()
+}
+
+/// Creates a settled project with two milestones, and returns it together with an account holding escrow shares.
+pub fn create_project_with_escrow<T: Config>(
+	inst: &mut BenchInstantiator<T>,
+	issuer: AccountIdOf<T>,
+) -> (ProjectId, ProjectMetadataOf<T>, AccountIdOf<T>)
+where
+	T::Price: From<u128>,
+	T::Hash: From<H256>,
+{
+	let mut project_metadata = default_project_metadata::<T>(issuer.clone());
+	project_metadata.milestones = default_milestones();
+	let project_id = inst.create_settled_project(
+		project_metadata.clone(),
+		issuer,
+		None,
+		default_evaluations::<T>(),
+		default_bids::<T>(),
+		default_community_contributions::<T>(),
+		vec![],
+		true,
+	);
+	let participant = inst.execute(|| EscrowShares::<T>::iter_prefix(project_id).next().unwrap().0);
+	(project_id, project_metadata, participant)
+}
+
 pub fn default_evaluations<T: Config>() -> Vec<UserToUSDBalance<T>>
 where
 	<T as Config>::Price: From<u128>,
@@ -391,6 +430,9 @@ mod benchmarks {
 			mainnet_token_max_supply: 200_000u128 * CT_UNIT,
 			total_allocation_size: 200_000u128 * CT_UNIT,
 			auction_round_allocation_percentage: Percent::from_percent(30u8),
+			candle_auction_closing_phase: None,
+			bucket_curve: BucketCurve::default(),
+			price_kind: PriceKind::Spot,
 			minimum_price: PriceProviderOf::<T>::calculate_decimals_aware_price(
 				11u128.into(),
 				USD_DECIMALS,
@@ -410,6 +452,7 @@ mod benchmarks {
 			},
 			participation_currencies: vec![AcceptedFundingAsset::USDT, AcceptedFundingAsset::USDC].try_into().unwrap(),
 			funding_destination_account: issuer_funding.clone().clone(),
+			milestones: Default::default(),
 			policy_ipfs_cid: Some(BoundedVec::try_from(IPFS_CID.as_bytes().to_vec()).unwrap()),
 		};
 
@@ -816,17 +859,10 @@ mod benchmarks {
 		}
 
 		// Bucket Storage Check
-		let bucket_delta_amount = Percent::from_percent(10) *
-			project_metadata.auction_round_allocation_percentage *
-			project_metadata.total_allocation_size;
-		let ten_percent_in_price: <T as Config>::Price =
-			PriceOf::<T>::checked_from_rational(1, 10).unwrap() * project_metadata.minimum_price;
-
 		let mut expected_bucket = Bucket::new(
 			project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size,
 			project_metadata.minimum_price,
-			ten_percent_in_price,
-			bucket_delta_amount,
+			project_metadata.bucket_curve,
 		);
 
 		for (bid_params, _price_) in existing_bids_post_bucketing.clone() {
@@ -895,6 +931,9 @@ mod benchmarks {
 		project_metadata.mainnet_token_max_supply = 10_000_000 * CT_UNIT;
 		project_metadata.total_allocation_size = 10_000_000 * CT_UNIT;
 		project_metadata.auction_round_allocation_percentage = Percent::from_percent(100u8);
+		// Worst case: the bucket is rebuilt from all the bids to get the WAP at the candle auction end.
+		// The bids are made on the first auction block, so whatever end is picked does not reject them.
+		project_metadata.candle_auction_closing_phase = Some(Percent::from_percent(100u8));
 
 		let project_id = inst.create_auctioning_project(
 			project_metadata.clone(),
@@ -906,7 +945,7 @@ mod benchmarks {
 				default_weights(),
 			),
 		);
-		let expected_remainder_round_block = inst.remainder_round_block() - One::one();
+		let expected_remainder_round_block = inst.remainder_round_block();
 
 		let mut all_bids = Vec::new();
 
@@ -982,8 +1021,12 @@ mod benchmarks {
 
 		inst.bid_for_users(project_id, all_bids).unwrap();
 
+		// The candle auction end needs randomness revealed after the auction, so end it one block later. The automatic
+		// transition of that block is dropped, so the measured call is the one ending the auction.
 		let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
-		inst.jump_to_block(auction_end);
+		let end_block = auction_end + One::one();
+		ProjectsToUpdate::<T>::remove(end_block);
+		inst.jump_to_block(end_block);
 
 		#[block]
 		{
@@ -1002,6 +1045,7 @@ mod benchmarks {
 			Bids::<T>::iter_prefix_values((project_id,)).filter(|b| matches!(b.status, BidStatus::Rejected)).count();
 		assert_eq!(accepted_bids_count, x as usize);
 		assert_eq!(rejected_bids_count, y as usize);
+		assert!(CandleAuctionEnds::<T>::get(project_id).is_some());
 
 		// Events
 		frame_system::Pallet::<T>::assert_last_event(
@@ -1013,6 +1057,147 @@ mod benchmarks {
 		);
 	}
 
+	// The most expensive path is cancelling the latest bid, since the bucket needs to be restored
+	#[benchmark]
+	fn cancel_bid() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let bidder = account::<AccountIdOf<T>>("bidder", 0, 0);
+		whitelist_account!(bidder);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = default_evaluations::<T>();
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), issuer, None, evaluations);
+
+		let bid_params = BidParams::new(bidder.clone(), (500 * CT_UNIT).into(), 1u8, AcceptedFundingAsset::USDT);
+		let plmc_for_bid = inst.calculate_auction_plmc_charged_with_given_price(
+			&vec![bid_params.clone()],
+			project_metadata.minimum_price,
+			false,
+		);
+		let usdt_for_bid = inst.calculate_auction_funding_asset_charged_with_given_price(
+			&vec![bid_params.clone()],
+			project_metadata.minimum_price,
+		);
+		inst.mint_plmc_to(plmc_for_bid.clone());
+		inst.mint_plmc_to(vec![bidder.clone()].existential_deposits());
+		inst.mint_funding_asset_to(usdt_for_bid.clone());
+		inst.bid_for_users(project_id, vec![bid_params]).unwrap();
+
+		let bid = inst.execute(|| Bids::<T>::iter_prefix_values((project_id, bidder.clone())).next().unwrap());
+		let bucket_before_cancel = inst.execute(|| {
+			let mut bucket = Buckets::<T>::get(project_id).unwrap();
+			bucket.revert(bid.original_ct_amount, bid.original_ct_usd_price);
+			bucket
+		});
+
+		let jwt = get_mock_jwt_with_cid(
+			bidder.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(bidder.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		cancel_bid(RawOrigin::Signed(bidder.clone()), jwt, project_id, bid.id);
+
+		// * validity checks *
+		// Storage
+		assert!(Bids::<T>::get((project_id, bidder.clone(), bid.id)).is_none());
+		assert_eq!(BidCounts::<T>::get(project_id), 0);
+		assert_eq!(Buckets::<T>::get(project_id).unwrap(), bucket_before_cancel);
+
+		// Balances
+		let bonded_plmc =
+			inst.get_reserved_plmc_balances_for(vec![bidder.clone()], HoldReason::Participation.into())[0].plmc_amount;
+		assert_eq!(bonded_plmc, 0);
+		let free_usdt = inst.get_free_funding_asset_balances_for(usdt_id(), vec![bidder.clone()])[0].asset_amount;
+		assert_eq!(free_usdt, usdt_for_bid[0].asset_amount);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::BidCancelled {
+				project_id,
+				bidder,
+				id: bid.id,
+				plmc_released: bid.plmc_bond,
+				funding_asset: AcceptedFundingAsset::USDT,
+				funding_amount_released: bid.funding_asset_amount_locked,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn amend_bid() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let bidder = account::<AccountIdOf<T>>("bidder", 0, 0);
+		whitelist_account!(bidder);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = default_evaluations::<T>();
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), issuer, None, evaluations);
+
+		let bid_params = BidParams::new(bidder.clone(), (1000 * CT_UNIT).into(), 1u8, AcceptedFundingAsset::USDT);
+		let plmc_for_bid = inst.calculate_auction_plmc_charged_with_given_price(
+			&vec![bid_params.clone()],
+			project_metadata.minimum_price,
+			false,
+		);
+		let usdt_for_bid = inst.calculate_auction_funding_asset_charged_with_given_price(
+			&vec![bid_params.clone()],
+			project_metadata.minimum_price,
+		);
+		inst.mint_plmc_to(plmc_for_bid.clone());
+		inst.mint_plmc_to(vec![bidder.clone()].existential_deposits());
+		inst.mint_funding_asset_to(usdt_for_bid.clone());
+		inst.bid_for_users(project_id, vec![bid_params]).unwrap();
+
+		let bid = inst.execute(|| Bids::<T>::iter_prefix_values((project_id, bidder.clone())).next().unwrap());
+		let new_ct_amount = bid.original_ct_amount / 2;
+
+		let jwt = get_mock_jwt_with_cid(
+			bidder.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(bidder.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		amend_bid(RawOrigin::Signed(bidder.clone()), jwt, project_id, bid.id, new_ct_amount);
+
+		// * validity checks *
+		// Storage
+		let stored_bid = Bids::<T>::get((project_id, bidder.clone(), bid.id)).unwrap();
+		assert_eq!(stored_bid.original_ct_amount, new_ct_amount);
+		assert_eq!(stored_bid.plmc_bond, Perquintill::from_percent(50) * bid.plmc_bond);
+
+		// Events
+		let maybe_event = find_event! {
+			T,
+			Event::<T>::BidAmended {
+				project_id,
+				ct_amount, ..
+			},
+			project_id == project_id,
+			ct_amount == new_ct_amount
+		};
+		assert!(maybe_event.is_some(), "Event not found");
+	}
+
 	// We check if the user has a winning bid regardless if its the community or remainder round, so both rounds should have
 	// the same weight with `x` being equal.
 	#[benchmark]
@@ -1164,6 +1349,360 @@ mod benchmarks {
 		));
 	}
 
+	// Accepting the funding is the most expensive decision, since it has to calculate the evaluator rewards.
+	#[benchmark]
+	fn decide_project_outcome() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		whitelist_account!(issuer);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+
+		let project_id = inst.create_remainder_contributing_project(
+			project_metadata.clone(),
+			issuer.clone(),
+			None,
+			default_evaluations::<T>(),
+			default_bids::<T>(),
+			default_community_contributions::<T>(),
+		);
+
+		// Bring the funding between the success thresholds so the project awaits a decision
+		let funding_ratio = Perquintill::from_parts(
+			(T::FundingSuccessThreshold::get().deconstruct() + T::FundingAutoSuccessThreshold::get().deconstruct()) / 2,
+		);
+		inst.execute(|| {
+			ProjectsDetails::<T>::mutate(project_id, |maybe_details| {
+				let details = maybe_details.as_mut().unwrap();
+				details.funding_amount_reached_usd = funding_ratio * details.fundraising_target_usd;
+			})
+		});
+		let end_block = inst.get_project_details(project_id).round_duration.end().unwrap();
+		inst.jump_to_block(end_block);
+		inst.execute(|| Pallet::<T>::do_end_funding(project_id).unwrap());
+		assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AwaitingDecision);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		decide_project_outcome(RawOrigin::Signed(issuer), jwt, project_id, FundingOutcomeDecision::AcceptFunding);
+
+		// * validity checks *
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(project_details.status, ProjectStatus::FundingSuccessful);
+		assert!(matches!(
+			project_details.evaluation_round_info.evaluators_outcome,
+			Some(EvaluatorsOutcome::Rewarded(_))
+		));
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::ProjectOutcomeDecided { project_id, decision: FundingOutcomeDecision::AcceptFunding }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn submit_milestone() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		whitelist_account!(issuer);
+		let (project_id, project_metadata, _participant) = create_project_with_escrow::<T>(&mut inst, issuer.clone());
+
+		// Submitting a later milestone has to check all the previous ones
+		inst.execute(|| MilestoneStatuses::<T>::insert(project_id, 0u8, MilestoneStatus::Approved));
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		submit_milestone(RawOrigin::Signed(issuer), jwt, project_id, 1u8);
+
+		// * validity checks *
+		let voting_end = inst.current_block() + T::MilestoneVotingDuration::get();
+		assert_eq!(
+			inst.execute(|| MilestoneStatuses::<T>::get(project_id, 1u8)),
+			Some(MilestoneStatus::Voting { end: voting_end, ayes: Zero::zero(), nays: Zero::zero() })
+		);
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::MilestoneSubmitted { project_id, milestone: 1u8, voting_end }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn vote_milestone() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let (project_id, _project_metadata, voter) = create_project_with_escrow::<T>(&mut inst, issuer.clone());
+		whitelist_account!(voter);
+
+		inst.execute(|| Pallet::<T>::do_submit_milestone(issuer, project_id, 0u8).unwrap());
+		let weight = inst.execute(|| EscrowShares::<T>::get(project_id, &voter));
+
+		#[extrinsic_call]
+		vote_milestone(RawOrigin::Signed(voter.clone()), project_id, 0u8, true);
+
+		// * validity checks *
+		assert_eq!(inst.execute(|| MilestoneVotes::<T>::get((project_id, 0u8, &voter))), Some(true));
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::MilestoneVoted { project_id, milestone: 0u8, voter, approve: true, weight }.into(),
+		);
+	}
+
+	// Approving is the most expensive path, since it releases the funds to the issuer.
+	#[benchmark]
+	fn resolve_milestone() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let anyone = account::<AccountIdOf<T>>("anyone", 0, 0);
+		whitelist_account!(anyone);
+		let (project_id, project_metadata, voter) = create_project_with_escrow::<T>(&mut inst, issuer.clone());
+
+		inst.execute(|| {
+			Pallet::<T>::do_submit_milestone(issuer, project_id, 0u8).unwrap();
+			Pallet::<T>::do_vote_milestone(voter, project_id, 0u8, true).unwrap();
+		});
+		let voting_end = inst.current_block() + T::MilestoneVotingDuration::get();
+		inst.jump_to_block(voting_end + One::one());
+
+		let usdt_escrow = inst.execute(|| EscrowedFunds::<T>::get(project_id, AcceptedFundingAsset::USDT));
+		let issuer_usdt_before =
+			inst.get_free_funding_asset_balance_for(usdt_id(), project_metadata.funding_destination_account.clone());
+
+		#[extrinsic_call]
+		resolve_milestone(RawOrigin::Signed(anyone), project_id, 0u8);
+
+		// * validity checks *
+		let released = Percent::from_percent(50u8) * usdt_escrow.deposited;
+		let issuer_usdt_after =
+			inst.get_free_funding_asset_balance_for(usdt_id(), project_metadata.funding_destination_account.clone());
+		assert_eq!(issuer_usdt_after, issuer_usdt_before + released);
+		assert_eq!(inst.execute(|| MilestoneStatuses::<T>::get(project_id, 0u8)), Some(MilestoneStatus::Approved));
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::MilestoneApproved { project_id, milestone: 0u8 }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn claim_escrow_refund() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let (project_id, _project_metadata, participant) = create_project_with_escrow::<T>(&mut inst, issuer.clone());
+		whitelist_account!(participant);
+
+		inst.execute(|| {
+			Pallet::<T>::do_submit_milestone(issuer, project_id, 0u8).unwrap();
+			Pallet::<T>::do_force_resolve_milestone(project_id, 0u8, false).unwrap();
+		});
+		let shares = inst.execute(|| EscrowShares::<T>::get(project_id, &participant));
+
+		#[extrinsic_call]
+		claim_escrow_refund(RawOrigin::Signed(participant.clone()), project_id);
+
+		// * validity checks *
+		assert!(inst.execute(|| EscrowShares::<T>::get(project_id, &participant)).is_zero());
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::EscrowRefunded { project_id, account: participant, shares }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn set_reward_parameters() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let mut parameters = inst.execute(|| Pallet::<T>::active_reward_parameters().unwrap());
+		parameters.evaluators_share = Perquintill::from_percent(40);
+		parameters.liquidity_pools_share = Perquintill::from_percent(40);
+
+		let origin = T::RewardParametersOrigin::try_successful_origin().expect("Origin should be able to be created");
+
+		#[extrinsic_call]
+		set_reward_parameters(origin, parameters.clone());
+
+		// * validity checks *
+		assert_eq!(ActiveRewardParameters::<T>::get(), Some(parameters.clone()));
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::RewardParametersSet { parameters }.into());
+	}
+
+	#[benchmark]
+	fn freeze_project() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_evaluating_project(project_metadata, issuer, None);
+
+		let origin = T::AdminOrigin::try_successful_origin().expect("Origin should be able to be created");
+
+		#[extrinsic_call]
+		freeze_project(origin, project_id);
+
+		// * validity checks *
+		assert!(FrozenProjects::<T>::get(project_id));
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::ProjectFrozen { project_id }.into());
+	}
+
+	#[benchmark]
+	fn unfreeze_project() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_evaluating_project(project_metadata, issuer, None);
+		inst.execute(|| Pallet::<T>::do_freeze_project(project_id).unwrap());
+
+		let origin = T::AdminOrigin::try_successful_origin().expect("Origin should be able to be created");
+
+		#[extrinsic_call]
+		unfreeze_project(origin, project_id);
+
+		// * validity checks *
+		assert!(!FrozenProjects::<T>::get(project_id));
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::ProjectUnfrozen { project_id }.into());
+	}
+
+	#[benchmark]
+	fn force_fail_project() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_evaluating_project(project_metadata, issuer, None);
+		inst.execute(|| Pallet::<T>::do_freeze_project(project_id).unwrap());
+
+		let origin = T::AdminOrigin::try_successful_origin().expect("Origin should be able to be created");
+
+		#[extrinsic_call]
+		force_fail_project(origin, project_id);
+
+		// * validity checks *
+		assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::FundingFailed);
+		assert!(!FrozenProjects::<T>::get(project_id));
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::ProjectForceFailed { project_id }.into());
+	}
+
+	#[benchmark]
+	fn set_participations_paused() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let origin = T::AdminOrigin::try_successful_origin().expect("Origin should be able to be created");
+
+		#[extrinsic_call]
+		pause_participations(origin);
+
+		// * validity checks *
+		assert!(ParticipationsPaused::<T>::get());
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::ParticipationsPaused.into());
+	}
+
+	#[benchmark]
+	fn register_funding_asset() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let origin = T::FundingAssetOrigin::try_successful_origin().expect("Origin should be able to be created");
+		let asset = AcceptedFundingAsset::Other(4242);
+		let info = FundingAssetInfo {
+			location: Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(4242)]),
+			decimals: 6,
+			oracle_key: *b"EURC\0\0\0\0",
+			enabled: true,
+		};
+
+		#[extrinsic_call]
+		register_funding_asset(origin, asset, info.clone());
+
+		// * validity checks *
+		assert_eq!(FundingAssets::<T>::get(asset), Some(info.clone()));
+		assert_eq!(FundingAssetLocations::<T>::get(&info.location), Some(asset));
+		assert_eq!(FundingAssetOracleKeys::<T>::get(info.oracle_key), Some(asset));
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::FundingAssetRegistered { asset, info }.into());
+	}
+
+	#[benchmark]
+	fn set_funding_asset_enabled() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let origin = T::FundingAssetOrigin::try_successful_origin().expect("Origin should be able to be created");
+		let asset = AcceptedFundingAsset::USDT;
+		assert!(FundingAssets::<T>::get(asset).is_some_and(|info| info.enabled));
+
+		#[extrinsic_call]
+		set_funding_asset_enabled(origin, asset, false);
+
+		// * validity checks *
+		assert!(FundingAssets::<T>::get(asset).is_some_and(|info| !info.enabled));
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::FundingAssetEnabledSet { asset, enabled: false }.into(),
+		);
+	}
+
 	// Success case is the most expensive, so we always charge for that.
 	#[benchmark]
 	fn start_settlement() {
@@ -16,6 +16,9 @@ impl<T: Config> Pallet<T> {
 				MetadataError::ParticipationCurrenciesError => Error::<T>::ParticipationCurrenciesError,
 				MetadataError::AllocationSizeError => Error::<T>::AllocationSizeError,
 				MetadataError::AuctionRoundPercentageError => Error::<T>::AuctionRoundPercentageError,
+				MetadataError::CandleAuctionPhaseError => Error::<T>::CandleAuctionPhaseError,
+				MetadataError::BucketCurveError => Error::<T>::BucketCurveError,
+				MetadataError::MilestonesError => Error::<T>::MilestonesError,
 				MetadataError::FundingTargetTooLow => Error::<T>::FundingTargetTooLow,
 				MetadataError::FundingTargetTooHigh => Error::<T>::FundingTargetTooHigh,
 				MetadataError::CidNotProvided => Error::<T>::CidNotProvided,
@@ -24,6 +27,9 @@ impl<T: Config> Pallet<T> {
 			};
 			return Err(pallet_error.into());
 		}
+		for funding_asset in project_metadata.participation_currencies.iter() {
+			Self::ensure_funding_asset_enabled(*funding_asset)?;
+		}
 		let total_allocation_size = project_metadata.total_allocation_size;
 
 		let fundraising_target =
@@ -8,9 +8,31 @@ impl<T: Config> Pallet<T> {
 	pub fn do_end_auction(project_id: ProjectId) -> DispatchResultWithPostInfo {
 		// * Get variables *
 		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
-		let bucket = Buckets::<T>::get(project_id).ok_or(Error::<T>::BucketNotFound)?;
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		// * Validity checks *
+		// Also checked on the transition, but the candle auction end must not be decided before the round is over.
+		ensure!(matches!(project_details.status, ProjectStatus::AuctionRound), Error::<T>::IncorrectRound);
+		ensure!(project_details.round_duration.ended(now), Error::<T>::TooEarlyForRound);
+
+		// * Decide candle auction end *
+		let candle_auction_end = match project_metadata.candle_auction_closing_phase {
+			Some(closing_phase) => {
+				let end_block = Self::decide_candle_auction_end(project_id, &project_details, closing_phase)?;
+				CandleAuctionEnds::<T>::insert(project_id, end_block);
+				Self::deposit_event(Event::CandleAuctionEndDecided { project_id, end_block });
+				Some(end_block)
+			},
+			None => None,
+		};
 
 		// * Calculate WAP *
+		// On a candle auction, the bucket is rebuilt without the bids made after the effective end.
+		let bucket = match candle_auction_end {
+			Some(end_block) => Self::bucket_at_block(project_id, &project_metadata, end_block)?,
+			None => Buckets::<T>::get(project_id).ok_or(Error::<T>::BucketNotFound)?,
+		};
 		let auction_allocation_size =
 			project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size;
 		let weighted_token_price = bucket.calculate_wap(auction_allocation_size);
@@ -20,6 +42,7 @@ impl<T: Config> Pallet<T> {
 			project_id,
 			project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size,
 			weighted_token_price,
+			candle_auction_end,
 		);
 		let updated_project_details =
 			ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
@@ -27,7 +50,6 @@ impl<T: Config> Pallet<T> {
 		match calculation_result {
 			Err(e) => return Err(DispatchErrorWithPostInfo { post_info: ().into(), error: e }),
 			Ok((accepted_bids_count, rejected_bids_count)) => {
-				let now = <frame_system::Pallet<T>>::block_number();
 				// * Transition Round *
 				Self::transition_project(
 					project_id,
@@ -57,6 +79,7 @@ impl<T: Config> Pallet<T> {
 	/// * [`ProjectsDetails`] - Check that the project is in the bidding stage
 	/// * [`BiddingBonds`] - Update the storage with the bidder's PLMC bond for that bid
 	/// * [`Bids`] - Check previous bids by that user, and update the storage with the new bid
+	/// * [`LastBidIds`] - Mark the new bids as the latest ones of the project
 	#[transactional]
 	pub fn do_bid(params: DoBidParams<T>) -> DispatchResultWithPostInfo {
 		// * Get variables *
@@ -102,6 +125,7 @@ impl<T: Config> Pallet<T> {
 		};
 
 		// * Validity checks *
+		Self::ensure_participations_allowed(project_id)?;
 		ensure!(project_policy == whitelisted_policy, Error::<T>::PolicyMismatch);
 		ensure!(
 			matches!(investor_type, InvestorType::Institutional | InvestorType::Professional),
@@ -115,6 +139,7 @@ impl<T: Config> Pallet<T> {
 			project_metadata.participation_currencies.contains(&funding_asset),
 			Error::<T>::FundingAssetNotAccepted
 		);
+		Self::ensure_funding_asset_enabled(funding_asset)?;
 
 		ensure!(
 			metadata_ticket_size_bounds.usd_ticket_above_minimum_per_participation(min_total_ticket_size),
@@ -128,7 +153,9 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::TooHigh
 		);
 		ensure!(existing_bids.len() < T::MaxBidsPerUser::get() as usize, Error::<T>::TooManyUserParticipations);
+		Self::ensure_fresh_prices(funding_asset)?;
 
+		let first_bid_id = NextBidId::<T>::get();
 		// While there's a remaining amount to bid for
 		while !amount_to_bid.is_zero() {
 			let ct_amount = if amount_to_bid <= current_bucket.amount_left {
@@ -151,6 +178,7 @@ impl<T: Config> Pallet<T> {
 				now,
 				did: did.clone(),
 				metadata_ticket_size_bounds,
+				price_kind: project_metadata.price_kind,
 				total_bids_by_bidder: existing_bids_amount.saturating_add(perform_bid_calls),
 				total_bids_for_project: total_bids_for_project.saturating_add(perform_bid_calls),
 			};
@@ -165,6 +193,7 @@ impl<T: Config> Pallet<T> {
 
 		// Note: If the bucket has been exhausted, the 'update' function has already made the 'current_bucket' point to the next one.
 		Buckets::<T>::insert(project_id, current_bucket);
+		LastBidIds::<T>::insert(project_id, (first_bid_id, NextBidId::<T>::get().saturating_sub(1)));
 
 		Ok(PostDispatchInfo {
 			actual_weight: Some(WeightInfoOf::<T>::bid(existing_bids_amount, perform_bid_calls)),
@@ -185,6 +214,7 @@ impl<T: Config> Pallet<T> {
 			now,
 			did,
 			metadata_ticket_size_bounds,
+			price_kind,
 			total_bids_by_bidder,
 			total_bids_for_project,
 		} = do_perform_bid_params;
@@ -201,8 +231,9 @@ impl<T: Config> Pallet<T> {
 		ensure!(total_bids_for_project < T::MaxBidsPerProject::get(), Error::<T>::TooManyProjectParticipations);
 
 		// * Calculate new variables *
-		let plmc_bond = Self::calculate_plmc_bond(ticket_size, multiplier).map_err(|_| Error::<T>::BadMath)?;
-		let funding_asset_amount_locked = Self::calculate_funding_asset_amount(ticket_size, funding_asset)?;
+		let plmc_bond =
+			Self::calculate_plmc_bond(ticket_size, multiplier, price_kind).map_err(|_| Error::<T>::BadMath)?;
+		let funding_asset_amount_locked = Self::calculate_funding_asset_amount(ticket_size, funding_asset, price_kind)?;
 
 		let new_bid = BidInfoOf::<T> {
 			id: bid_id,
@@ -241,4 +272,144 @@ impl<T: Config> Pallet<T> {
 
 		Ok(new_bid)
 	}
+
+	/// Cancel a bid made during the auction round, and give back the PLMC bond and the funding assets.
+	///
+	/// # Arguments
+	/// * `bidder` - The account that made the bid
+	/// * `project_id` - The project the bid was made for
+	/// * `bid_id` - The id of the bid to cancel
+	///
+	/// # Storage access
+	/// * [`Bids`] - Remove the bid
+	/// * [`Buckets`] - Restore the bucket if the bid is one of the latest ones of the project, as per [`LastBidIds`]
+	/// * [`BidCounts`], [`AuctionBoughtUSD`], [`DidWithWinningBids`] - Remove the bid from the project totals
+	#[transactional]
+	pub fn do_cancel_bid(bidder: &AccountIdOf<T>, project_id: ProjectId, bid_id: u32) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let bid = Bids::<T>::get((project_id, bidder, bid_id)).ok_or(Error::<T>::ParticipationNotFound)?;
+		let ticket_size =
+			bid.original_ct_usd_price.checked_mul_int(bid.original_ct_amount).ok_or(Error::<T>::BadMath)?;
+
+		// * Validity checks *
+		ensure!(matches!(project_details.status, ProjectStatus::AuctionRound), Error::<T>::IncorrectRound);
+
+		// * Update Storage *
+		Self::restore_bucket(project_id, &bid, bid.original_ct_amount)?;
+		Self::release_participation_bond(bidder, bid.plmc_bond)?;
+		Self::release_funding_asset(project_id, bidder, bid.funding_asset_amount_locked, bid.funding_asset)?;
+
+		Bids::<T>::remove((project_id, bidder, bid_id));
+		BidCounts::<T>::mutate(project_id, |c| c.saturating_dec());
+		AuctionBoughtUSD::<T>::mutate((project_id, bid.did.clone()), |amount| amount.saturating_reduce(ticket_size));
+		if AuctionBoughtUSD::<T>::get((project_id, bid.did.clone())).is_zero() {
+			AuctionBoughtUSD::<T>::remove((project_id, bid.did.clone()));
+			DidWithWinningBids::<T>::remove(project_id, bid.did);
+		}
+
+		Self::deposit_event(Event::BidCancelled {
+			project_id,
+			bidder: bidder.clone(),
+			id: bid_id,
+			plmc_released: bid.plmc_bond,
+			funding_asset: bid.funding_asset,
+			funding_amount_released: bid.funding_asset_amount_locked,
+		});
+
+		Ok(())
+	}
+
+	/// Lower the amount of CTs of a bid made during the auction round. The bid keeps its price, and the
+	/// PLMC bond and funding assets are reduced in the same proportion as the CT amount.
+	///
+	/// # Arguments
+	/// * `bidder` - The account that made the bid
+	/// * `project_id` - The project the bid was made for
+	/// * `bid_id` - The id of the bid to amend
+	/// * `new_ct_amount` - The new amount of CTs, which must be lower than the current one
+	/// * `investor_type` - Used to check the new ticket size against the project's minimum
+	///
+	/// # Storage access
+	/// * [`Bids`] - Update the bid
+	/// * [`Buckets`] - Restore the bucket if the bid is one of the latest ones of the project, as per [`LastBidIds`]
+	/// * [`AuctionBoughtUSD`] - Remove the difference from the DID's total
+	#[transactional]
+	pub fn do_amend_bid(
+		bidder: &AccountIdOf<T>,
+		project_id: ProjectId,
+		bid_id: u32,
+		new_ct_amount: Balance,
+		investor_type: InvestorType,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let mut bid = Bids::<T>::get((project_id, bidder, bid_id)).ok_or(Error::<T>::ParticipationNotFound)?;
+		let metadata_ticket_size_bounds = match investor_type {
+			InvestorType::Institutional => project_metadata.bidding_ticket_sizes.institutional,
+			InvestorType::Professional => project_metadata.bidding_ticket_sizes.professional,
+			_ => return Err(Error::<T>::WrongInvestorType.into()),
+		};
+		let old_ticket_size =
+			bid.original_ct_usd_price.checked_mul_int(bid.original_ct_amount).ok_or(Error::<T>::BadMath)?;
+		let new_ticket_size = bid.original_ct_usd_price.checked_mul_int(new_ct_amount).ok_or(Error::<T>::BadMath)?;
+
+		// * Validity checks *
+		ensure!(matches!(project_details.status, ProjectStatus::AuctionRound), Error::<T>::IncorrectRound);
+		ensure!(new_ct_amount > Zero::zero(), Error::<T>::TooLow);
+		ensure!(new_ct_amount < bid.original_ct_amount, Error::<T>::TooHigh);
+		ensure!(
+			metadata_ticket_size_bounds.usd_ticket_above_minimum_per_participation(new_ticket_size),
+			Error::<T>::TooLow
+		);
+
+		// * Calculate new variables *
+		let kept_percentage = Perquintill::from_rational(new_ct_amount, bid.original_ct_amount);
+		let new_plmc_bond = kept_percentage * bid.plmc_bond;
+		let new_funding_asset_amount = kept_percentage * bid.funding_asset_amount_locked;
+		let plmc_released = bid.plmc_bond.saturating_sub(new_plmc_bond);
+		let funding_amount_released = bid.funding_asset_amount_locked.saturating_sub(new_funding_asset_amount);
+
+		// * Update Storage *
+		Self::restore_bucket(project_id, &bid, bid.original_ct_amount.saturating_sub(new_ct_amount))?;
+		Self::release_participation_bond(bidder, plmc_released)?;
+		Self::release_funding_asset(project_id, bidder, funding_amount_released, bid.funding_asset)?;
+
+		AuctionBoughtUSD::<T>::mutate((project_id, bid.did.clone()), |amount| {
+			amount.saturating_reduce(old_ticket_size.saturating_sub(new_ticket_size))
+		});
+		bid.original_ct_amount = new_ct_amount;
+		bid.plmc_bond = new_plmc_bond;
+		bid.funding_asset_amount_locked = new_funding_asset_amount;
+		Bids::<T>::insert((project_id, bidder, bid_id), &bid);
+
+		Self::deposit_event(Event::BidAmended {
+			project_id,
+			bidder: bidder.clone(),
+			id: bid_id,
+			ct_amount: new_ct_amount,
+			plmc_released,
+			funding_asset: bid.funding_asset,
+			funding_amount_released,
+		});
+
+		Ok(())
+	}
+
+	/// Give back the CTs of the bid to the project's bucket, if the bid was created by the latest bid call made to the
+	/// project. Older bids leave the bucket untouched, since later bids already moved the price past them.
+	/// The bids of a call split over several buckets are restored by price level, so all their CTs go back when they
+	/// are cancelled from the most expensive one down.
+	fn restore_bucket(project_id: ProjectId, bid: &BidInfoOf<T>, returned_amount: Balance) -> DispatchResult {
+		match LastBidIds::<T>::get(project_id) {
+			Some((first_id, last_id)) if (first_id..=last_id).contains(&bid.id) => {},
+			_ => return Ok(()),
+		}
+		let mut bucket = Buckets::<T>::get(project_id).ok_or(Error::<T>::BucketNotFound)?;
+		if bucket.revert(returned_amount, bid.original_ct_usd_price) {
+			Buckets::<T>::insert(project_id, bucket);
+		}
+		Ok(())
+	}
 }
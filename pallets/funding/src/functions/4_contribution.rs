@@ -34,8 +34,10 @@ impl<T: Config> Pallet<T> {
 		let now = <frame_system::Pallet<T>>::block_number();
 		let remainder_started = now >= remainder_start;
 		let round_end = project_details.round_duration.end().ok_or(Error::<T>::ImpossibleState)?;
+		Self::ensure_participations_allowed(project_id)?;
 		ensure!(!did_has_winning_bid || remainder_started, Error::<T>::UserHasWinningBid);
 		ensure!(now < round_end, Error::<T>::TooLateForRound);
+		Self::ensure_fresh_prices(funding_asset)?;
 
 		let buyable_tokens = token_amount.min(project_details.remaining_contribution_tokens);
 		if buyable_tokens.is_zero() {
@@ -99,6 +101,7 @@ impl<T: Config> Pallet<T> {
 			project_metadata.participation_currencies.contains(&funding_asset),
 			Error::<T>::FundingAssetNotAccepted
 		);
+		Self::ensure_funding_asset_enabled(funding_asset)?;
 		ensure!(did.clone() != project_details.issuer_did, Error::<T>::ParticipationToOwnProject);
 		ensure!(
 			caller_existing_contributions.len() < T::MaxContributionsPerUser::get() as usize,
@@ -114,8 +117,9 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::TooHigh
 		);
 
-		let plmc_bond = Self::calculate_plmc_bond(ticket_size, multiplier)?;
-		let funding_asset_amount = Self::calculate_funding_asset_amount(ticket_size, funding_asset)?;
+		let plmc_bond = Self::calculate_plmc_bond(ticket_size, multiplier, project_metadata.price_kind)?;
+		let funding_asset_amount =
+			Self::calculate_funding_asset_amount(ticket_size, funding_asset, project_metadata.price_kind)?;
 
 		let contribution_id = NextContributionId::<T>::get();
 		let new_contribution = ContributionInfoOf::<T> {
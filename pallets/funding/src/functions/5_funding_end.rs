@@ -14,27 +14,25 @@ impl<T: Config> Pallet<T> {
 	/// Update the project information with the new round status.
 	///
 	/// # Success Path
-	/// The validity checks pass, and either of 2 paths happen:
+	/// The validity checks pass, and either of 3 paths happen:
 	///
-	/// * Project achieves its funding target - the project info is set to a successful funding state,
-	/// and the contribution token asset class is created with the same id as the project.
+	/// * Project reaches the [`FundingAutoSuccessThreshold`](Config::FundingAutoSuccessThreshold) - the project info
+	/// is set to a successful funding state.
 	///
-	/// * Project doesn't achieve its funding target - the project info is set to an unsuccessful funding state.
+	/// * Project reaches the [`FundingSuccessThreshold`](Config::FundingSuccessThreshold) but not the automatic one - the
+	/// project waits for the issuer to call [`decide_project_outcome`](Pallet::decide_project_outcome) during the
+	/// [`ManualAcceptanceDuration`](Config::ManualAcceptanceDuration).
 	///
-	/// # Next step
-	/// If **successful**, bidders can claim:
-	///	* Contribution tokens with [`vested_contribution_token_bid_mint_for`](Self::vested_contribution_token_bid_mint_for)
-	/// * Bonded plmc with [`vested_plmc_bid_unbond_for`](Self::vested_plmc_bid_unbond_for)
-	///
-	/// And contributors can claim:
-	/// * Contribution tokens with [`vested_contribution_token_purchase_mint_for`](Self::vested_contribution_token_purchase_mint_for)
-	/// * Bonded plmc with [`vested_plmc_purchase_unbond_for`](Self::vested_plmc_purchase_unbond_for)
+	/// * Project doesn't reach the [`FundingSuccessThreshold`](Config::FundingSuccessThreshold) - the project info is
+	/// set to an unsuccessful funding state.
 	///
-	/// If **unsuccessful**, users every user should have their PLMC vesting unbonded.
+	/// # Next step
+	/// Once the funding is successful or failed, the settlement can be started with
+	/// [`start_settlement`](Pallet::start_settlement).
 	#[transactional]
 	pub fn do_end_funding(project_id: ProjectId) -> DispatchResult {
 		// * Get variables *
-		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 		let remaining_cts = project_details.remaining_contribution_tokens;
 		let now = <frame_system::Pallet<T>>::block_number();
 		let issuer_did = project_details.issuer_did.clone();
@@ -56,17 +54,95 @@ impl<T: Config> Pallet<T> {
 		// * Update Storage *
 		DidWithActiveProjects::<T>::set(issuer_did, None);
 
-		let next_status = if funding_ratio < T::FundingSuccessThreshold::get() {
-			project_details.evaluation_round_info.evaluators_outcome = Some(EvaluatorsOutcome::Slashed);
-			ProjectStatus::FundingFailed
+		if funding_ratio < T::FundingSuccessThreshold::get() {
+			Self::finalize_funding(project_id, project_details, FundingOutcomeDecision::RejectFunding)
+		} else if funding_ratio < T::FundingAutoSuccessThreshold::get() {
+			Self::transition_project(
+				project_id,
+				project_details.clone(),
+				project_details.status,
+				ProjectStatus::AwaitingDecision,
+				Some(T::ManualAcceptanceDuration::get()),
+				true,
+			)
 		} else {
-			let reward_info = Self::generate_evaluator_rewards_info(project_id)?;
-			project_details.evaluation_round_info.evaluators_outcome = Some(EvaluatorsOutcome::Rewarded(reward_info));
-			ProjectStatus::FundingSuccessful
+			Self::finalize_funding(project_id, project_details, FundingOutcomeDecision::AcceptFunding)
+		}
+	}
+
+	/// Called by the issuer of a project awaiting a decision, to accept or reject its funding.
+	///
+	/// # Arguments
+	/// * `issuer` - The account of the project issuer
+	/// * `project_id` - The project identifier
+	/// * `decision` - Whether the funding is accepted or rejected
+	///
+	/// # Next step
+	/// If the issuer does not decide before the end of the round, the
+	/// [`DefaultFundingDecision`](Config::DefaultFundingDecision) is applied by `on_initialize`.
+	#[transactional]
+	pub fn do_decide_project_outcome(
+		issuer: AccountIdOf<T>,
+		project_id: ProjectId,
+		decision: FundingOutcomeDecision,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		// * Validity checks *
+		ensure!(project_details.issuer_account == issuer, Error::<T>::NotIssuer);
+		ensure!(project_details.status == ProjectStatus::AwaitingDecision, Error::<T>::IncorrectRound);
+		ensure!(!project_details.round_duration.ended(now), Error::<T>::TooLateForRound);
+
+		// * Update storage *
+		Self::finalize_funding(project_id, project_details, decision)
+	}
+
+	/// Called by `on_initialize` once the decision window of a project is over, to apply the
+	/// [`DefaultFundingDecision`](Config::DefaultFundingDecision).
+	#[transactional]
+	pub fn do_apply_default_project_decision(project_id: ProjectId) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		// * Validity checks *
+		ensure!(project_details.status == ProjectStatus::AwaitingDecision, Error::<T>::IncorrectRound);
+		ensure!(project_details.round_duration.ended(now), Error::<T>::TooEarlyForRound);
+
+		// * Update storage *
+		Self::finalize_funding(project_id, project_details, T::DefaultFundingDecision::get())
+	}
+
+	/// Sets the evaluators outcome according to `decision`, and moves the project to the matching funding state.
+	fn finalize_funding(
+		project_id: ProjectId,
+		mut project_details: ProjectDetailsOf<T>,
+		decision: FundingOutcomeDecision,
+	) -> DispatchResult {
+		let next_status = match decision {
+			FundingOutcomeDecision::AcceptFunding => {
+				// Later changes to the reward parameters should not affect this project
+				ProjectRewardParameters::<T>::insert(project_id, Self::active_reward_parameters()?);
+				let reward_info = Self::generate_evaluator_rewards_info(project_id)?;
+				project_details.evaluation_round_info.evaluators_outcome =
+					Some(EvaluatorsOutcome::Rewarded(reward_info));
+				ProjectStatus::FundingSuccessful
+			},
+			FundingOutcomeDecision::RejectFunding => {
+				project_details.evaluation_round_info.evaluators_outcome = Some(EvaluatorsOutcome::Slashed);
+				ProjectStatus::FundingFailed
+			},
 		};
 
+		let awaited_decision = project_details.status == ProjectStatus::AwaitingDecision;
 		Self::transition_project(project_id, project_details.clone(), project_details.status, next_status, None, true)?;
 
+		if awaited_decision {
+			Self::deposit_event(Event::ProjectOutcomeDecided { project_id, decision });
+		}
+
 		Ok(())
 	}
 }
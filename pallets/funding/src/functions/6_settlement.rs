@@ -4,6 +4,7 @@ use crate::{traits::VestingDurationCalculation, Balance};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
+	storage::with_storage_layer,
 	traits::{
 		fungible::MutateHold as FungibleMutateHold,
 		fungibles::Mutate as FungiblesMutate,
@@ -30,6 +31,9 @@ impl<T: Config> Pallet<T> {
 		let now = <frame_system::Pallet<T>>::block_number();
 
 		project_details.funding_end_block = Some(now);
+		// Register the project for the batch and on_idle settlement
+		SettlementCursors::<T>::insert(project_id, SettlementCursorOf::<T>::default());
+		ProjectsToSettleOnIdle::<T>::insert(project_id, ());
 
 		let escrow_account = Self::fund_account_id(project_id);
 		if project_details.status == ProjectStatus::FundingSuccessful {
@@ -139,7 +143,8 @@ impl<T: Config> Pallet<T> {
 		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let funding_success =
 			matches!(project_details.status, ProjectStatus::SettlementStarted(FundingOutcome::Success));
-		let wap = project_details.weighted_average_price.ok_or(Error::<T>::ImpossibleState)?;
+		// A project failed by the `AdminOrigin` during the auction has no WAP
+		let maybe_wap = project_details.weighted_average_price;
 
 		ensure!(
 			matches!(project_details.status, ProjectStatus::SettlementStarted(..)) || bid.status == BidStatus::Rejected,
@@ -149,7 +154,7 @@ impl<T: Config> Pallet<T> {
 		// Return either the full amount to refund if bid is rejected/project failed,
 		// or a partial amount when the wap > paid price/bid is partially accepted
 		let BidRefund { final_ct_usd_price, final_ct_amount, refunded_plmc, refunded_funding_asset_amount } =
-			Self::calculate_refund(&bid, funding_success, wap)?;
+			Self::calculate_refund(&bid, funding_success, maybe_wap, project_metadata.price_kind)?;
 
 		Self::release_participation_bond(&bid.bidder, refunded_plmc)?;
 		Self::release_funding_asset(project_id, &bid.bidder, refunded_funding_asset_amount, bid.funding_asset)?;
@@ -184,11 +189,13 @@ impl<T: Config> Pallet<T> {
 				vesting_info.duration,
 			)?;
 
-			Self::release_funding_asset(
+			Self::pay_out_funding_asset(
 				project_id,
-				&project_metadata.funding_destination_account,
+				&project_metadata,
+				&bid.bidder,
 				bid.funding_asset_amount_locked.saturating_sub(refunded_funding_asset_amount),
 				bid.funding_asset,
+				final_ct_amount,
 			)?;
 		}
 
@@ -210,9 +217,13 @@ impl<T: Config> Pallet<T> {
 	fn calculate_refund(
 		bid: &BidInfoOf<T>,
 		funding_success: bool,
-		wap: PriceOf<T>,
+		maybe_wap: Option<PriceOf<T>>,
+		price_kind: PriceKind,
 	) -> Result<BidRefund<T>, DispatchError> {
-		let final_ct_usd_price = if bid.original_ct_usd_price > wap { wap } else { bid.original_ct_usd_price };
+		let final_ct_usd_price = match maybe_wap {
+			Some(wap) if bid.original_ct_usd_price > wap => wap,
+			_ => bid.original_ct_usd_price,
+		};
 
 		if bid.status == BidStatus::Rejected || !funding_success {
 			return Ok(BidRefund::<T> {
@@ -222,11 +233,13 @@ impl<T: Config> Pallet<T> {
 				refunded_funding_asset_amount: bid.funding_asset_amount_locked,
 			});
 		}
+		ensure!(maybe_wap.is_some(), Error::<T>::ImpossibleState);
 		let final_ct_amount = bid.final_ct_amount();
 
 		let new_ticket_size = final_ct_usd_price.checked_mul_int(final_ct_amount).ok_or(Error::<T>::BadMath)?;
-		let new_plmc_bond = Self::calculate_plmc_bond(new_ticket_size, bid.multiplier)?;
-		let new_funding_asset_amount = Self::calculate_funding_asset_amount(new_ticket_size, bid.funding_asset)?;
+		let new_plmc_bond = Self::calculate_plmc_bond(new_ticket_size, bid.multiplier, price_kind)?;
+		let new_funding_asset_amount =
+			Self::calculate_funding_asset_amount(new_ticket_size, bid.funding_asset, price_kind)?;
 		let refunded_plmc = bid.plmc_bond.saturating_sub(new_plmc_bond);
 		let refunded_funding_asset_amount = bid.funding_asset_amount_locked.saturating_sub(new_funding_asset_amount);
 
@@ -278,11 +291,13 @@ impl<T: Config> Pallet<T> {
 			Self::mint_contribution_tokens(project_id, &contribution.contributor, contribution.ct_amount)?;
 
 			// Payout the bid funding asset amount to the project account
-			Self::release_funding_asset(
+			Self::pay_out_funding_asset(
 				project_id,
-				&project_metadata.funding_destination_account,
+				&project_metadata,
+				&contribution.contributor,
 				contribution.funding_asset_amount,
 				contribution.funding_asset,
+				contribution.ct_amount,
 			)?;
 
 			// Create Migration
@@ -329,6 +344,8 @@ impl<T: Config> Pallet<T> {
 		);
 
 		// Mark the project as settled
+		SettlementCursors::<T>::remove(project_id);
+		ProjectsToSettleOnIdle::<T>::remove(project_id);
 		Self::transition_project(
 			project_id,
 			project_details,
@@ -341,6 +358,128 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Settles up to `limit` participations of a project, continuing from the stored [`SettlementCursors`] entry.
+	/// Participations that fail to settle are skipped, and can still be settled with their individual extrinsic.
+	/// Once the cursor went through all of them, the next call starts over to retry the skipped participations.
+	pub fn do_settle_project_batch(project_id: ProjectId, limit: u32) -> DispatchResultWithPostInfo {
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		ensure!(
+			matches!(project_details.status, ProjectStatus::SettlementStarted(..)),
+			Error::<T>::SettlementNotStarted
+		);
+
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		let mut cursor = SettlementCursors::<T>::get(project_id);
+		let mut used_weight = db_weight.reads_writes(2, 1);
+		if !cursor.has_remaining_operations() {
+			used_weight.saturating_accrue(db_weight.reads(3));
+			if Self::has_unsettled_participations(project_id) {
+				cursor = SettlementCursorOf::<T>::default();
+			}
+		}
+		let mut operations = 0u32;
+		while operations < limit && cursor.has_remaining_operations() {
+			match cursor.do_one_operation(project_id) {
+				Ok(weight) => used_weight.saturating_accrue(weight),
+				Err(e) => {
+					used_weight.saturating_accrue(Self::settlement_operation_weight());
+					log::warn!(
+						target: "pallet_funding::settlement",
+						"Batch settlement of project {:?} skipped a participation: {:?}",
+						project_id,
+						e
+					);
+				},
+			}
+			operations += 1;
+		}
+		SettlementCursors::<T>::insert(project_id, cursor);
+
+		Ok(PostDispatchInfo { actual_weight: Some(used_weight), pays_fee: Pays::Yes })
+	}
+
+	/// Called by `on_idle`. Settles the participations of the projects in [`ProjectsToSettleOnIdle`] with the leftover
+	/// block weight, and marks each project as settled once all its participations are gone.
+	/// If a project cannot be marked as settled because some participations failed to settle, it is removed from the
+	/// queue, and has to be finished with the settlement extrinsics.
+	pub(crate) fn do_settle_projects_on_idle(max_weight: Weight) -> Weight {
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		let operation_weight = Self::settlement_operation_weight();
+		let mark_weight = WeightInfoOf::<T>::mark_project_as_settled();
+		let mut used_weight = Weight::zero();
+
+		let mut projects = ProjectsToSettleOnIdle::<T>::iter_keys();
+		loop {
+			if used_weight.saturating_add(db_weight.reads_writes(3, 1)).any_gt(max_weight) {
+				break;
+			}
+			let Some(project_id) = projects.next() else { break };
+			used_weight.saturating_accrue(db_weight.reads_writes(3, 1));
+			// Settled through the extrinsics in the meantime
+			if !matches!(
+				ProjectsDetails::<T>::get(project_id).map(|details| details.status),
+				Some(ProjectStatus::SettlementStarted(_))
+			) {
+				ProjectsToSettleOnIdle::<T>::remove(project_id);
+				continue;
+			}
+
+			let mut cursor = SettlementCursors::<T>::get(project_id);
+			while cursor.has_remaining_operations() {
+				if used_weight.saturating_add(operation_weight).any_gt(max_weight) {
+					break;
+				}
+				match cursor.do_one_operation(project_id) {
+					Ok(weight) => used_weight.saturating_accrue(weight),
+					Err(e) => {
+						used_weight.saturating_accrue(operation_weight);
+						log::warn!(
+							target: "pallet_funding::settlement",
+							"Idle settlement of project {:?} skipped a participation: {:?}",
+							project_id,
+							e
+						);
+					},
+				}
+			}
+
+			// Out of weight. Continue from here on the next block.
+			if cursor.has_remaining_operations() || used_weight.saturating_add(mark_weight).any_gt(max_weight) {
+				SettlementCursors::<T>::insert(project_id, cursor);
+				break;
+			}
+
+			used_weight.saturating_accrue(mark_weight);
+			if let Err(e) = with_storage_layer(|| Self::do_mark_project_as_settled(project_id)) {
+				log::warn!(
+					target: "pallet_funding::settlement",
+					"Idle settlement could not mark project {:?} as settled: {:?}",
+					project_id,
+					e
+				);
+				// Don't retry the failed participations on every block.
+				SettlementCursors::<T>::insert(project_id, cursor);
+				ProjectsToSettleOnIdle::<T>::remove(project_id);
+			}
+		}
+
+		used_weight
+	}
+
+	/// Whether the project still has evaluations, bids or contributions left to settle.
+	fn has_unsettled_participations(project_id: ProjectId) -> bool {
+		Evaluations::<T>::iter_prefix((project_id,)).next().is_some() ||
+			Bids::<T>::iter_prefix((project_id,)).next().is_some() ||
+			Contributions::<T>::iter_prefix((project_id,)).next().is_some()
+	}
+
+	/// Worst case weight of settling a single participation.
+	pub fn settlement_operation_weight() -> Weight {
+		WeightInfoOf::<T>::settle_rewarded_evaluation()
+			.max(WeightInfoOf::<T>::settle_accepted_bid_with_refund())
+			.max(WeightInfoOf::<T>::settle_contribution_project_successful())
+	}
+
 	fn mint_contribution_tokens(
 		project_id: ProjectId,
 		participant: &AccountIdOf<T>,
@@ -353,7 +492,7 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	fn release_funding_asset(
+	pub(crate) fn release_funding_asset(
 		project_id: ProjectId,
 		participant: &AccountIdOf<T>,
 		amount: Balance,
@@ -367,7 +506,7 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	fn release_participation_bond(participant: &AccountIdOf<T>, amount: Balance) -> DispatchResult {
+	pub(crate) fn release_participation_bond(participant: &AccountIdOf<T>, amount: Balance) -> DispatchResult {
 		if amount.is_zero() {
 			return Ok(());
 		}
@@ -451,3 +590,69 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 }
+
+impl<T: Config> DoRemainingOperation<T> for SettlementCursorOf<T> {
+	fn has_remaining_operations(&self) -> bool {
+		!matches!(self, SettlementCursor::Finished)
+	}
+
+	/// Settles the next participation of the project. Storage maps without participations left are skipped over,
+	/// so one operation settles exactly one participation unless the cursor reaches the end.
+	fn do_one_operation(&mut self, project_id: ProjectId) -> Result<Weight, DispatchError> {
+		let mut weight = Weight::zero();
+		loop {
+			weight.saturating_accrue(<T as frame_system::Config>::DbWeight::get().reads(1));
+			match self.clone() {
+				SettlementCursor::Evaluations(last) => {
+					let mut evaluations = match last {
+						Some((account, id)) => Evaluations::<T>::iter_prefix_from(
+							(project_id,),
+							Evaluations::<T>::hashed_key_for((project_id, account, id)),
+						),
+						None => Evaluations::<T>::iter_prefix((project_id,)),
+					};
+					let Some(((evaluator, id), evaluation)) = evaluations.next() else {
+						*self = SettlementCursor::Bids(None);
+						continue;
+					};
+					*self = SettlementCursor::Evaluations(Some((evaluator, id)));
+					with_storage_layer(|| Pallet::<T>::do_settle_evaluation(evaluation, project_id))?;
+					return Ok(weight.saturating_add(WeightInfoOf::<T>::settle_rewarded_evaluation()));
+				},
+				SettlementCursor::Bids(last) => {
+					let mut bids = match last {
+						Some((account, id)) => Bids::<T>::iter_prefix_from(
+							(project_id,),
+							Bids::<T>::hashed_key_for((project_id, account, id)),
+						),
+						None => Bids::<T>::iter_prefix((project_id,)),
+					};
+					let Some(((bidder, id), bid)) = bids.next() else {
+						*self = SettlementCursor::Contributions(None);
+						continue;
+					};
+					*self = SettlementCursor::Bids(Some((bidder, id)));
+					with_storage_layer(|| Pallet::<T>::do_settle_bid(bid, project_id))?;
+					return Ok(weight.saturating_add(WeightInfoOf::<T>::settle_accepted_bid_with_refund()));
+				},
+				SettlementCursor::Contributions(last) => {
+					let mut contributions = match last {
+						Some((account, id)) => Contributions::<T>::iter_prefix_from(
+							(project_id,),
+							Contributions::<T>::hashed_key_for((project_id, account, id)),
+						),
+						None => Contributions::<T>::iter_prefix((project_id,)),
+					};
+					let Some(((contributor, id), contribution)) = contributions.next() else {
+						*self = SettlementCursor::Finished;
+						continue;
+					};
+					*self = SettlementCursor::Contributions(Some((contributor, id)));
+					with_storage_layer(|| Pallet::<T>::do_settle_contribution(contribution, project_id))?;
+					return Ok(weight.saturating_add(WeightInfoOf::<T>::settle_contribution_project_successful()));
+				},
+				SettlementCursor::Finished => return Ok(weight),
+			}
+		}
+	}
+}
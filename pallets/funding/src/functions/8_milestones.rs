@@ -0,0 +1,243 @@
+#[allow(clippy::wildcard_imports)]
+use super::*;
+use frame_support::traits::tokens::{DepositConsequence, Provenance};
+
+impl<T: Config> Pallet<T> {
+	/// Called during the settlement of a successful participation.
+	/// Pays out the funding assets to the issuer, or holds them in the project pot if the project has milestones.
+	pub(crate) fn pay_out_funding_asset(
+		project_id: ProjectId,
+		project_metadata: &ProjectMetadataOf<T>,
+		participant: &AccountIdOf<T>,
+		amount: Balance,
+		asset: AcceptedFundingAsset,
+		ct_amount: Balance,
+	) -> DispatchResult {
+		if project_metadata.milestones.is_empty() {
+			return Self::release_funding_asset(
+				project_id,
+				&project_metadata.funding_destination_account,
+				amount,
+				asset,
+			);
+		}
+
+		EscrowedFunds::<T>::mutate(project_id, asset, |escrow| {
+			escrow.deposited.saturating_accrue(amount);
+			escrow.remaining.saturating_accrue(amount);
+		});
+		EscrowShares::<T>::mutate(project_id, participant, |shares| shares.saturating_accrue(ct_amount));
+		EscrowDeposits::<T>::mutate((project_id, participant, asset), |paid| paid.saturating_accrue(amount));
+		ProjectEscrows::<T>::mutate(project_id, |escrow| escrow.total_shares.saturating_accrue(ct_amount));
+
+		Ok(())
+	}
+
+	/// Called by the issuer once the deliverable of a milestone is ready.
+	///
+	/// # Arguments
+	/// * `issuer` - The account of the project issuer
+	/// * `project_id` - The project identifier
+	/// * `milestone` - The index of the milestone in the project metadata
+	///
+	/// # Next step
+	/// The participants vote on the milestone during the [`MilestoneVotingDuration`](Config::MilestoneVotingDuration),
+	/// after which anyone can call [`resolve_milestone`](Pallet::resolve_milestone).
+	#[transactional]
+	pub fn do_submit_milestone(issuer: AccountIdOf<T>, project_id: ProjectId, milestone: u8) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		// * Validity checks *
+		ensure!(project_details.issuer_account == issuer, Error::<T>::NotIssuer);
+		ensure!(
+			matches!(
+				project_details.status,
+				ProjectStatus::SettlementFinished(FundingOutcome::Success) |
+					ProjectStatus::CTMigrationStarted |
+					ProjectStatus::CTMigrationFinished
+			),
+			Error::<T>::IncorrectRound
+		);
+		ensure!((milestone as usize) < project_metadata.milestones.len(), Error::<T>::MilestoneNotFound);
+		ensure!(!ProjectEscrows::<T>::get(project_id).refunding, Error::<T>::EscrowRefunding);
+		ensure!(MilestoneStatuses::<T>::get(project_id, milestone).is_none(), Error::<T>::MilestoneAlreadySubmitted);
+		Self::ensure_previous_milestones_approved(project_id, milestone)?;
+
+		// * Update storage *
+		let voting_end = now.saturating_add(T::MilestoneVotingDuration::get());
+		MilestoneStatuses::<T>::insert(
+			project_id,
+			milestone,
+			MilestoneStatus::Voting { end: voting_end, ayes: Zero::zero(), nays: Zero::zero() },
+		);
+
+		// * Emit events *
+		Self::deposit_event(Event::MilestoneSubmitted { project_id, milestone, voting_end });
+
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_vote_milestone(
+		voter: AccountIdOf<T>,
+		project_id: ProjectId,
+		milestone: u8,
+		approve: bool,
+	) -> DispatchResult {
+		// * Get variables *
+		let now = <frame_system::Pallet<T>>::block_number();
+		let Some(MilestoneStatus::Voting { end, mut ayes, mut nays }) =
+			MilestoneStatuses::<T>::get(project_id, milestone)
+		else {
+			return Err(Error::<T>::MilestoneNotInVoting.into());
+		};
+		let weight = EscrowShares::<T>::get(project_id, &voter);
+
+		// * Validity checks *
+		ensure!(now <= end, Error::<T>::TooLateForRound);
+		ensure!(!weight.is_zero(), Error::<T>::NoEscrowShares);
+		ensure!(!MilestoneVotes::<T>::contains_key((project_id, milestone, &voter)), Error::<T>::MilestoneAlreadyVoted);
+
+		// * Update storage *
+		if approve {
+			ayes.saturating_accrue(weight);
+		} else {
+			nays.saturating_accrue(weight);
+		}
+		MilestoneStatuses::<T>::insert(project_id, milestone, MilestoneStatus::Voting { end, ayes, nays });
+		MilestoneVotes::<T>::insert((project_id, milestone, &voter), approve);
+
+		// * Emit events *
+		Self::deposit_event(Event::MilestoneVoted { project_id, milestone, voter, approve, weight });
+
+		Ok(())
+	}
+
+	/// Approves the milestone if at least as much voting power approved it as rejected it.
+	/// A milestone nobody voted for is rejected.
+	#[transactional]
+	pub fn do_resolve_milestone(project_id: ProjectId, milestone: u8) -> DispatchResult {
+		// * Get variables *
+		let now = <frame_system::Pallet<T>>::block_number();
+		let Some(MilestoneStatus::Voting { end, ayes, nays }) = MilestoneStatuses::<T>::get(project_id, milestone)
+		else {
+			return Err(Error::<T>::MilestoneNotInVoting.into());
+		};
+
+		// * Validity checks *
+		ensure!(now > end, Error::<T>::TooEarlyForRound);
+
+		// * Update storage *
+		Self::finalize_milestone(project_id, milestone, !ayes.is_zero() && ayes >= nays)
+	}
+
+	#[transactional]
+	pub fn do_force_resolve_milestone(project_id: ProjectId, milestone: u8, approve: bool) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+
+		// * Validity checks *
+		ensure!(
+			matches!(
+				project_details.status,
+				ProjectStatus::SettlementFinished(FundingOutcome::Success) |
+					ProjectStatus::CTMigrationStarted |
+					ProjectStatus::CTMigrationFinished
+			),
+			Error::<T>::IncorrectRound
+		);
+		ensure!((milestone as usize) < project_metadata.milestones.len(), Error::<T>::MilestoneNotFound);
+		ensure!(!ProjectEscrows::<T>::get(project_id).refunding, Error::<T>::EscrowRefunding);
+		ensure!(
+			matches!(MilestoneStatuses::<T>::get(project_id, milestone), None | Some(MilestoneStatus::Voting { .. })),
+			Error::<T>::MilestoneAlreadySubmitted
+		);
+		Self::ensure_previous_milestones_approved(project_id, milestone)?;
+
+		// * Update storage *
+		Self::finalize_milestone(project_id, milestone, approve)
+	}
+
+	/// Pays out the participant's share of the funds that were still held when a milestone got rejected.
+	/// Each funding asset is split by what the participant paid in it. Refunds the participant's account cannot
+	/// receive, like dust below the asset's minimum balance, stay in the project pot and can be claimed again later.
+	#[transactional]
+	pub fn do_claim_escrow_refund(participant: AccountIdOf<T>, project_id: ProjectId) -> DispatchResult {
+		// * Get variables *
+		let escrow = ProjectEscrows::<T>::get(project_id);
+		let shares = EscrowShares::<T>::get(project_id, &participant);
+		let deposits: Vec<_> = EscrowDeposits::<T>::iter_prefix((project_id, &participant)).collect();
+
+		// * Validity checks *
+		ensure!(escrow.refunding, Error::<T>::EscrowNotRefunding);
+		ensure!(!shares.is_zero(), Error::<T>::NoEscrowShares);
+
+		// * Update storage *
+		let mut skipped_refund = false;
+		for (asset, paid) in deposits {
+			let escrow_balance = EscrowedFunds::<T>::get(project_id, asset);
+			let refund = Perquintill::from_rational(paid, escrow_balance.deposited) * escrow_balance.remaining;
+			if T::FundingCurrency::can_deposit(asset.id(), &participant, refund, Provenance::Extant) !=
+				DepositConsequence::Success
+			{
+				skipped_refund = true;
+				continue;
+			}
+			Self::release_funding_asset(project_id, &participant, refund, asset)?;
+			EscrowDeposits::<T>::remove((project_id, &participant, asset));
+		}
+		if !skipped_refund {
+			EscrowShares::<T>::remove(project_id, &participant);
+		}
+
+		// * Emit events *
+		Self::deposit_event(Event::EscrowRefunded { project_id, account: participant, shares });
+
+		Ok(())
+	}
+
+	fn ensure_previous_milestones_approved(project_id: ProjectId, milestone: u8) -> DispatchResult {
+		for previous in 0..milestone {
+			ensure!(
+				MilestoneStatuses::<T>::get(project_id, previous) == Some(MilestoneStatus::Approved),
+				Error::<T>::PreviousMilestoneNotApproved
+			);
+		}
+		Ok(())
+	}
+
+	/// Releases the share of the milestone to the issuer if approved. Otherwise, the remaining funds become refundable.
+	fn finalize_milestone(project_id: ProjectId, milestone: u8, approve: bool) -> DispatchResult {
+		if !approve {
+			ProjectEscrows::<T>::mutate(project_id, |escrow| escrow.refunding = true);
+			MilestoneStatuses::<T>::insert(project_id, milestone, MilestoneStatus::Rejected);
+			Self::deposit_event(Event::MilestoneRejected { project_id, milestone });
+			return Ok(());
+		}
+
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let share = project_metadata.milestones.get(milestone as usize).ok_or(Error::<T>::MilestoneNotFound)?.share;
+		// The last milestone releases everything left, so no funds get stuck due to rounding.
+		let is_last = milestone as usize == project_metadata.milestones.len().saturating_sub(1);
+
+		for (asset, mut escrow_balance) in EscrowedFunds::<T>::iter_prefix(project_id) {
+			let amount = if is_last {
+				escrow_balance.remaining
+			} else {
+				(share * escrow_balance.deposited).min(escrow_balance.remaining)
+			};
+			Self::release_funding_asset(project_id, &project_metadata.funding_destination_account, amount, asset)?;
+			escrow_balance.remaining.saturating_reduce(amount);
+			EscrowedFunds::<T>::insert(project_id, asset, escrow_balance);
+		}
+
+		MilestoneStatuses::<T>::insert(project_id, milestone, MilestoneStatus::Approved);
+		Self::deposit_event(Event::MilestoneApproved { project_id, milestone });
+
+		Ok(())
+	}
+}
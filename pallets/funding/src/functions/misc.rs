@@ -16,20 +16,23 @@ impl<T: Config> Pallet<T> {
 
 	pub fn create_bucket_from_metadata(metadata: &ProjectMetadataOf<T>) -> Result<BucketOf<T>, DispatchError> {
 		let auction_allocation_size = metadata.auction_round_allocation_percentage * metadata.total_allocation_size;
-		let bucket_delta_amount = Percent::from_percent(10) * auction_allocation_size;
-		let ten_percent_in_price: <T as Config>::Price =
-			PriceOf::<T>::checked_from_rational(1, 10).ok_or(Error::<T>::BadMath)?;
-		let bucket_delta_price: <T as Config>::Price = metadata.minimum_price.saturating_mul(ten_percent_in_price);
-
-		let bucket: BucketOf<T> =
-			Bucket::new(auction_allocation_size, metadata.minimum_price, bucket_delta_price, bucket_delta_amount);
+		let bucket: BucketOf<T> = Bucket::new(auction_allocation_size, metadata.minimum_price, metadata.bucket_curve);
 
 		Ok(bucket)
 	}
 
-	pub fn calculate_plmc_bond(ticket_size: Balance, multiplier: MultiplierOf<T>) -> Result<Balance, DispatchError> {
-		let plmc_usd_price = T::PriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS)
-			.ok_or(Error::<T>::PriceNotFound)?;
+	pub fn calculate_plmc_bond(
+		ticket_size: Balance,
+		multiplier: MultiplierOf<T>,
+		price_kind: PriceKind,
+	) -> Result<Balance, DispatchError> {
+		let plmc_usd_price = T::PriceProvider::get_decimals_aware_price_of_kind(
+			PLMC_FOREIGN_ID,
+			price_kind,
+			USD_DECIMALS,
+			PLMC_DECIMALS,
+		)
+		.ok_or(Error::<T>::PriceNotFound)?;
 		let usd_bond = multiplier.calculate_bonding_requirement::<T>(ticket_size).ok_or(Error::<T>::BadMath)?;
 		plmc_usd_price
 			.reciprocal()
@@ -41,17 +44,27 @@ impl<T: Config> Pallet<T> {
 	pub fn calculate_funding_asset_amount(
 		ticket_size: Balance,
 		asset_id: AcceptedFundingAsset,
+		price_kind: PriceKind,
 	) -> Result<Balance, DispatchError> {
+		let asset_decimals = Self::funding_asset_info(asset_id)?.decimals;
 		let asset_id = asset_id.id();
-		let asset_decimals = T::FundingCurrency::decimals(asset_id);
-		let asset_usd_price = T::PriceProvider::get_decimals_aware_price(asset_id, USD_DECIMALS, asset_decimals)
-			.ok_or(Error::<T>::PriceNotFound)?;
+		let asset_usd_price =
+			T::PriceProvider::get_decimals_aware_price_of_kind(asset_id, price_kind, USD_DECIMALS, asset_decimals)
+				.ok_or(Error::<T>::PriceNotFound)?;
 		asset_usd_price
 			.reciprocal()
 			.and_then(|recip| recip.checked_mul_int(ticket_size))
 			.ok_or(Error::<T>::BadMath.into())
 	}
 
+	/// New participations must not bond PLMC or lock funding assets against prices the oracle stopped updating.
+	pub fn ensure_fresh_prices(funding_asset: AcceptedFundingAsset) -> DispatchResult {
+		for asset_id in [PLMC_FOREIGN_ID, funding_asset.id()] {
+			T::PriceProvider::get_fresh_price(asset_id).map_err(Error::<T>::from)?;
+		}
+		Ok(())
+	}
+
 	// Based on the amount of tokens and price to buy, a desired multiplier, and the type of investor the caller is,
 	/// calculate the amount and vesting periods of bonded PLMC and reward CT tokens.
 	pub fn calculate_vesting_info(
@@ -74,6 +87,7 @@ impl<T: Config> Pallet<T> {
 		project_id: ProjectId,
 		auction_allocation_size: Balance,
 		wap: PriceOf<T>,
+		candle_auction_end: Option<BlockNumberFor<T>>,
 	) -> Result<(u32, u32), DispatchError> {
 		let mut bids = Bids::<T>::iter_prefix_values((project_id,)).collect::<Vec<_>>();
 		// temp variable to store the sum of the bids
@@ -84,7 +98,8 @@ impl<T: Config> Pallet<T> {
 			.into_iter()
 			.map(|mut bid| {
 				let buyable_amount = auction_allocation_size.saturating_sub(bid_token_amount_sum);
-				if buyable_amount.is_zero() {
+				let made_after_candle_end = candle_auction_end.is_some_and(|end_block| bid.when > end_block);
+				if buyable_amount.is_zero() || made_after_candle_end {
 					bid.status = BidStatus::Rejected;
 				} else if bid.original_ct_amount <= buyable_amount {
 					bid_token_amount_sum.saturating_accrue(bid.original_ct_amount);
@@ -129,6 +144,53 @@ impl<T: Config> Pallet<T> {
 		Ok((accepted_bid_len, rejected_bids.len() as u32))
 	}
 
+	/// Picks the effective end of a candle auction from [`Config::Randomness`]. The end is a block inside the
+	/// last `closing_phase` of the auction round, and never before the round started.
+	/// Fails while the randomness was already known when the auction ended, since bidders could have used it.
+	pub fn decide_candle_auction_end(
+		project_id: ProjectId,
+		project_details: &ProjectDetailsOf<T>,
+		closing_phase: Percent,
+	) -> Result<BlockNumberFor<T>, DispatchError> {
+		let auction_start = project_details.round_duration.start().ok_or(Error::<T>::ImpossibleState)?;
+		let auction_end = project_details.round_duration.end().ok_or(Error::<T>::ImpossibleState)?;
+		let auction_duration = auction_end.saturating_sub(auction_start).saturating_add(One::one());
+		let closing_phase_duration = closing_phase * auction_duration;
+
+		let (random_value, known_since) = T::Randomness::random(&(b"candle_auction", project_id).encode());
+		ensure!(known_since > auction_end, Error::<T>::CandleAuctionRandomnessNotReady);
+		let random_number: BlockNumberFor<T> = u32::decode(&mut random_value.as_ref()).unwrap_or_default().into();
+		let offset = random_number % closing_phase_duration.saturating_add(One::one());
+
+		Ok(auction_end.saturating_sub(offset).max(auction_start))
+	}
+
+	/// Rebuilds the bucket of a project as it was at `block`, by replaying the bids made until then.
+	/// Goes through at most [`Config::MaxBidsPerProject`] bids, which is accounted for in the `end_auction` weight.
+	pub fn bucket_at_block(
+		project_id: ProjectId,
+		project_metadata: &ProjectMetadataOf<T>,
+		block: BlockNumberFor<T>,
+	) -> Result<BucketOf<T>, DispatchError> {
+		let mut bucket = Self::create_bucket_from_metadata(project_metadata)?;
+		let mut bids = Bids::<T>::iter_prefix_values((project_id,))
+			.take(T::MaxBidsPerProject::get() as usize)
+			.filter(|bid| bid.when <= block)
+			.collect::<Vec<_>>();
+		bids.sort_by_key(|bid| bid.id);
+
+		for bid in bids {
+			let mut amount_left = bid.original_ct_amount;
+			while !amount_left.is_zero() {
+				let amount = amount_left.min(bucket.amount_left);
+				bucket.update(amount);
+				amount_left.saturating_reduce(amount);
+			}
+		}
+
+		Ok(bucket)
+	}
+
 	pub fn try_plmc_participation_lock(who: &T::AccountId, project_id: ProjectId, amount: Balance) -> DispatchResult {
 		// Check if the user has already locked tokens in the evaluation period
 		let user_evaluations = Evaluations::<T>::iter_prefix_values((project_id, who));
@@ -183,7 +245,8 @@ impl<T: Config> Pallet<T> {
 
 		// Determine how much funding has been achieved.
 		let funding_amount_reached = project_details.funding_amount_reached_usd;
-		let fee_usd = Self::compute_total_fee_from_brackets(funding_amount_reached);
+		let fee_brackets = Self::project_reward_parameters(project_id)?.fee_brackets;
+		let fee_usd = Self::compute_total_fee_from_brackets(fee_brackets.into_inner(), funding_amount_reached);
 		let fee_percentage = Perquintill::from_rational(fee_usd, funding_amount_reached);
 
 		let initial_token_allocation_size = project_metadata.total_allocation_size;
@@ -200,10 +263,10 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Computes the total fee from all defined fee brackets.
-	fn compute_total_fee_from_brackets(funding_reached: Balance) -> Balance {
+	fn compute_total_fee_from_brackets(fee_brackets: Vec<(Percent, Balance)>, funding_reached: Balance) -> Balance {
 		let mut remaining_for_fee = funding_reached;
 
-		T::FeeBrackets::get()
+		fee_brackets
 			.into_iter()
 			.map(|(fee, limit)| Self::compute_fee_for_bracket(&mut remaining_for_fee, fee, limit))
 			.fold(Balance::zero(), |acc, fee| acc.saturating_add(fee))
@@ -232,14 +295,15 @@ impl<T: Config> Pallet<T> {
 	pub fn generate_evaluator_rewards_info(project_id: ProjectId) -> Result<RewardInfo, DispatchError> {
 		// Fetching the necessary data for a specific project.
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let reward_parameters = Self::project_reward_parameters(project_id)?;
 		let total_fee_allocation = Self::calculate_fee_allocation(project_id)?;
 
 		// Calculate rewards.
-		let evaluator_rewards = Perquintill::from_percent(30) * total_fee_allocation;
+		let evaluator_rewards = reward_parameters.evaluators_share * total_fee_allocation;
 
 		// Distribute rewards between early and normal evaluators.
-		let early_evaluator_reward_pot = Perquintill::from_percent(20) * evaluator_rewards;
-		let normal_evaluator_reward_pot = Perquintill::from_percent(80) * evaluator_rewards;
+		let early_evaluator_reward_pot = reward_parameters.early_evaluators_share * evaluator_rewards;
+		let normal_evaluator_reward_pot = reward_parameters.normal_evaluators_share * evaluator_rewards;
 
 		let normal_evaluator_total_bonded_usd = project_details.evaluation_round_info.total_bonded_usd;
 		let early_evaluation_reward_threshold_usd =
@@ -261,17 +325,175 @@ impl<T: Config> Pallet<T> {
 	pub fn generate_liquidity_pools_and_long_term_holder_rewards(
 		project_id: ProjectId,
 	) -> Result<(Balance, Balance), DispatchError> {
+		let reward_parameters = Self::project_reward_parameters(project_id)?;
 		let total_fee_allocation = Self::calculate_fee_allocation(project_id)?;
 
-		let liquidity_pools_percentage = Perquintill::from_percent(50);
-		let liquidity_pools_reward_pot = liquidity_pools_percentage * total_fee_allocation;
-
-		let long_term_holder_percentage = Perquintill::from_percent(20);
-		let long_term_holder_reward_pot = long_term_holder_percentage * total_fee_allocation;
+		let liquidity_pools_reward_pot = reward_parameters.liquidity_pools_share * total_fee_allocation;
+		let long_term_holder_reward_pot = reward_parameters.long_term_holders_share * total_fee_allocation;
 
 		Ok((liquidity_pools_reward_pot, long_term_holder_reward_pot))
 	}
 
+	/// The reward parameters used before governance set any.
+	pub fn default_reward_parameters() -> Result<RewardParameters, DispatchError> {
+		RewardParameters::with_fee_brackets(T::FeeBrackets::get()).map_err(|_| Error::<T>::TooManyFeeBrackets.into())
+	}
+
+	/// The reward parameters that apply to projects reaching `FundingSuccessful` now.
+	pub fn active_reward_parameters() -> Result<RewardParameters, DispatchError> {
+		ActiveRewardParameters::<T>::get().map_or_else(Self::default_reward_parameters, Ok)
+	}
+
+	/// The reward parameters snapshotted for the project when it reached `FundingSuccessful`.
+	/// Projects that got there before the parameters were stored on chain keep the defaults they were funded with.
+	pub fn project_reward_parameters(project_id: ProjectId) -> Result<RewardParameters, DispatchError> {
+		ProjectRewardParameters::<T>::get(project_id).map_or_else(Self::default_reward_parameters, Ok)
+	}
+
+	pub fn do_set_reward_parameters(parameters: RewardParameters) -> DispatchResult {
+		ensure!(parameters.is_valid(), Error::<T>::InvalidRewardParameters);
+
+		ActiveRewardParameters::<T>::put(parameters.clone());
+		Self::deposit_event(Event::RewardParametersSet { parameters });
+
+		Ok(())
+	}
+
+	pub fn do_freeze_project(project_id: ProjectId) -> DispatchResult {
+		ensure!(ProjectsDetails::<T>::contains_key(project_id), Error::<T>::ProjectDetailsNotFound);
+		ensure!(!FrozenProjects::<T>::get(project_id), Error::<T>::ProjectFrozenByAdmin);
+
+		FrozenProjects::<T>::insert(project_id, true);
+		Self::deposit_event(Event::ProjectFrozen { project_id });
+
+		Ok(())
+	}
+
+	/// Unfreezes the project, and schedules again the automatic transition out of its round,
+	/// in case it was skipped while the project was frozen.
+	pub fn do_unfreeze_project(project_id: ProjectId) -> DispatchResult {
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		ensure!(FrozenProjects::<T>::get(project_id), Error::<T>::ProjectNotFrozenByAdmin);
+
+		FrozenProjects::<T>::remove(project_id);
+		if let Some(update_type) = UpdateType::for_status(&project_details.status) {
+			let update_block = project_details.round_duration.end().unwrap_or(now).max(now).saturating_add(One::one());
+			if let Err(e) = Self::schedule_project_update(project_id, update_type, update_block) {
+				log::warn!(
+					target: "pallet_funding::scheduler",
+					"Project {:?} could not be scheduled for an automatic transition: {:?}",
+					project_id,
+					e
+				);
+			}
+		}
+		Self::deposit_event(Event::ProjectUnfrozen { project_id });
+
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_force_fail_project(project_id: ProjectId) -> DispatchResult {
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let current_status = project_details.status.clone();
+
+		match current_status {
+			ProjectStatus::Application |
+			ProjectStatus::EvaluationRound |
+			ProjectStatus::AuctionRound |
+			ProjectStatus::CommunityRound(_) |
+			ProjectStatus::AwaitingDecision |
+			ProjectStatus::FundingSuccessful => (),
+			ProjectStatus::FundingFailed => return Err(Error::<T>::IncorrectRound.into()),
+			// Once the settlement started, participations were already paid out and cannot be refunded anymore.
+			ProjectStatus::SettlementStarted(_) |
+			ProjectStatus::SettlementFinished(_) |
+			ProjectStatus::CTMigrationStarted |
+			ProjectStatus::CTMigrationFinished => return Err(Error::<T>::SettlementAlreadyStarted.into()),
+		}
+
+		FrozenProjects::<T>::remove(project_id);
+		// Evaluators should not be slashed for the project failing, nor rewarded.
+		project_details.evaluation_round_info.evaluators_outcome = None;
+		ProjectRewardParameters::<T>::remove(project_id);
+		// Only clear the issuer's entry if it still points to this project, so the issuer can create a new one.
+		if DidWithActiveProjects::<T>::get(&project_details.issuer_did) == Some(project_id) {
+			DidWithActiveProjects::<T>::set(project_details.issuer_did.clone(), None);
+		}
+
+		Self::transition_project(
+			project_id,
+			project_details,
+			current_status,
+			ProjectStatus::FundingFailed,
+			None,
+			true,
+		)?;
+		Self::deposit_event(Event::ProjectForceFailed { project_id });
+
+		Ok(())
+	}
+
+	pub fn do_set_participations_paused(paused: bool) -> DispatchResult {
+		ParticipationsPaused::<T>::put(paused);
+		if paused {
+			Self::deposit_event(Event::ParticipationsPaused);
+		} else {
+			Self::deposit_event(Event::ParticipationsUnpaused);
+		}
+
+		Ok(())
+	}
+
+	/// Participations are rejected while the pallet is paused, or the project is frozen.
+	pub(crate) fn ensure_participations_allowed(project_id: ProjectId) -> DispatchResult {
+		ensure!(!ParticipationsPaused::<T>::get(), Error::<T>::ParticipationsArePaused);
+		ensure!(!FrozenProjects::<T>::get(project_id), Error::<T>::ProjectFrozenByAdmin);
+		Ok(())
+	}
+
+	pub fn do_register_funding_asset(asset: AcceptedFundingAsset, info: FundingAssetInfo) -> DispatchResult {
+		ensure!(AcceptedFundingAsset::from_id(asset.id()) == asset, Error::<T>::FundingAssetIdNotCanonical);
+		ensure!(!FundingAssets::<T>::contains_key(asset), Error::<T>::FundingAssetAlreadyRegistered);
+		ensure!(!FundingAssetLocations::<T>::contains_key(&info.location), Error::<T>::FundingAssetAlreadyRegistered);
+		ensure!(!FundingAssetOracleKeys::<T>::contains_key(info.oracle_key), Error::<T>::FundingAssetAlreadyRegistered);
+		ensure!(FundingAssets::<T>::count() < T::MaxFundingAssets::get(), Error::<T>::TooManyFundingAssets);
+
+		FundingAssetLocations::<T>::insert(&info.location, asset);
+		FundingAssetOracleKeys::<T>::insert(info.oracle_key, asset);
+		FundingAssets::<T>::insert(asset, &info);
+		Self::deposit_event(Event::FundingAssetRegistered { asset, info });
+
+		Ok(())
+	}
+
+	pub fn do_set_funding_asset_enabled(asset: AcceptedFundingAsset, enabled: bool) -> DispatchResult {
+		FundingAssets::<T>::try_mutate(asset, |maybe_info| -> DispatchResult {
+			let info = maybe_info.as_mut().ok_or(Error::<T>::FundingAssetNotRegistered)?;
+			info.enabled = enabled;
+			Ok(())
+		})?;
+		Self::deposit_event(Event::FundingAssetEnabledSet { asset, enabled });
+
+		Ok(())
+	}
+
+	pub fn funding_asset_info(asset: AcceptedFundingAsset) -> Result<FundingAssetInfo, DispatchError> {
+		FundingAssets::<T>::get(asset).ok_or(Error::<T>::FundingAssetNotRegistered.into())
+	}
+
+	/// New projects and participations can only use the registered funding assets that are enabled.
+	pub(crate) fn ensure_funding_asset_enabled(asset: AcceptedFundingAsset) -> DispatchResult {
+		ensure!(Self::funding_asset_info(asset)?.enabled, Error::<T>::FundingAssetDisabled);
+		Ok(())
+	}
+
+	/// The registered funding asset whose price the oracle publishes under `oracle_key`.
+	pub fn funding_asset_by_oracle_key(oracle_key: [u8; 8]) -> Option<AcceptedFundingAsset> {
+		FundingAssetOracleKeys::<T>::get(oracle_key)
+	}
+
 	pub fn migrations_per_xcm_message_allowed() -> u32 {
 		const MAX_WEIGHT: Weight = Weight::from_parts(20_000_000_000, 1_000_000);
 
@@ -379,6 +601,7 @@ impl<T: Config> Pallet<T> {
 		/* Verify */
 		let now = <frame_system::Pallet<T>>::block_number();
 		ensure!(project_details.status == current_round, Error::<T>::IncorrectRound);
+		ensure!(!FrozenProjects::<T>::get(project_id), Error::<T>::ProjectFrozenByAdmin);
 		ensure!(project_details.round_duration.ended(now) || skip_end_check, Error::<T>::TooEarlyForRound);
 
 		let round_end =
@@ -389,9 +612,139 @@ impl<T: Config> Pallet<T> {
 		// * Update storage *
 		ProjectsDetails::<T>::insert(project_id, project_details);
 
+		// * Schedule the automatic transition out of the new round *
+		if let Some(update_type) = UpdateType::for_status(&next_round) {
+			let update_block = round_end.unwrap_or(now).saturating_add(One::one());
+			if let Err(e) = Self::schedule_project_update(project_id, update_type, update_block) {
+				log::warn!(
+					target: "pallet_funding::scheduler",
+					"Project {:?} could not be scheduled for an automatic transition: {:?}",
+					project_id,
+					e
+				);
+			}
+		}
+
 		// * Emit events *
 		Self::deposit_event(Event::ProjectPhaseTransition { project_id, phase: next_round });
 
 		Ok(())
 	}
+
+	/// Adds a project transition to [`ProjectsToUpdate`] on the first block starting from `block` which still has space.
+	/// Returns the block in which the transition was scheduled.
+	pub(crate) fn schedule_project_update(
+		project_id: ProjectId,
+		update_type: UpdateType,
+		mut block: BlockNumberFor<T>,
+	) -> Result<BlockNumberFor<T>, DispatchError> {
+		for _ in 0..T::MaxProjectsToUpdateInsertionAttempts::get() {
+			if ProjectsToUpdate::<T>::try_append(block, (project_id, update_type.clone())).is_ok() {
+				return Ok(block);
+			}
+			block.saturating_inc();
+		}
+		Err(Error::<T>::TooManyInsertionAttempts.into())
+	}
+
+	/// Worst case weight of executing a scheduled project transition.
+	pub(crate) fn project_update_weight(update_type: &UpdateType) -> Weight {
+		match update_type {
+			UpdateType::EvaluationEnd => WeightInfoOf::<T>::end_evaluation_failure(),
+			UpdateType::AuctionEnd => {
+				WeightInfoOf::<T>::end_auction(T::MaxBidsPerProject::get() / 2, T::MaxBidsPerProject::get() / 2)
+					.max(WeightInfoOf::<T>::end_auction(T::MaxBidsPerProject::get(), 0u32))
+					.max(WeightInfoOf::<T>::end_auction(0u32, T::MaxBidsPerProject::get()))
+			},
+			UpdateType::FundingEnd => WeightInfoOf::<T>::end_funding_project_successful(),
+			UpdateType::StartSettlement => WeightInfoOf::<T>::start_settlement(),
+			UpdateType::ProjectDecision => WeightInfoOf::<T>::decide_project_outcome(),
+		}
+	}
+
+	/// Called by `on_initialize`. Executes the project transitions scheduled for block `now`.
+	/// Transitions that exceed the [`MaxTransitionWeightPerBlock`](Config::MaxTransitionWeightPerBlock) budget are moved
+	/// to the next block. The first transition is always executed, so a single heavy transition cannot stall the queue.
+	pub(crate) fn do_execute_project_updates(now: BlockNumberFor<T>) -> Weight {
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		let updates = ProjectsToUpdate::<T>::take(now);
+		if updates.is_empty() {
+			return db_weight.reads(1);
+		}
+
+		let max_weight = T::MaxTransitionWeightPerBlock::get();
+		let mut used_weight = db_weight.reads_writes(1, 1);
+		let mut executed_updates = 0u32;
+		let mut deferred_updates = Vec::new();
+		for (project_id, update_type) in updates {
+			let update_weight = Self::project_update_weight(&update_type);
+			let within_budget = used_weight.saturating_add(update_weight).all_lte(max_weight);
+			// Keep the original order by deferring everything after the first transition that doesn't fit.
+			if deferred_updates.is_empty() && (within_budget || executed_updates == 0) {
+				executed_updates += 1;
+				used_weight.saturating_accrue(Self::execute_project_update(project_id, update_type, now));
+			} else {
+				deferred_updates.push((project_id, update_type));
+			}
+		}
+
+		let next_block = now.saturating_add(One::one());
+		for (project_id, update_type) in deferred_updates {
+			used_weight.saturating_accrue(db_weight.reads_writes(1, 1));
+			if let Err(e) = Self::schedule_project_update(project_id, update_type, next_block) {
+				log::warn!(
+					target: "pallet_funding::scheduler",
+					"Project {:?} could not be rescheduled for an automatic transition: {:?}",
+					project_id,
+					e
+				);
+			}
+		}
+
+		used_weight
+	}
+
+	/// Executes a scheduled project transition, and returns the weight it used.
+	fn execute_project_update(project_id: ProjectId, update_type: UpdateType, now: BlockNumberFor<T>) -> Weight {
+		let update_weight = Self::project_update_weight(&update_type);
+		let Some(project_details) = ProjectsDetails::<T>::get(project_id) else { return update_weight };
+		// The project was already moved forward with one of the manual extrinsics.
+		if UpdateType::for_status(&project_details.status) != Some(update_type.clone()) {
+			return update_weight;
+		}
+
+		let result = match &update_type {
+			UpdateType::EvaluationEnd => Self::do_end_evaluation(project_id),
+			UpdateType::AuctionEnd => match Self::do_end_auction(project_id) {
+				// Retried on every block, so the candle auction end is sampled on the first block that has
+				// randomness revealed after the auction, no matter when `end_auction` is called.
+				Err(e) if e.error == Error::<T>::CandleAuctionRandomnessNotReady.into() => {
+					let next_block = now.saturating_add(One::one());
+					if let Err(e) = Self::schedule_project_update(project_id, UpdateType::AuctionEnd, next_block) {
+						log::warn!(
+							target: "pallet_funding::scheduler",
+							"Project {:?} could not be rescheduled for an automatic transition: {:?}",
+							project_id,
+							e
+						);
+					}
+					return <T as frame_system::Config>::DbWeight::get().reads_writes(5, 1);
+				},
+				result => result.map(|_| ()).map_err(|e| e.error),
+			},
+			UpdateType::FundingEnd => Self::do_end_funding(project_id),
+			UpdateType::StartSettlement => Self::do_start_settlement(project_id),
+			UpdateType::ProjectDecision => Self::do_apply_default_project_decision(project_id),
+		};
+		if let Err(e) = result {
+			log::warn!(
+				target: "pallet_funding::scheduler",
+				"Automatic transition {:?} of project {:?} failed: {:?}",
+				update_type,
+				project_id,
+				e
+			);
+		}
+		update_weight
+	}
 }
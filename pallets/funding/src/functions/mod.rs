@@ -8,8 +8,7 @@ use frame_support::{
 	traits::{
 		fungible::{Mutate, MutateHold as FungibleMutateHold},
 		fungibles::{
-			metadata::{Inspect as MetadataInspect, Mutate as MetadataMutate},
-			Create, Inspect as FungibleInspect, Mutate as FungiblesMutate,
+			metadata::Mutate as MetadataMutate, Create, Inspect as FungibleInspect, Mutate as FungiblesMutate,
 		},
 		tokens::{Precision, Preservation},
 		Get,
@@ -39,6 +38,8 @@ mod ct_migration;
 mod evaluation;
 #[path = "5_funding_end.rs"]
 mod funding_end;
+#[path = "8_milestones.rs"]
+mod milestones;
 mod misc;
 #[path = "6_settlement.rs"]
 mod settlement;
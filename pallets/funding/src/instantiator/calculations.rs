@@ -649,7 +649,7 @@ impl<
 		}
 
 		// Fill first bucket
-		bucket.update(bucket.delta_amount * 10u128);
+		bucket.update(bucket.amount_left);
 
 		// Fill remaining buckets till we pass by the wap
 		loop {
@@ -666,8 +666,7 @@ impl<
 		}
 
 		// Go back one bucket
-		bucket.amount_left = bucket.delta_amount;
-		bucket.current_price = bucket.current_price - bucket.delta_price;
+		bucket.previous();
 
 		// Do a binary search on the amount to reach the desired wap
 		let mut lower_bound: Balance = Zero::zero();
@@ -701,7 +700,7 @@ impl<
 	where
 		F: FnMut(AccountIdOf<T>) -> AccountIdOf<T>,
 	{
-		if bucket.current_price == bucket.initial_price {
+		if bucket.index.is_zero() {
 			return vec![]
 		}
 		let auction_allocation =
@@ -713,7 +712,6 @@ impl<
 			bid
 		};
 
-		let step_amounts = ((bucket.current_price - bucket.initial_price) / bucket.delta_price).saturating_mul_int(1u8);
 		let last_bid_amount = bucket.delta_amount - bucket.amount_left;
 
 		let mut bids = Vec::new();
@@ -721,8 +719,8 @@ impl<
 		let first_bid = generate_bid(auction_allocation);
 		bids.push(first_bid);
 
-		for _i in 0u8..step_amounts - 1u8 {
-			let full_bucket_bid = generate_bid(bucket.delta_amount);
+		for (full_bucket_amount, _price) in bucket.buckets_until(bucket.index - 1) {
+			let full_bucket_bid = generate_bid(full_bucket_amount);
 			bids.push(full_bucket_bid);
 		}
 
@@ -382,6 +382,11 @@ impl<
 		}
 		let project_details = self.get_project_details(project_id);
 
+		// The transition might have already been executed by on_initialize
+		if project_details.status != original_state {
+			return project_details.status;
+		}
+
 		match project_details.status {
 			ProjectStatus::Application => {
 				self.execute(|| <Pallet<T>>::do_start_evaluation(issuer, project_id).unwrap());
@@ -395,6 +400,9 @@ impl<
 			ProjectStatus::CommunityRound(..) => {
 				self.execute(|| <Pallet<T>>::do_end_funding(project_id).unwrap());
 			},
+			ProjectStatus::AwaitingDecision => {
+				self.execute(|| <Pallet<T>>::do_apply_default_project_decision(project_id).unwrap());
+			},
 			ProjectStatus::FundingSuccessful | ProjectStatus::FundingFailed => {
 				self.execute(|| <Pallet<T>>::do_start_settlement(project_id).unwrap());
 			},
@@ -679,45 +687,30 @@ impl<
 		contributions: Vec<ContributionInfoOf<T>>,
 	) {
 		let project_metadata = self.get_project_metadata(project_id);
-		let mut total_expected_dot: Balance = Zero::zero();
-		let mut total_expected_usdt: Balance = Zero::zero();
-		let mut total_expected_usdc: Balance = Zero::zero();
+		let mut total_expected: BTreeMap<AcceptedFundingAsset, Balance> = BTreeMap::new();
 
 		for bid in bids {
-			match bid.funding_asset {
-				AcceptedFundingAsset::DOT => total_expected_dot += bid.funding_asset_amount_locked,
-				AcceptedFundingAsset::USDT => total_expected_usdt += bid.funding_asset_amount_locked,
-				AcceptedFundingAsset::USDC => total_expected_usdc += bid.funding_asset_amount_locked,
-			}
+			*total_expected.entry(bid.funding_asset).or_default() += bid.funding_asset_amount_locked;
 		}
 
 		for contribution in contributions {
-			match contribution.funding_asset {
-				AcceptedFundingAsset::DOT => total_expected_dot += contribution.funding_asset_amount,
-				AcceptedFundingAsset::USDT => total_expected_usdt += contribution.funding_asset_amount,
-				AcceptedFundingAsset::USDC => total_expected_usdc += contribution.funding_asset_amount,
-			}
+			*total_expected.entry(contribution.funding_asset).or_default() += contribution.funding_asset_amount;
 		}
 
-		let total_stored_dot = self.get_free_funding_asset_balances_for(
-			AcceptedFundingAsset::DOT.id(),
-			vec![project_metadata.funding_destination_account.clone()],
-		)[0]
-		.asset_amount;
-		let total_stored_usdt = self.get_free_funding_asset_balances_for(
-			AcceptedFundingAsset::USDT.id(),
-			vec![project_metadata.funding_destination_account.clone()],
-		)[0]
-		.asset_amount;
-		let total_stored_usdc = self.get_free_funding_asset_balances_for(
-			AcceptedFundingAsset::USDC.id(),
-			vec![project_metadata.funding_destination_account.clone()],
-		)[0]
-		.asset_amount;
-
-		assert_eq!(total_expected_dot, total_stored_dot, "DOT amount is incorrect");
-		assert_eq!(total_expected_usdt, total_stored_usdt, "USDT amount is incorrect");
-		assert_eq!(total_expected_usdc, total_stored_usdc, "USDC amount is incorrect");
+		let funding_assets = self.execute(|| FundingAssets::<T>::iter_keys().collect::<Vec<_>>());
+		for funding_asset in funding_assets {
+			let total_stored = self.get_free_funding_asset_balances_for(
+				funding_asset.id(),
+				vec![project_metadata.funding_destination_account.clone()],
+			)[0]
+			.asset_amount;
+			assert_eq!(
+				total_expected.get(&funding_asset).copied().unwrap_or_default(),
+				total_stored,
+				"Amount of funding asset {:?} is incorrect",
+				funding_asset
+			);
+		}
 	}
 
 	// Used to check if all evaluations are settled correctly. We cannot check amount of
@@ -1003,7 +996,11 @@ impl<
 			assert_eq!(self.get_plmc_total_supply(), post_supply);
 		}
 
-		let status = self.go_to_next_state(project_id);
+		let mut status = self.go_to_next_state(project_id);
+		// Let the default decision apply to projects funded between the success thresholds
+		if status == ProjectStatus::AwaitingDecision {
+			status = self.go_to_next_state(project_id);
+		}
 
 		if status == ProjectStatus::FundingSuccessful {
 			// Check that remaining CTs are updated
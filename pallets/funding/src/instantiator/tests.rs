@@ -38,6 +38,9 @@ fn dry_run_wap() {
 		mainnet_token_max_supply: 8_000_000 * CT_UNIT,
 		total_allocation_size: 100_000 * CT_UNIT,
 		auction_round_allocation_percentage: Percent::from_percent(50u8),
+		candle_auction_closing_phase: None,
+		bucket_curve: BucketCurve::default(),
+		price_kind: PriceKind::Spot,
 		minimum_price: decimal_aware_price,
 		bidding_ticket_sizes: BiddingTicketSizes {
 			professional: TicketSize::new(5000 * USD_UNIT, None),
@@ -52,6 +55,7 @@ fn dry_run_wap() {
 		},
 		participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 		funding_destination_account: 0u32,
+		milestones: Default::default(),
 		policy_ipfs_cid: Some(metadata_hash),
 	};
 
@@ -120,6 +124,9 @@ fn find_bucket_for_wap() {
 		mainnet_token_max_supply: 8_000_000 * CT_UNIT,
 		total_allocation_size: 100_000 * CT_UNIT,
 		auction_round_allocation_percentage: Percent::from_percent(50u8),
+		candle_auction_closing_phase: None,
+		bucket_curve: BucketCurve::default(),
+		price_kind: PriceKind::Spot,
 		minimum_price: decimal_aware_price,
 		bidding_ticket_sizes: BiddingTicketSizes {
 			professional: TicketSize::new(5000 * USD_UNIT, None),
@@ -134,6 +141,7 @@ fn find_bucket_for_wap() {
 		},
 		participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 		funding_destination_account: 0u32,
+		milestones: Default::default(),
 		policy_ipfs_cid: Some(metadata_hash),
 	};
 
@@ -43,17 +43,19 @@
 //! 1) **Project Creation**: Issuer creates a project with the [`create_project`](Pallet::create_project) extrinsic.
 //! 2) **Evaluation Start**: Issuer starts the evaluation round with the [`start_evaluation`](Pallet::start_evaluation) extrinsic.
 //! 3) **Evaluate**: Evaluators bond PLMC to evaluate a project with the [`evaluate`](Pallet::evaluate) extrinsic.
-//! 4) **Evaluation End**: Anyone can end the evaluation round with the [`end_evaluation`](Pallet::end_evaluation) extrinsic after the defined end block.
-//! 5) **Auction Start**: If the project receives at least 10% of its target funding (in USD) in PLMC bonded, the auction starts immediately after `end_evaluation` is called.
-//! 6) **Bid**: Professional and institutional investors can place bids on the project using the [`bid`](Pallet::bid) extrinsic. The price starts at the issuer-defined minimum, and increases by increments of 10% in price and bucket size.
-//! 7) **Auction End**: Anyone can end the auction round with the [`end_auction`](Pallet::end_auction) extrinsic after the defined end block.
-//! 8) **Community Round Start**: After `end_auction` is called, a weighted average price is calculated from the bids, and the community round starts.
+//! 4) **Evaluation End**: The evaluation round ends automatically on the block after its defined end block. Anyone can also end it with the [`end_evaluation`](Pallet::end_evaluation) extrinsic.
+//! 5) **Auction Start**: If the project receives at least 10% of its target funding (in USD) in PLMC bonded, the auction starts immediately after the evaluation round ends.
+//! 6) **Bid**: Professional and institutional investors can place bids on the project using the [`bid`](Pallet::bid) extrinsic. The price starts at the issuer-defined minimum, and increases following the bucket curve chosen by the issuer (by default, increments of 10% in price and bucket size).
+//!     Until the auction ends, bidders can withdraw a bid with [`cancel_bid`](Pallet::cancel_bid), or lower it with [`amend_bid`](Pallet::amend_bid).
+//! 7) **Auction End**: The auction round ends automatically on the block after its defined end block. Anyone can also end it with the [`end_auction`](Pallet::end_auction) extrinsic.
+//!     If the issuer opted for a candle auction, its effective end is picked at random from the closing phase, using randomness revealed after the auction, and bids made after it are rejected.
+//! 8) **Community Round Start**: After the auction ends, a weighted average price is calculated from the bids, and the community round starts.
 //! 9) **Contribute**: Anyone without a winning bid can now contribute at the weighted average price with the [`contribute`](Pallet::contribute) extrinsic.
 //! 10) **Remainder Round Start**: After a defined [period](<T as Config>::CommunityRoundDuration), the remainder round starts.
 //! 11) **Contribute**: Participants with winning bids can also contribute at the weighted average price with the [`contribute`](Pallet::contribute) extrinsic.
-//! 12) **Funding End**: Anyone can end the project with the [`end_project`](Pallet::end_project) extrinsic after the defined end block.
+//! 12) **Funding End**: The funding ends automatically on the block after the remainder round end block. Anyone can also end it with the [`end_funding`](Pallet::end_funding) extrinsic.
 //!     The project will now be considered Failed if it reached <=33% of its target funding in USD, and Successful otherwise.
-//! 13) **Settlement Start**: Anyone can start the settlement process with the [`start_settlement`](Pallet::start_settlement) extrinsic after the defined end block.
+//! 13) **Settlement Start**: The settlement process starts automatically on the block after the funding ends. Anyone can also start it with the [`start_settlement`](Pallet::start_settlement) extrinsic.
 //! 14) **Settle Evaluation**: Anyone can now settle an evaluation with the [`settle_evaluation`](Pallet::settle_evaluation) extrinsic.
 //!     This will unlock the PLMC bonded, and either apply a slash to the PLMC, or reward CTs to the evaluator.
 //! 15) **Settle Bid**: Anyone can now settle a bid with the [`settle_bid`](Pallet::settle_bid) extrinsic.
@@ -61,10 +63,16 @@
 //!     or the price paid was higher than the weighted average price.
 //! 16) **Settle Contribution**: Anyone can now settle a contribution with the [`settle_contribution`](Pallet::settle_contribution) extrinsic.
 //!     This will set a vesting schedule on the PLMC bonded, and pay out the funding assets to the issuer.
+//!     Evaluations, bids and contributions can also be settled in bulk with the [`settle_project_batch`](Pallet::settle_project_batch) extrinsic.
 //! 17) **Settlement End**: Anyone can now mark the project settlement as finished by calling the [`mark_project_as_settled`](Pallet::mark_project_as_settled) extrinsic.
+//!     Settlement also progresses on its own: `on_idle` settles pending participations with the leftover block weight,
+//!     and marks the project as settled once none are left.
 //! 18) **Migration Start**: Once the issuer has tokens to distribute on mainnet, he can start the migration process with the [`start_offchain`](Pallet::start_offchain_migration) extrinsic.
 //! 19) **Confirm Migration**: The issuer has to mark each participant's CTs as migrated with the [`confirm_offchain_migration`](Pallet::confirm_offchain_migration) extrinsic.
 //! 20) **Migration End**: Once all participants have migrated their CTs, anyone can mark the migration as finished with the [`mark_project_ct_migration_as_finished`](Pallet::mark_project_ct_migration_as_finished) extrinsic.
+//!
+//! The automatic transitions are stored in [`ProjectsToUpdate`], indexed by the block in which they should be executed,
+//! and run in `on_initialize` within the [`MaxTransitionWeightPerBlock`](Config::MaxTransitionWeightPerBlock) budget.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -139,6 +147,8 @@ pub type BidInfoOf<T> = BidInfo<ProjectId, Did, PriceOf<T>, AccountIdOf<T>, Bloc
 pub type ContributionInfoOf<T> =
 	ContributionInfo<u32, Did, ProjectId, AccountIdOf<T>, BlockNumberFor<T>, MultiplierOf<T>>;
 
+pub type SettlementCursorOf<T> = SettlementCursor<AccountIdOf<T>>;
+
 pub type BucketOf<T> = Bucket<PriceOf<T>>;
 pub type WeightInfoOf<T> = <T as Config>::WeightInfo;
 pub type VestingOf<T> = pallet_linear_release::Pallet<T>;
@@ -186,6 +196,9 @@ pub mod pallet {
 		/// A way to convert from and to the account type used in CT migrations
 		type AccountId32Conversion: ConvertBack<Self::AccountId, [u8; 32]>;
 
+		/// The origin allowed to freeze or fail any project, and to pause all participations.
+		type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
 		/// Type used for testing and benchmarks
 		#[cfg(any(test, feature = "runtime-benchmarks", feature = "std"))]
 		type AllPalletsWithoutSystem: OnFinalize<BlockNumberFor<Self>>
@@ -218,6 +231,11 @@ pub mod pallet {
 		/// Convert 24 hours as FixedU128, to the corresponding amount of blocks in the same type as frame_system
 		type DaysToBlocks: Convert<FixedU128, BlockNumberFor<Self>>;
 
+		/// The decision applied to a project awaiting one, if the issuer did not submit it within the
+		/// [`ManualAcceptanceDuration`](Config::ManualAcceptanceDuration).
+		#[pallet::constant]
+		type DefaultFundingDecision: Get<FundingOutcomeDecision>;
+
 		/// The length (expressed in number of blocks) of the Auction Round.
 		#[pallet::constant]
 		type AuctionRoundDuration: Get<BlockNumberFor<Self>>;
@@ -234,10 +252,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type EvaluatorSlash: Get<Percent>;
 
-		/// The fee brackets for the project's funding
+		/// The fee brackets for the project's funding, used until governance sets the reward parameters.
 		#[pallet::constant]
 		type FeeBrackets: Get<Vec<(Percent, Balance)>>;
 
+		/// The origin allowed to register funding assets, and to enable or disable them.
+		type FundingAssetOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
 		/// The currency used for funding projects in bids and contributions
 		type FundingCurrency: fungibles::InspectEnumerable<AccountIdOf<Self>, Balance = Balance, AssetId = u32>
 			+ fungibles::metadata::Inspect<AccountIdOf<Self>, AssetId = u32>
@@ -246,12 +267,23 @@ pub mod pallet {
 
 		type FundingSuccessThreshold: Get<Perquintill>;
 
+		/// Percentage of the funding target from which a project is successful without a decision of the issuer.
+		/// Projects funded between the [`FundingSuccessThreshold`](Config::FundingSuccessThreshold) and this value
+		/// wait for the issuer to accept or reject the funding.
+		#[pallet::constant]
+		type FundingAutoSuccessThreshold: Get<Perquintill>;
+
 		/// Credentialized investor Origin, ensures users are of investing type Retail, or Professional, or Institutional.
 		type InvestorOrigin: EnsureOriginWithCredentials<
 			<Self as frame_system::Config>::RuntimeOrigin,
 			Success = (AccountIdOf<Self>, Did, InvestorType, Cid),
 		>;
 
+		/// The length (expressed in number of blocks) the issuer has to decide on the outcome of a project awaiting a
+		/// decision.
+		#[pallet::constant]
+		type ManualAcceptanceDuration: Get<BlockNumberFor<Self>>;
+
 		/// Max individual bids per project. Used to estimate worst case weight for price calculation
 		#[pallet::constant]
 		type MaxBidsPerProject: Get<u32>;
@@ -276,6 +308,18 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxEvaluationsPerUser: Get<u32>;
 
+		/// Max funding assets in the registry. The oracle feeds the prices of all of them and of PLMC in one
+		/// call, so this must be lower than its `MaxFeedValues`.
+		#[pallet::constant]
+		type MaxFundingAssets: Get<u32>;
+
+		/// The origin allowed to approve or reject a project milestone without waiting for the participants' vote.
+		type MilestoneOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// The length (expressed in number of blocks) of the participants' vote on a submitted milestone.
+		#[pallet::constant]
+		type MilestoneVotingDuration: Get<BlockNumberFor<Self>>;
+
 		#[pallet::constant]
 		type MinUsdPerEvaluation: Get<Balance>;
 
@@ -283,6 +327,19 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxMessageSizeThresholds: Get<RangeInclusive<u32>>;
 
+		/// How many times we try to schedule a project transition in the following blocks, if the target block is full
+		#[pallet::constant]
+		type MaxProjectsToUpdateInsertionAttempts: Get<u32>;
+
+		/// How many project transitions can be scheduled for the same block
+		#[pallet::constant]
+		type MaxProjectsToUpdatePerBlock: Get<u32>;
+
+		/// Max weight `on_initialize` can spend on automatic project transitions in a single block.
+		/// Transitions that don't fit are moved to the next block.
+		#[pallet::constant]
+		type MaxTransitionWeightPerBlock: Get<Weight>;
+
 		/// Multiplier type that decides how much PLMC needs to be bonded for a token buy/bid
 		type Multiplier: Parameter
 			+ BondingRequirementCalculation
@@ -311,7 +368,8 @@ pub mod pallet {
 		/// Method to get the price of an asset like USDT or PLMC. Likely to come from an oracle
 		type PriceProvider: ProvideAssetPrice<AssetId = u32, Price = Self::Price>;
 
-		/// Something that provides randomness in the runtime.
+		/// Randomness used to pick the end of candle auctions. The block author must not be able to influence it.
+		/// Only randomness known since after the auction ended is used.
 		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
 		/// The length (expressed in number of blocks) of the Remainder Round.
@@ -326,6 +384,9 @@ pub mod pallet {
 		#[pallet::constant]
 		type RequiredMaxMessageSize: Get<u32>;
 
+		/// The origin allowed to change the fee and reward split parameters.
+		type RewardParametersOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
 		/// The runtime enum constructed by the construct_runtime macro
 		type RuntimeCall: Parameter + IsType<<Self as pallet_xcm::Config>::RuntimeCall> + From<Call<Self>>;
 
@@ -387,6 +448,11 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type BidCounts<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, u32, ValueQuery>;
 
+	#[pallet::storage]
+	/// The first and last id of the bids created by the latest bid call to each project, which is split into one bid
+	/// per bucket it reaches. Only those bids can give their CTs back to the bucket.
+	pub type LastBidIds<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, (u32, u32), OptionQuery>;
+
 	#[pallet::storage]
 	pub type EvaluationCounts<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, u32, ValueQuery>;
 
@@ -468,6 +534,25 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ActiveMigrationQueue<T: Config> = StorageMap<_, Blake2_128Concat, QueryId, (ProjectId, T::AccountId)>;
 
+	#[pallet::storage]
+	/// Projects that need to transition to their next round on a given block, and the transition to execute
+	pub type ProjectsToUpdate<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(ProjectId, UpdateType), T::MaxProjectsToUpdatePerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Where the next [`settle_project_batch`](Pallet::settle_project_batch) call continues settling each project
+	pub type SettlementCursors<T: Config> =
+		StorageMap<_, Blake2_128Concat, ProjectId, SettlementCursorOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	/// Projects that `on_idle` still has to settle
+	pub type ProjectsToSettleOnIdle<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, (), OptionQuery>;
+
 	/// A map to keep track of what issuer's did has an active project. It prevents one issuer having multiple active projects
 	#[pallet::storage]
 	pub type DidWithActiveProjects<T: Config> = StorageMap<_, Blake2_128Concat, Did, ProjectId, OptionQuery>;
@@ -476,6 +561,112 @@ pub mod pallet {
 	pub type DidWithWinningBids<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, Did, bool, ValueQuery>;
 
+	#[pallet::storage]
+	/// The effective end block of a candle auction, picked at random once the auction round is over.
+	pub type CandleAuctionEnds<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Funds of projects with milestones, held in the project pot until the milestones are approved.
+	pub type ProjectEscrows<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, ProjectEscrow, ValueQuery>;
+
+	#[pallet::storage]
+	/// Amount of each funding asset held in the escrow of a project.
+	pub type EscrowedFunds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProjectId,
+		Blake2_128Concat,
+		AcceptedFundingAsset,
+		EscrowBalance,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// CTs bought by each participant of a project with milestones. Used as voting power and to split refunds.
+	pub type EscrowShares<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, AccountIdOf<T>, Balance, ValueQuery>;
+
+	#[pallet::storage]
+	/// Funding assets each participant paid into the escrow of a project. Used to split refunds.
+	pub type EscrowDeposits<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, ProjectId>,
+			NMapKey<Blake2_128Concat, AccountIdOf<T>>,
+			NMapKey<Blake2_128Concat, AcceptedFundingAsset>,
+		),
+		Balance,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Status of the milestones submitted by the issuer. Milestones not yet submitted have no entry.
+	pub type MilestoneStatuses<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProjectId,
+		Twox64Concat,
+		u8,
+		MilestoneStatus<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Participants that already voted on a milestone.
+	pub type MilestoneVotes<T: Config> = StorageNMap<
+		_,
+		(NMapKey<Blake2_128Concat, ProjectId>, NMapKey<Twox64Concat, u8>, NMapKey<Blake2_128Concat, AccountIdOf<T>>),
+		bool,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Fee and reward split parameters set by governance. If not set, the defaults with `T::FeeBrackets` are used.
+	pub type ActiveRewardParameters<T: Config> = StorageValue<_, RewardParameters, OptionQuery>;
+
+	#[pallet::storage]
+	/// Reward parameters that were active when the project reached `FundingSuccessful`.
+	pub type ProjectRewardParameters<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, RewardParameters>;
+
+	#[pallet::storage]
+	/// Projects frozen by the `AdminOrigin`. They accept no participations and don't move to the next round.
+	pub type FrozenProjects<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, bool, ValueQuery>;
+
+	#[pallet::storage]
+	/// Whether the `AdminOrigin` paused evaluations, bids and contributions on all projects.
+	pub type ParticipationsPaused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	/// Registry of the assets projects can raise funds in. Holds at most `MaxFundingAssets` entries.
+	pub type FundingAssets<T: Config> = CountedStorageMap<_, Blake2_128Concat, AcceptedFundingAsset, FundingAssetInfo>;
+
+	#[pallet::storage]
+	/// Reverse lookup of the [`FundingAssets`] registry by asset location.
+	pub type FundingAssetLocations<T: Config> = StorageMap<_, Blake2_128Concat, Location, AcceptedFundingAsset>;
+
+	#[pallet::storage]
+	/// Reverse lookup of the [`FundingAssets`] registry by oracle key.
+	pub type FundingAssetOracleKeys<T: Config> = StorageMap<_, Blake2_128Concat, [u8; 8], AcceptedFundingAsset>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Funding assets registered at genesis.
+		pub funding_assets: Vec<(AcceptedFundingAsset, FundingAssetInfo)>,
+		#[serde(skip)]
+		pub _marker: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (asset, info) in self.funding_assets.iter() {
+				Pallet::<T>::do_register_funding_asset(*asset, info.clone())
+					.expect("Genesis funding assets should be unique");
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -500,6 +691,11 @@ pub mod pallet {
 			project_id: ProjectId,
 			phase: ProjectStatus<BlockNumberFor<T>>,
 		},
+		/// The outcome of a project awaiting a decision was set, either by the issuer or by default.
+		ProjectOutcomeDecided {
+			project_id: ProjectId,
+			decision: FundingOutcomeDecision,
+		},
 		/// A `bonder` bonded an `amount` of PLMC for `project_id`.
 		Evaluation {
 			project_id: ProjectId,
@@ -519,6 +715,30 @@ pub mod pallet {
 			plmc_bond: Balance,
 			multiplier: MultiplierOf<T>,
 		},
+		/// The effective end of a candle auction was decided. Bids made after `end_block` are rejected.
+		CandleAuctionEndDecided {
+			project_id: ProjectId,
+			end_block: BlockNumberFor<T>,
+		},
+		/// A bid was cancelled during the auction round, and its bond and funds were released
+		BidCancelled {
+			project_id: ProjectId,
+			bidder: AccountIdOf<T>,
+			id: u32,
+			plmc_released: Balance,
+			funding_asset: AcceptedFundingAsset,
+			funding_amount_released: Balance,
+		},
+		/// The CT amount of a bid was lowered during the auction round
+		BidAmended {
+			project_id: ProjectId,
+			bidder: AccountIdOf<T>,
+			id: u32,
+			ct_amount: Balance,
+			plmc_released: Balance,
+			funding_asset: AcceptedFundingAsset,
+			funding_amount_released: Balance,
+		},
 		/// A contribution was made for a project. i.e token purchase
 		Contribution {
 			project_id: ProjectId,
@@ -596,6 +816,67 @@ pub mod pallet {
 		CTMigrationFinished {
 			project_id: ProjectId,
 		},
+		/// The issuer submitted the deliverable of a milestone, and the participants can vote on it.
+		MilestoneSubmitted {
+			project_id: ProjectId,
+			milestone: u8,
+			voting_end: BlockNumberFor<T>,
+		},
+		/// A participant voted on a milestone, with a weight equal to the CTs they bought.
+		MilestoneVoted {
+			project_id: ProjectId,
+			milestone: u8,
+			voter: AccountIdOf<T>,
+			approve: bool,
+			weight: Balance,
+		},
+		/// A milestone was approved, and its share of the funds was released to the issuer.
+		MilestoneApproved {
+			project_id: ProjectId,
+			milestone: u8,
+		},
+		/// A milestone was rejected. The remaining funds can be claimed back by the participants.
+		MilestoneRejected {
+			project_id: ProjectId,
+			milestone: u8,
+		},
+		/// A participant got their share of the remaining escrowed funds back.
+		EscrowRefunded {
+			project_id: ProjectId,
+			account: AccountIdOf<T>,
+			shares: Balance,
+		},
+		/// Governance changed the reward parameters for projects reaching `FundingSuccessful` from now on.
+		RewardParametersSet {
+			parameters: RewardParameters,
+		},
+		/// The `AdminOrigin` froze a project.
+		ProjectFrozen {
+			project_id: ProjectId,
+		},
+		/// The `AdminOrigin` unfroze a project.
+		ProjectUnfrozen {
+			project_id: ProjectId,
+		},
+		/// The `AdminOrigin` failed a project. All its participations get refunded on settlement, and the evaluator
+		/// bonds are released without being slashed or rewarded.
+		ProjectForceFailed {
+			project_id: ProjectId,
+		},
+		/// The `AdminOrigin` paused all participations.
+		ParticipationsPaused,
+		/// The `AdminOrigin` resumed all participations.
+		ParticipationsUnpaused,
+		/// A funding asset was added to the registry.
+		FundingAssetRegistered {
+			asset: AcceptedFundingAsset,
+			info: FundingAssetInfo,
+		},
+		/// A funding asset was enabled or disabled for new projects and participations.
+		FundingAssetEnabledSet {
+			asset: AcceptedFundingAsset,
+			enabled: bool,
+		},
 	}
 
 	#[pallet::error]
@@ -608,6 +889,8 @@ pub mod pallet {
 		BadMath,
 		/// Could not get the price in USD equivalent for an asset/PLMC.
 		PriceNotFound,
+		/// The price in USD equivalent for an asset/PLMC is too old to value a new participation.
+		PriceStale,
 		/// Tried to retrieve a evaluation, bid or contribution but it does not exist.
 		ParticipationNotFound,
 		/// The user investor type is not eligible for the action.
@@ -644,6 +927,9 @@ pub mod pallet {
 		TooLateForRound,
 		/// A project's transition point (block number) was not set.
 		TransitionPointNotSet,
+		/// The candle auction end cannot be decided yet, as no randomness was revealed after the auction ended.
+		/// The transition is retried every block until there is.
+		CandleAuctionRandomnessNotReady,
 
 		// * Issuer related errors. E.g. the action was not executed by the issuer, or the issuer *
 		/// did not have the correct state to execute an action.
@@ -668,6 +954,12 @@ pub mod pallet {
 		AllocationSizeError,
 		/// The auction round percentage cannot be zero.
 		AuctionRoundPercentageError,
+		/// The candle auction closing phase cannot be zero.
+		CandleAuctionPhaseError,
+		/// The bucket curve steps cannot be zero, and a custom curve needs at least one step.
+		BucketCurveError,
+		/// The milestone shares cannot be zero, and have to add up to 100%.
+		MilestonesError,
 		/// The funding target has to be higher than 1000 USD.
 		FundingTargetTooLow,
 		/// The funding target has to be lower than 1bn USD.
@@ -721,6 +1013,8 @@ pub mod pallet {
 		ChannelNotReady,
 		/// Settlement for this project has not yet started.
 		SettlementNotStarted,
+		/// Settlement for this project already started, so its participations cannot be refunded anymore.
+		SettlementAlreadyStarted,
 		/// Wanted to settle as successful when it failed, or vice versa.
 		WrongSettlementOutcome,
 		/// User still has participations that need to be settled before migration.
@@ -729,6 +1023,57 @@ pub mod pallet {
 		SettlementNotComplete,
 		/// Tried to mark a project's CT migration as finished but there are still migrations to be confirmed
 		MigrationsStillPending,
+		/// Could not schedule the automatic transition of a project, as all the attempted blocks were full.
+		TooManyInsertionAttempts,
+
+		// * An error related to the milestones of a project. *
+		/// The project did not declare a milestone with this index.
+		MilestoneNotFound,
+		/// The milestone was already submitted.
+		MilestoneAlreadySubmitted,
+		/// The milestones have to be approved in order.
+		PreviousMilestoneNotApproved,
+		/// The milestone is not being voted on.
+		MilestoneNotInVoting,
+		/// The participant already voted on this milestone.
+		MilestoneAlreadyVoted,
+		/// The account did not buy CTs of this project, so it has no voting power or refund.
+		NoEscrowShares,
+		/// A milestone was rejected, so the escrowed funds can only be refunded.
+		EscrowRefunding,
+		/// No milestone was rejected, so the escrowed funds cannot be refunded.
+		EscrowNotRefunding,
+		/// The fee brackets don't cover every amount raised, or the reward splits don't add up to 100%.
+		InvalidRewardParameters,
+		/// The runtime configures more fee brackets than the reward parameters can hold.
+		TooManyFeeBrackets,
+		/// The project was frozen by the `AdminOrigin`.
+		ProjectFrozenByAdmin,
+		/// The project was not frozen by the `AdminOrigin`.
+		ProjectNotFrozenByAdmin,
+		/// Participations on all projects were paused by the `AdminOrigin`.
+		ParticipationsArePaused,
+		/// The funding asset is not in the registry.
+		FundingAssetNotRegistered,
+		/// A funding asset with the same id, location or oracle key is already in the registry.
+		FundingAssetAlreadyRegistered,
+		/// The funding asset was disabled, so new projects and participations cannot use it.
+		FundingAssetDisabled,
+		/// `AcceptedFundingAsset::Other` was used with the asset id of a named funding asset.
+		FundingAssetIdNotCanonical,
+		/// The registry already holds `MaxFundingAssets` funding assets.
+		TooManyFundingAssets,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::do_execute_project_updates(now)
+		}
+
+		fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::do_settle_projects_on_idle(remaining_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -889,6 +1234,21 @@ pub mod pallet {
 			Self::do_end_funding(project_id)
 		}
 
+		/// Accept or reject the funding of a project that ended between the success thresholds.
+		#[pallet::call_index(27)]
+		#[pallet::weight(WeightInfoOf::<T>::decide_project_outcome())]
+		pub fn decide_project_outcome(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			decision: FundingOutcomeDecision,
+		) -> DispatchResult {
+			let (account, _did, investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::do_decide_project_outcome(account, project_id, decision)
+		}
+
 		#[pallet::call_index(11)]
 		#[pallet::weight(WeightInfoOf::<T>::start_settlement())]
 		pub fn start_settlement(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
@@ -923,6 +1283,48 @@ pub mod pallet {
 			Self::do_settle_bid(bid, project_id)
 		}
 
+		/// Settle up to `limit` evaluations, bids and contributions of a project in one call.
+		/// Progress is stored, so consecutive calls continue where the previous one stopped.
+		#[pallet::call_index(14)]
+		#[pallet::weight(Pallet::<T>::settlement_operation_weight().saturating_mul(u64::from(*limit)))]
+		pub fn settle_project_batch(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			limit: u32,
+		) -> DispatchResultWithPostInfo {
+			let _caller = ensure_signed(origin)?;
+			Self::do_settle_project_batch(project_id, limit)
+		}
+
+		/// Cancel a bid while the auction round is still running. The PLMC bond and funding assets are released.
+		#[pallet::call_index(15)]
+		#[pallet::weight(WeightInfoOf::<T>::cancel_bid())]
+		pub fn cancel_bid(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			bid_id: u32,
+		) -> DispatchResult {
+			let (bidder, _did, _investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			Self::do_cancel_bid(&bidder, project_id, bid_id)
+		}
+
+		/// Lower the CT amount of a bid while the auction round is still running.
+		#[pallet::call_index(16)]
+		#[pallet::weight(WeightInfoOf::<T>::amend_bid())]
+		pub fn amend_bid(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			bid_id: u32,
+			#[pallet::compact] new_ct_amount: Balance,
+		) -> DispatchResult {
+			let (bidder, _did, investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			Self::do_amend_bid(&bidder, project_id, bid_id, new_ct_amount, investor_type)
+		}
+
 		#[pallet::call_index(17)]
 		#[pallet::weight(WeightInfoOf::<T>::settle_contribution_project_successful())]
 		pub fn settle_contribution(
@@ -1042,6 +1444,137 @@ pub mod pallet {
 
 			Self::do_mark_project_ct_migration_as_finished(project_id)
 		}
+
+		/// Submit the deliverable of the next milestone, starting the participants' vote on it.
+		#[pallet::call_index(28)]
+		#[pallet::weight(WeightInfoOf::<T>::submit_milestone())]
+		pub fn submit_milestone(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			milestone: u8,
+		) -> DispatchResult {
+			let (account, _did, investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::do_submit_milestone(account, project_id, milestone)
+		}
+
+		/// Vote on a submitted milestone, with the CTs bought in the project as weight.
+		#[pallet::call_index(29)]
+		#[pallet::weight(WeightInfoOf::<T>::vote_milestone())]
+		pub fn vote_milestone(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			milestone: u8,
+			approve: bool,
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+			Self::do_vote_milestone(voter, project_id, milestone, approve)
+		}
+
+		/// Approve or reject a milestone once its vote ended.
+		#[pallet::call_index(30)]
+		#[pallet::weight(WeightInfoOf::<T>::resolve_milestone())]
+		pub fn resolve_milestone(origin: OriginFor<T>, project_id: ProjectId, milestone: u8) -> DispatchResult {
+			let _caller = ensure_signed(origin)?;
+			Self::do_resolve_milestone(project_id, milestone)
+		}
+
+		/// Approve or reject a milestone without waiting for the participants' vote.
+		#[pallet::call_index(31)]
+		#[pallet::weight(WeightInfoOf::<T>::resolve_milestone())]
+		pub fn force_resolve_milestone(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			milestone: u8,
+			approve: bool,
+		) -> DispatchResult {
+			T::MilestoneOrigin::ensure_origin(origin)?;
+			Self::do_force_resolve_milestone(project_id, milestone, approve)
+		}
+
+		/// Claim back a share of the funds still held for a project, after one of its milestones got rejected.
+		#[pallet::call_index(32)]
+		#[pallet::weight(WeightInfoOf::<T>::claim_escrow_refund())]
+		pub fn claim_escrow_refund(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			let participant = ensure_signed(origin)?;
+			Self::do_claim_escrow_refund(participant, project_id)
+		}
+
+		/// Change the fee and reward split parameters. Projects that already reached `FundingSuccessful` keep theirs.
+		#[pallet::call_index(33)]
+		#[pallet::weight(WeightInfoOf::<T>::set_reward_parameters())]
+		pub fn set_reward_parameters(origin: OriginFor<T>, parameters: RewardParameters) -> DispatchResult {
+			T::RewardParametersOrigin::ensure_origin(origin)?;
+			Self::do_set_reward_parameters(parameters)
+		}
+
+		/// Stop a project from accepting participations and from moving to the next round.
+		#[pallet::call_index(34)]
+		#[pallet::weight(WeightInfoOf::<T>::freeze_project())]
+		pub fn freeze_project(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_freeze_project(project_id)
+		}
+
+		/// Let a frozen project continue where it stopped.
+		#[pallet::call_index(35)]
+		#[pallet::weight(WeightInfoOf::<T>::unfreeze_project())]
+		pub fn unfreeze_project(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_unfreeze_project(project_id)
+		}
+
+		/// Move a project that did not start its settlement to `FundingFailed`, frozen or not.
+		/// The settlement then refunds all bids and contributions, and releases the evaluator bonds without slashing.
+		/// Fails with `SettlementAlreadyStarted` for projects in the settlement or CT migration, since their
+		/// participations may already be paid out, and with `IncorrectRound` for projects that already failed.
+		#[pallet::call_index(36)]
+		#[pallet::weight(WeightInfoOf::<T>::force_fail_project())]
+		pub fn force_fail_project(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_force_fail_project(project_id)
+		}
+
+		/// Reject new evaluations, bids and contributions on all projects.
+		#[pallet::call_index(37)]
+		#[pallet::weight(WeightInfoOf::<T>::set_participations_paused())]
+		pub fn pause_participations(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_set_participations_paused(true)
+		}
+
+		#[pallet::call_index(38)]
+		#[pallet::weight(WeightInfoOf::<T>::set_participations_paused())]
+		pub fn unpause_participations(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_set_participations_paused(false)
+		}
+
+		/// Add an asset to the registry of funding assets. It is enabled according to `info.enabled`.
+		#[pallet::call_index(39)]
+		#[pallet::weight(WeightInfoOf::<T>::register_funding_asset())]
+		pub fn register_funding_asset(
+			origin: OriginFor<T>,
+			asset: AcceptedFundingAsset,
+			info: FundingAssetInfo,
+		) -> DispatchResult {
+			T::FundingAssetOrigin::ensure_origin(origin)?;
+			Self::do_register_funding_asset(asset, info)
+		}
+
+		/// Allow or forbid new projects and participations to use a registered funding asset.
+		#[pallet::call_index(40)]
+		#[pallet::weight(WeightInfoOf::<T>::set_funding_asset_enabled())]
+		pub fn set_funding_asset_enabled(
+			origin: OriginFor<T>,
+			asset: AcceptedFundingAsset,
+			enabled: bool,
+		) -> DispatchResult {
+			T::FundingAssetOrigin::ensure_origin(origin)?;
+			Self::do_set_funding_asset_enabled(asset, enabled)
+		}
 	}
 }
 
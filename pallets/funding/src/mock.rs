@@ -21,7 +21,10 @@
 use super::*;
 use crate as pallet_funding;
 use crate::{
-	runtime_api::{ExtrinsicHelpers, Leaderboards, ProjectInformation, ProjectParticipationIds, UserInformation},
+	runtime_api::{
+		ExtrinsicHelpers, Leaderboards, ParticipationFilter, ParticipationPage, ParticipationSimulation,
+		ProjectInformation, ProjectParticipationIds, ProjectTimeline, UserInformation,
+	},
 	traits::ProvideAssetPrice,
 };
 use core::ops::RangeInclusive;
@@ -29,7 +32,9 @@ use frame_support::{
 	construct_runtime, derive_impl,
 	pallet_prelude::Weight,
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, Everything, OriginTrait, WithdrawReasons},
+	traits::{
+		AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, Everything, OriginTrait, Randomness, WithdrawReasons,
+	},
 	PalletId,
 };
 use frame_system as system;
@@ -40,7 +45,7 @@ use sp_arithmetic::Percent;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, ConvertBack, ConvertInto, Get, IdentityLookup, TryConvert},
-	BuildStorage, Perquintill,
+	BuildStorage, DispatchError, Perquintill,
 };
 use sp_std::collections::btree_map::BTreeMap;
 use std::cell::RefCell;
@@ -279,7 +284,22 @@ impl pallet_balances::Config for TestRuntime {
 	type WeightInfo = ();
 }
 
-impl pallet_insecure_randomness_collective_flip::Config for TestRuntime {}
+parameter_types! {
+	// Storage parameters so tests can pick the outcome of the randomness, and since when it is known
+	pub storage RandomSeed: u32 = 0;
+	pub storage RandomnessKnownSince: Option<BlockNumber> = None;
+}
+
+/// Randomness that always returns [`RandomSeed`], so the tests know which candle auction end is picked.
+/// It is known since [`RandomnessKnownSince`], or the current block if unset.
+pub struct DeterministicRandomness;
+impl Randomness<H256, BlockNumber> for DeterministicRandomness {
+	fn random(_subject: &[u8]) -> (H256, BlockNumber) {
+		let mut output = H256::zero();
+		output.as_mut()[..4].copy_from_slice(&RandomSeed::get().to_le_bytes());
+		(output, RandomnessKnownSince::get().unwrap_or_else(System::block_number))
+	}
+}
 
 impl pallet_timestamp::Config for TestRuntime {
 	type MinimumPeriod = ConstU64<5>;
@@ -297,6 +317,8 @@ parameter_types! {
 	pub const AuctionRoundDuration: BlockNumber = 15u64;
 	pub const CommunityRoundDuration: BlockNumber = 18u64;
 	pub const RemainderRoundDuration: BlockNumber = 6u64;
+	pub const ManualAcceptanceDuration: BlockNumber = 8u64;
+	pub const MilestoneVotingDuration: BlockNumber = 12u64;
 
 	pub const FundingPalletId: PalletId = PalletId(*b"py/cfund");
 	pub FeeBrackets: Vec<(Percent, Balance)> = vec![
@@ -309,6 +331,10 @@ parameter_types! {
 	pub BlockchainOperationTreasuryAccount: AccountId = AccountId::from(696969u32);
 	pub ContributionTreasury: AccountId = AccountId::from(4204204206u32);
 	pub FundingSuccessThreshold: Perquintill = Perquintill::from_percent(33);
+	pub FundingAutoSuccessThreshold: Perquintill = Perquintill::from_percent(90);
+	pub static DefaultFundingDecision: FundingOutcomeDecision = FundingOutcomeDecision::AcceptFunding;
+	// Storage parameter so tests can restrict the budget and check that transitions get deferred
+	pub storage MaxTransitionWeightPerBlock: Weight = Weight::MAX;
 }
 
 parameter_types! {
@@ -372,14 +398,37 @@ thread_local! {
 		(AcceptedFundingAsset::USDT.id(), FixedU128::from_float(1.0f64)), // USDT
 		(PLMC_FOREIGN_ID, FixedU128::from_float(8.4f64)), // PLMC
 	]));
+	/// Moment of the last price update. Prices without an entry are always fresh.
+	pub static PRICE_TIMESTAMPS: RefCell<BTreeMap<AssetId, u64>> = RefCell::new(BTreeMap::new());
+	/// Time-weighted average prices. Assets without an entry use their spot price.
+	pub static TWAP_MAP: RefCell<BTreeMap<AssetId, Price>> = RefCell::new(BTreeMap::new());
+}
+parameter_types! {
+	pub const MaxPriceAge: u64 = 60 * 60 * 1000;
+	pub static PriceClock: u64 = 0;
 }
 pub struct ConstPriceProvider;
 impl ProvideAssetPrice for ConstPriceProvider {
 	type AssetId = AssetId;
+	type Moment = u64;
 	type Price = Price;
 
-	fn get_price(asset_id: AssetId) -> Option<Price> {
-		PRICE_MAP.with(|price_map| price_map.borrow().get(&asset_id).cloned())
+	fn get_timestamped_price(asset_id: AssetId) -> Option<(Price, u64)> {
+		let price = PRICE_MAP.with(|price_map| price_map.borrow().get(&asset_id).cloned())?;
+		let updated_at = PRICE_TIMESTAMPS.with(|timestamps| timestamps.borrow().get(&asset_id).cloned());
+		Some((price, updated_at.unwrap_or_else(Self::now)))
+	}
+
+	fn get_twap(asset_id: AssetId) -> Option<Price> {
+		TWAP_MAP.with(|twap_map| twap_map.borrow().get(&asset_id).cloned()).or_else(|| Self::get_price(asset_id))
+	}
+
+	fn now() -> u64 {
+		PriceClock::get()
+	}
+
+	fn max_price_age(_asset_id: &AssetId) -> u64 {
+		MaxPriceAge::get()
 	}
 }
 
@@ -389,11 +438,23 @@ impl ConstPriceProvider {
 			price_map.borrow_mut().insert(asset_id, price);
 		});
 	}
+
+	pub fn set_price_timestamp(asset_id: AssetId, updated_at: u64) {
+		PRICE_TIMESTAMPS.with(|timestamps| {
+			timestamps.borrow_mut().insert(asset_id, updated_at);
+		});
+	}
+
+	pub fn set_twap(asset_id: AssetId, twap: Price) {
+		TWAP_MAP.with(|twap_map| {
+			twap_map.borrow_mut().insert(asset_id, twap);
+		});
+	}
 }
 impl Config for TestRuntime {
 	type AccountId32Conversion = DummyConverter;
-	type AllPalletsWithoutSystem =
-		(Balances, ContributionTokens, ForeignAssets, PolimecFunding, LinearRelease, RandomnessCollectiveFlip);
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type AllPalletsWithoutSystem = (Balances, ContributionTokens, ForeignAssets, PolimecFunding, LinearRelease);
 	type AuctionRoundDuration = AuctionRoundDuration;
 	type BlockNumber = BlockNumber;
 	type BlockchainOperationTreasury = BlockchainOperationTreasuryAccount;
@@ -401,20 +462,30 @@ impl Config for TestRuntime {
 	type ContributionTokenCurrency = ContributionTokens;
 	type ContributionTreasury = ContributionTreasury;
 	type DaysToBlocks = DaysToBlocks;
+	type DefaultFundingDecision = DefaultFundingDecision;
 	type EvaluationRoundDuration = EvaluationRoundDuration;
 	type EvaluationSuccessThreshold = EarlyEvaluationThreshold;
 	type EvaluatorSlash = EvaluatorSlash;
 	type FeeBrackets = FeeBrackets;
+	type FundingAssetOrigin = EnsureRoot<AccountId>;
 	type FundingCurrency = ForeignAssets;
+	type FundingAutoSuccessThreshold = FundingAutoSuccessThreshold;
 	type FundingSuccessThreshold = FundingSuccessThreshold;
 	type InvestorOrigin = EnsureInvestor<TestRuntime>;
+	type ManualAcceptanceDuration = ManualAcceptanceDuration;
 	type MaxBidsPerProject = ConstU32<512>;
 	type MaxBidsPerUser = ConstU32<25>;
 	type MaxCapacityThresholds = MaxCapacityThresholds;
 	type MaxContributionsPerUser = ConstU32<25>;
 	type MaxEvaluationsPerProject = ConstU32<512>;
 	type MaxEvaluationsPerUser = ConstU32<4>;
+	type MaxFundingAssets = ConstU32<4>;
 	type MaxMessageSizeThresholds = MaxMessageSizeThresholds;
+	type MaxProjectsToUpdateInsertionAttempts = ConstU32<100>;
+	type MaxProjectsToUpdatePerBlock = ConstU32<100>;
+	type MaxTransitionWeightPerBlock = MaxTransitionWeightPerBlock;
+	type MilestoneOrigin = EnsureRoot<AccountId>;
+	type MilestoneVotingDuration = MilestoneVotingDuration;
 	type MinUsdPerEvaluation = MinUsdPerEvaluation;
 	type Multiplier = Multiplier;
 	type NativeCurrency = Balances;
@@ -422,10 +493,11 @@ impl Config for TestRuntime {
 	type PalletId = FundingPalletId;
 	type Price = FixedU128;
 	type PriceProvider = ConstPriceProvider;
-	type Randomness = RandomnessCollectiveFlip;
+	type Randomness = DeterministicRandomness;
 	type RemainderRoundDuration = RemainderRoundDuration;
 	type RequiredMaxCapacity = RequiredMaxCapacity;
 	type RequiredMaxMessageSize = RequiredMaxMessageSize;
+	type RewardParametersOrigin = EnsureRoot<AccountId>;
 	type RuntimeCall = RuntimeCall;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -443,16 +515,25 @@ construct_runtime!(
 	{
 		System: frame_system,
 		Timestamp: pallet_timestamp,
-		RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip,
 		Balances: pallet_balances,
 		LinearRelease: pallet_linear_release,
 		ContributionTokens: pallet_assets::<Instance1>::{Pallet, Call, Storage, Event<T>},
 		ForeignAssets: pallet_assets::<Instance2>::{Pallet, Call, Storage, Event<T>, Config<T>},
 		PolkadotXcm: pallet_xcm,
-		PolimecFunding: pallet_funding::{Pallet, Call, Storage, Event<T>, HoldReason}  = 52,
+		PolimecFunding: pallet_funding::{Pallet, Call, Storage, Event<T>, Config<T>, HoldReason}  = 52,
 	}
 );
 
+pub fn default_funding_assets() -> Vec<(AcceptedFundingAsset, FundingAssetInfo)> {
+	let asset_hub_asset = |index: u128| Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(index)]);
+	let info = |location, decimals, oracle_key| FundingAssetInfo { location, decimals, oracle_key, enabled: true };
+	vec![
+		(AcceptedFundingAsset::USDT, info(asset_hub_asset(1984), 6, *b"USDT\0\0\0\0")),
+		(AcceptedFundingAsset::USDC, info(asset_hub_asset(1337), 6, *b"USDC\0\0\0\0")),
+		(AcceptedFundingAsset::DOT, info(Location::parent(), 10, *b"DOT\0\0\0\0\0")),
+	]
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::<TestRuntime>::default().build_storage().unwrap();
@@ -493,6 +574,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 			],
 			accounts: vec![],
 		},
+		polimec_funding: PolimecFundingConfig { funding_assets: default_funding_assets(), ..Default::default() },
 		..Default::default()
 	}
 	.assimilate_storage(&mut t)
@@ -537,6 +619,18 @@ sp_api::mock_impl_runtime_apis! {
 		fn all_project_participations_by_did(project_id: ProjectId, did: Did) -> Vec<ProjectParticipationIds<TestRuntime>> {
 			PolimecFunding::all_project_participations_by_did(project_id, did)
 		}
+
+		fn evaluations_page(project_id: ProjectId, filter: ParticipationFilter<AccountId, BlockNumber>, cursor: Option<Vec<u8>>, limit: u32) -> ParticipationPage<EvaluationInfoOf<TestRuntime>> {
+			PolimecFunding::evaluations_page(project_id, filter, cursor, limit)
+		}
+
+		fn bids_page(project_id: ProjectId, filter: ParticipationFilter<AccountId, BlockNumber>, cursor: Option<Vec<u8>>, limit: u32) -> ParticipationPage<BidInfoOf<TestRuntime>> {
+			PolimecFunding::bids_page(project_id, filter, cursor, limit)
+		}
+
+		fn contributions_page(project_id: ProjectId, filter: ParticipationFilter<AccountId, BlockNumber>, cursor: Option<Vec<u8>>, limit: u32) -> ParticipationPage<ContributionInfoOf<TestRuntime>> {
+			PolimecFunding::contributions_page(project_id, filter, cursor, limit)
+		}
 	}
 
 	impl ProjectInformation<Block, TestRuntime> for TestRuntime {
@@ -547,6 +641,10 @@ sp_api::mock_impl_runtime_apis! {
 		fn projects_by_did(did: Did) -> Vec<ProjectId> {
 			PolimecFunding::projects_by_did(did)
 		}
+
+		fn project_timeline(project_id: ProjectId) -> Option<ProjectTimeline<BlockNumber>> {
+			PolimecFunding::project_timeline(project_id)
+		}
 	}
 
 	impl ExtrinsicHelpers<Block, TestRuntime> for TestRuntime {
@@ -557,6 +655,16 @@ sp_api::mock_impl_runtime_apis! {
 			PolimecFunding::get_next_vesting_schedule_merge_candidates(account, hold_reason, end_max_delta)
 		}
 
+		fn simulate_bid(account: AccountId, did: Did, investor_type: InvestorType, project_id: ProjectId, ct_amount: Balance, multiplier: MultiplierOf<TestRuntime>, funding_asset: AcceptedFundingAsset) -> Result<ParticipationSimulation<PriceOf<TestRuntime>>, DispatchError> {
+			PolimecFunding::simulate_bid(account, did, investor_type, project_id, ct_amount, multiplier, funding_asset)
+		}
+
+		fn simulate_contribute(account: AccountId, did: Did, investor_type: InvestorType, project_id: ProjectId, ct_amount: Balance, multiplier: MultiplierOf<TestRuntime>, funding_asset: AcceptedFundingAsset) -> Result<ParticipationSimulation<PriceOf<TestRuntime>>, DispatchError> {
+			PolimecFunding::simulate_contribute(account, did, investor_type, project_id, ct_amount, multiplier, funding_asset)
+		}
 
+		fn simulate_evaluate(account: AccountId, did: Did, project_id: ProjectId, usd_amount: Balance) -> Result<ParticipationSimulation<PriceOf<TestRuntime>>, DispatchError> {
+			PolimecFunding::simulate_evaluate(account, did, project_id, usd_amount)
+		}
 	}
 }
@@ -1,12 +1,15 @@
 #[allow(clippy::wildcard_imports)]
 use crate::{traits::*, *};
 use alloc::collections::BTreeMap;
-use frame_support::traits::fungibles::{metadata::Inspect as MetadataInspect, Inspect, InspectEnumerable};
+use frame_support::{
+	storage::{with_transaction, PrefixIterator, TransactionOutcome},
+	traits::fungibles::{Inspect, InspectEnumerable},
+};
 use itertools::Itertools;
 use parity_scale_codec::{Decode, Encode};
 use polimec_common::USD_DECIMALS;
 use scale_info::TypeInfo;
-use sp_runtime::traits::Zero;
+use sp_runtime::{traits::Zero, DispatchError};
 
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
 pub struct ProjectParticipationIds<T: Config> {
@@ -16,6 +19,114 @@ pub struct ProjectParticipationIds<T: Config> {
 	contribution_ids: Vec<u32>,
 }
 
+/// What a participation extrinsic would do if it was submitted in the current state.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct ParticipationSimulation<Price> {
+	/// PLMC that would be held, including evaluation bonds converted into participation bonds.
+	pub plmc_held: Balance,
+	/// Funding asset that would be transferred to the project. Zero for evaluations.
+	pub funding_asset_amount: Balance,
+	/// The CT amount and USD price of each participation that would be stored. A bid is split in one entry per
+	/// bucket it goes through. Empty for evaluations.
+	pub ct_purchases: Vec<(Balance, Price)>,
+}
+
+/// Filters for the paginated participation queries. Every filter that is set must match.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct ParticipationFilter<AccountId, BlockNumber> {
+	pub account: Option<AccountId>,
+	pub did: Option<Did>,
+	/// Only applies to bids.
+	pub bid_status: Option<BidStatus>,
+	/// Does not apply to evaluations.
+	pub funding_asset: Option<AcceptedFundingAsset>,
+	/// First block, inclusive, in which the participation was made.
+	pub from_block: Option<BlockNumber>,
+	/// Last block, inclusive, in which the participation was made.
+	pub to_block: Option<BlockNumber>,
+}
+
+impl<AccountId, BlockNumber> Default for ParticipationFilter<AccountId, BlockNumber> {
+	fn default() -> Self {
+		Self { account: None, did: None, bid_status: None, funding_asset: None, from_block: None, to_block: None }
+	}
+}
+
+impl<AccountId, BlockNumber: PartialOrd> ParticipationFilter<AccountId, BlockNumber> {
+	fn matches(&self, did: &Did, when: &BlockNumber) -> bool {
+		self.did.as_ref().map_or(true, |filter| filter == did) &&
+			self.from_block.as_ref().map_or(true, |from| from <= when) &&
+			self.to_block.as_ref().map_or(true, |to| when <= to)
+	}
+}
+
+/// A page of participations. `next_cursor` is the raw storage key to continue from, or `None` if the
+/// end of the storage map was reached.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct ParticipationPage<Participation> {
+	pub items: Vec<Participation>,
+	pub next_cursor: Option<Vec<u8>>,
+}
+
+/// The extrinsic needed to move a project forward from its current status.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum ProjectAction {
+	/// `start_evaluation`
+	StartEvaluation,
+	/// `end_evaluation`
+	EndEvaluation,
+	/// `end_auction`
+	EndAuction,
+	/// `end_funding`
+	EndFunding,
+	/// `decide_project_outcome`
+	DecideProjectOutcome,
+	/// `start_settlement`
+	StartSettlement,
+	/// `settle_evaluation`, `settle_bid`, `settle_contribution` or `settle_project_batch` for the remaining
+	/// participations, followed by `mark_project_as_settled`.
+	SettleParticipations,
+	/// `start_offchain_migration` or `start_pallet_migration`
+	StartMigration,
+	/// `confirm_offchain_migration` or `send_pallet_migration_for` for the remaining participants,
+	/// followed by `mark_project_ct_migration_as_finished`.
+	MigrateParticipations,
+}
+
+/// Who is allowed to submit a [`ProjectAction`].
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum ActionCaller {
+	/// The project issuer, with an institutional credential.
+	Issuer,
+	/// Any signed account.
+	Anyone,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct NextAction<BlockNumber> {
+	pub action: ProjectAction,
+	pub caller: ActionCaller,
+	/// The first block in which the action can succeed. `None` if it can already be called.
+	pub available_from: Option<BlockNumber>,
+	/// Whether the pallet hooks perform the action on their own once it is available.
+	pub automatic: bool,
+}
+
+/// Start and end blocks of the phases of a project, as stored for the current phase and projected from the round
+/// durations for the upcoming ones. Past phases are left empty, since their transitions could have happened later
+/// than the round durations imply.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct ProjectTimeline<BlockNumber> {
+	pub status: ProjectStatus<BlockNumber>,
+	pub evaluation: BlockNumberPair<BlockNumber>,
+	pub auction: BlockNumberPair<BlockNumber>,
+	pub community: BlockNumberPair<BlockNumber>,
+	pub remainder: BlockNumberPair<BlockNumber>,
+	/// The block in which the funding ended.
+	pub funding_end: Option<BlockNumber>,
+	pub next_action: Option<NextAction<BlockNumber>>,
+}
+
 sp_api::decl_runtime_apis! {
 	#[api_version(1)]
 	pub trait Leaderboards<T: Config> {
@@ -35,25 +146,39 @@ sp_api::decl_runtime_apis! {
 		fn top_projects_by_usd_target_percent_reached(amount: u32) -> Vec<(ProjectId, ProjectMetadataOf<T>, ProjectDetailsOf<T>)>;
 	}
 
-	#[api_version(1)]
+	#[api_version(2)]
 	pub trait UserInformation<T: Config> {
 		/// Get all the contribution token balances for the participated projects
 		fn contribution_tokens(account: AccountIdOf<T>) -> Vec<(ProjectId, Balance)>;
 
 		/// Get all the project participations made by a single DID.
 		fn all_project_participations_by_did(project_id: ProjectId, did: Did) -> Vec<ProjectParticipationIds<T>>;
+
+		/// Page through the evaluations of a project. At most `limit` storage entries are read, starting after `cursor`,
+		/// so a page can hold less than `limit` items even if more are left.
+		fn evaluations_page(project_id: ProjectId, filter: ParticipationFilter<AccountIdOf<T>, BlockNumberFor<T>>, cursor: Option<Vec<u8>>, limit: u32) -> ParticipationPage<EvaluationInfoOf<T>>;
+
+		/// Page through the bids of a project. Works like `evaluations_page`.
+		fn bids_page(project_id: ProjectId, filter: ParticipationFilter<AccountIdOf<T>, BlockNumberFor<T>>, cursor: Option<Vec<u8>>, limit: u32) -> ParticipationPage<BidInfoOf<T>>;
+
+		/// Page through the contributions of a project. Works like `evaluations_page`.
+		fn contributions_page(project_id: ProjectId, filter: ParticipationFilter<AccountIdOf<T>, BlockNumberFor<T>>, cursor: Option<Vec<u8>>, limit: u32) -> ParticipationPage<ContributionInfoOf<T>>;
 	}
 
-	#[api_version(1)]
+	#[api_version(2)]
 	pub trait ProjectInformation<T: Config> {
 		/// Get the percentage of the target reached for a project
 		fn usd_target_percent_reached(project_id: ProjectId) -> FixedU128;
 
 		/// Get all the projects created by a single DID.
 		fn projects_by_did(did: Did) -> Vec<ProjectId>;
+
+		/// Get the start and end blocks of every phase of a project, and the action needed to move it forward.
+		/// Returns `None` if the project does not exist.
+		fn project_timeline(project_id: ProjectId) -> Option<ProjectTimeline<BlockNumberFor<T>>>;
 	}
 
-	#[api_version(2)]
+	#[api_version(3)]
 	pub trait ExtrinsicHelpers<T: Config> {
 		/// Get the current price of a contribution token (either current bucket in the auction, or WAP in contribution phase),
 		/// and calculate the amount of tokens that can be bought with the given amount USDT/USDC/DOT.
@@ -62,6 +187,16 @@ sp_api::decl_runtime_apis! {
 		/// Get the indexes of vesting schedules that are good candidates to be merged.
 		/// Schedules that have not yet started are de-facto bad candidates.
 		fn get_next_vesting_schedule_merge_candidates(account_id: AccountIdOf<T>, hold_reason: <T as Config>::RuntimeHoldReason, end_max_delta: Balance) -> Option<(u32, u32)>;
+
+		/// Run a bid against the current state without storing it, assuming the credential matches the project policy.
+		/// Returns what would be held, transferred and bought, or the error the extrinsic would fail with.
+		fn simulate_bid(account: AccountIdOf<T>, did: Did, investor_type: InvestorType, project_id: ProjectId, ct_amount: Balance, multiplier: MultiplierOf<T>, funding_asset: AcceptedFundingAsset) -> Result<ParticipationSimulation<PriceOf<T>>, DispatchError>;
+
+		/// Run a contribution against the current state without storing it. Works like `simulate_bid`.
+		fn simulate_contribute(account: AccountIdOf<T>, did: Did, investor_type: InvestorType, project_id: ProjectId, ct_amount: Balance, multiplier: MultiplierOf<T>, funding_asset: AcceptedFundingAsset) -> Result<ParticipationSimulation<PriceOf<T>>, DispatchError>;
+
+		/// Run an evaluation against the current state without storing it. Works like `simulate_bid`.
+		fn simulate_evaluate(account: AccountIdOf<T>, did: Did, project_id: ProjectId, usd_amount: Balance) -> Result<ParticipationSimulation<PriceOf<T>>, DispatchError>;
 	}
 }
 
@@ -139,7 +274,7 @@ impl<T: Config> Pallet<T> {
 	) -> Balance {
 		let project_details = ProjectsDetails::<T>::get(project_id).expect("Project not found");
 		let funding_asset_id = asset.id();
-		let funding_asset_decimals = T::FundingCurrency::decimals(funding_asset_id);
+		let funding_asset_decimals = Self::funding_asset_info(asset).expect("Funding asset not registered").decimals;
 		let funding_asset_usd_price =
 			T::PriceProvider::get_decimals_aware_price(funding_asset_id, USD_DECIMALS, funding_asset_decimals)
 				.expect("Price not found");
@@ -208,6 +343,109 @@ impl<T: Config> Pallet<T> {
 		None
 	}
 
+	pub fn simulate_bid(
+		bidder: AccountIdOf<T>,
+		did: Did,
+		investor_type: InvestorType,
+		project_id: ProjectId,
+		ct_amount: Balance,
+		multiplier: MultiplierOf<T>,
+		funding_asset: AcceptedFundingAsset,
+	) -> Result<ParticipationSimulation<PriceOf<T>>, DispatchError> {
+		let whitelisted_policy = Self::simulation_policy(project_id)?;
+		Self::simulate(|| {
+			let first_bid_id = NextBidId::<T>::get();
+			Self::do_bid(DoBidParams::<T> {
+				bidder: bidder.clone(),
+				project_id,
+				ct_amount,
+				multiplier,
+				funding_asset,
+				did,
+				investor_type,
+				whitelisted_policy,
+			})
+			.map_err(|e| e.error)?;
+			let bids = (first_bid_id..NextBidId::<T>::get())
+				.filter_map(|bid_id| Bids::<T>::get((project_id, bidder.clone(), bid_id)))
+				.collect_vec();
+			Ok(ParticipationSimulation {
+				plmc_held: bids.iter().fold(Zero::zero(), |acc: Balance, bid| acc.saturating_add(bid.plmc_bond)),
+				funding_asset_amount: bids
+					.iter()
+					.fold(Zero::zero(), |acc: Balance, bid| acc.saturating_add(bid.funding_asset_amount_locked)),
+				ct_purchases: bids.iter().map(|bid| (bid.original_ct_amount, bid.original_ct_usd_price)).collect(),
+			})
+		})
+	}
+
+	pub fn simulate_contribute(
+		contributor: AccountIdOf<T>,
+		did: Did,
+		investor_type: InvestorType,
+		project_id: ProjectId,
+		ct_amount: Balance,
+		multiplier: MultiplierOf<T>,
+		funding_asset: AcceptedFundingAsset,
+	) -> Result<ParticipationSimulation<PriceOf<T>>, DispatchError> {
+		let whitelisted_policy = Self::simulation_policy(project_id)?;
+		Self::simulate(|| {
+			let contribution_id = NextContributionId::<T>::get();
+			Self::do_contribute(DoContributeParams::<T> {
+				contributor: contributor.clone(),
+				project_id,
+				ct_amount,
+				multiplier,
+				funding_asset,
+				did,
+				investor_type,
+				whitelisted_policy,
+			})
+			.map_err(|e| e.error)?;
+			let contribution = Contributions::<T>::get((project_id, contributor.clone(), contribution_id))
+				.ok_or(Error::<T>::ImpossibleState)?;
+			let wap = ProjectsDetails::<T>::get(project_id)
+				.and_then(|details| details.weighted_average_price)
+				.ok_or(Error::<T>::ImpossibleState)?;
+			Ok(ParticipationSimulation {
+				plmc_held: contribution.plmc_bond,
+				funding_asset_amount: contribution.funding_asset_amount,
+				ct_purchases: vec![(contribution.ct_amount, wap)],
+			})
+		})
+	}
+
+	pub fn simulate_evaluate(
+		evaluator: AccountIdOf<T>,
+		did: Did,
+		project_id: ProjectId,
+		usd_amount: Balance,
+	) -> Result<ParticipationSimulation<PriceOf<T>>, DispatchError> {
+		let whitelisted_policy = Self::simulation_policy(project_id)?;
+		Self::simulate(|| {
+			let evaluation_id = NextEvaluationId::<T>::get();
+			Self::do_evaluate(&evaluator, project_id, usd_amount, did, whitelisted_policy).map_err(|e| e.error)?;
+			let evaluation = Evaluations::<T>::get((project_id, evaluator.clone(), evaluation_id))
+				.ok_or(Error::<T>::ImpossibleState)?;
+			Ok(ParticipationSimulation {
+				plmc_held: evaluation.original_plmc_bond,
+				funding_asset_amount: Zero::zero(),
+				ct_purchases: vec![],
+			})
+		})
+	}
+
+	/// The credential policy a simulated participation is made with. Simulations assume a matching credential.
+	fn simulation_policy(project_id: ProjectId) -> Result<Cid, DispatchError> {
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		project_metadata.policy_ipfs_cid.ok_or(Error::<T>::ImpossibleState.into())
+	}
+
+	/// Runs `f` and reverts every storage change it made, keeping only its result.
+	fn simulate<R>(f: impl FnOnce() -> Result<R, DispatchError>) -> Result<R, DispatchError> {
+		with_transaction(|| TransactionOutcome::Rollback(f()))
+	}
+
 	pub fn all_project_participations_by_did(project_id: ProjectId, did: Did) -> Vec<ProjectParticipationIds<T>> {
 		let evaluations = Evaluations::<T>::iter_prefix((project_id,))
 			.filter(|((_account_id, _evaluation_id), evaluation)| evaluation.did == did)
@@ -249,6 +487,105 @@ impl<T: Config> Pallet<T> {
 			.collect()
 	}
 
+	pub fn evaluations_page(
+		project_id: ProjectId,
+		filter: ParticipationFilter<AccountIdOf<T>, BlockNumberFor<T>>,
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+	) -> ParticipationPage<EvaluationInfoOf<T>> {
+		let matches = |evaluation: &EvaluationInfoOf<T>| filter.matches(&evaluation.did, &evaluation.when);
+		match filter.account.clone() {
+			Some(account) => {
+				let iter = match cursor {
+					Some(cursor) => Evaluations::<T>::iter_prefix_from((project_id, account), cursor),
+					None => Evaluations::<T>::iter_prefix((project_id, account)),
+				};
+				Self::participations_page(iter, limit, matches)
+			},
+			None => {
+				let iter = match cursor {
+					Some(cursor) => Evaluations::<T>::iter_prefix_from((project_id,), cursor),
+					None => Evaluations::<T>::iter_prefix((project_id,)),
+				};
+				Self::participations_page(iter, limit, matches)
+			},
+		}
+	}
+
+	pub fn bids_page(
+		project_id: ProjectId,
+		filter: ParticipationFilter<AccountIdOf<T>, BlockNumberFor<T>>,
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+	) -> ParticipationPage<BidInfoOf<T>> {
+		let matches = |bid: &BidInfoOf<T>| {
+			filter.matches(&bid.did, &bid.when) &&
+				filter.bid_status.as_ref().map_or(true, |status| status == &bid.status) &&
+				filter.funding_asset.map_or(true, |asset| asset == bid.funding_asset)
+		};
+		match filter.account.clone() {
+			Some(account) => {
+				let iter = match cursor {
+					Some(cursor) => Bids::<T>::iter_prefix_from((project_id, account), cursor),
+					None => Bids::<T>::iter_prefix((project_id, account)),
+				};
+				Self::participations_page(iter, limit, matches)
+			},
+			None => {
+				let iter = match cursor {
+					Some(cursor) => Bids::<T>::iter_prefix_from((project_id,), cursor),
+					None => Bids::<T>::iter_prefix((project_id,)),
+				};
+				Self::participations_page(iter, limit, matches)
+			},
+		}
+	}
+
+	pub fn contributions_page(
+		project_id: ProjectId,
+		filter: ParticipationFilter<AccountIdOf<T>, BlockNumberFor<T>>,
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+	) -> ParticipationPage<ContributionInfoOf<T>> {
+		let matches = |contribution: &ContributionInfoOf<T>| {
+			filter.matches(&contribution.did, &contribution.when) &&
+				filter.funding_asset.map_or(true, |asset| asset == contribution.funding_asset)
+		};
+		match filter.account.clone() {
+			Some(account) => {
+				let iter = match cursor {
+					Some(cursor) => Contributions::<T>::iter_prefix_from((project_id, account), cursor),
+					None => Contributions::<T>::iter_prefix((project_id, account)),
+				};
+				Self::participations_page(iter, limit, matches)
+			},
+			None => {
+				let iter = match cursor {
+					Some(cursor) => Contributions::<T>::iter_prefix_from((project_id,), cursor),
+					None => Contributions::<T>::iter_prefix((project_id,)),
+				};
+				Self::participations_page(iter, limit, matches)
+			},
+		}
+	}
+
+	/// Reads up to `limit` entries from `iter`, keeping the ones that match.
+	fn participations_page<Key, Participation>(
+		mut iter: PrefixIterator<(Key, Participation)>,
+		limit: u32,
+		matches: impl Fn(&Participation) -> bool,
+	) -> ParticipationPage<Participation> {
+		let mut items = Vec::new();
+		for _ in 0..limit {
+			match iter.next() {
+				Some((_key, participation)) if matches(&participation) => items.push(participation),
+				Some(_) => continue,
+				None => return ParticipationPage { items, next_cursor: None },
+			}
+		}
+		ParticipationPage { items, next_cursor: Some(iter.last_raw_key().to_vec()) }
+	}
+
 	pub fn usd_target_percent_reached(project_id: ProjectId) -> FixedU128 {
 		let project_details = ProjectsDetails::<T>::get(project_id).expect("Project not found");
 		let funding_reached = project_details.funding_amount_reached_usd;
@@ -262,4 +599,95 @@ impl<T: Config> Pallet<T> {
 			.map(|(project_id, _)| project_id)
 			.collect()
 	}
+
+	pub fn project_timeline(project_id: ProjectId) -> Option<ProjectTimeline<BlockNumberFor<T>>> {
+		let project_details = ProjectsDetails::<T>::get(project_id)?;
+		let round_duration = project_details.round_duration;
+		let one = BlockNumberFor::<T>::one();
+
+		// A phase of `duration` blocks, starting right after `previous_end`.
+		let phase_after = |previous_end: BlockNumberFor<T>, duration: BlockNumberFor<T>| {
+			let start = previous_end.saturating_add(one);
+			BlockNumberPair::new(Some(start), Some(start.saturating_add(duration).saturating_sub(one)))
+		};
+		let action = |action, caller, available_from: Option<BlockNumberFor<T>>, automatic| {
+			Some(NextAction { action, caller, available_from, automatic })
+		};
+
+		let mut evaluation = BlockNumberPair::new(None, None);
+		let mut auction = BlockNumberPair::new(None, None);
+		let mut community = BlockNumberPair::new(None, None);
+		let mut remainder = BlockNumberPair::new(None, None);
+		let mut funding_end = project_details.funding_end_block;
+
+		match project_details.status {
+			ProjectStatus::EvaluationRound => {
+				evaluation = round_duration.clone();
+				if let Some(evaluation_end) = round_duration.end {
+					auction = phase_after(evaluation_end, T::AuctionRoundDuration::get());
+				}
+			},
+			ProjectStatus::AuctionRound => {
+				auction = round_duration.clone();
+			},
+			ProjectStatus::CommunityRound(remainder_start) => {
+				community = BlockNumberPair::new(round_duration.start, Some(remainder_start.saturating_sub(one)));
+				remainder = BlockNumberPair::new(Some(remainder_start), round_duration.end);
+			},
+			ProjectStatus::AwaitingDecision | ProjectStatus::FundingSuccessful | ProjectStatus::FundingFailed => {
+				funding_end = funding_end.or(round_duration.start);
+			},
+			_ => {},
+		}
+
+		// Project the phases that did not start yet
+		if matches!(project_details.status, ProjectStatus::EvaluationRound | ProjectStatus::AuctionRound) {
+			if let Some(auction_end) = auction.end {
+				community = phase_after(auction_end, T::CommunityRoundDuration::get());
+			}
+			if let Some(community_end) = community.end {
+				remainder = phase_after(community_end, T::RemainderRoundDuration::get());
+			}
+		}
+
+		let after = |block: Option<BlockNumberFor<T>>| block.map(|block| block.saturating_add(one));
+		let next_action = match project_details.status {
+			ProjectStatus::Application => action(ProjectAction::StartEvaluation, ActionCaller::Issuer, None, false),
+			ProjectStatus::EvaluationRound =>
+				action(ProjectAction::EndEvaluation, ActionCaller::Anyone, after(round_duration.end), true),
+			ProjectStatus::AuctionRound =>
+				action(ProjectAction::EndAuction, ActionCaller::Anyone, after(round_duration.end), true),
+			ProjectStatus::CommunityRound(..) => {
+				// The funding can end early if all the CTs were sold
+				let available_from = if project_details.remaining_contribution_tokens.is_zero() {
+					None
+				} else {
+					after(round_duration.end)
+				};
+				action(ProjectAction::EndFunding, ActionCaller::Anyone, available_from, true)
+			},
+			// The default decision is applied automatically once the round ends
+			ProjectStatus::AwaitingDecision =>
+				action(ProjectAction::DecideProjectOutcome, ActionCaller::Issuer, None, true),
+			ProjectStatus::FundingSuccessful | ProjectStatus::FundingFailed =>
+				action(ProjectAction::StartSettlement, ActionCaller::Anyone, after(round_duration.start), true),
+			ProjectStatus::SettlementStarted(_) =>
+				action(ProjectAction::SettleParticipations, ActionCaller::Anyone, None, true),
+			ProjectStatus::SettlementFinished(FundingOutcome::Success) =>
+				action(ProjectAction::StartMigration, ActionCaller::Issuer, None, false),
+			ProjectStatus::CTMigrationStarted =>
+				action(ProjectAction::MigrateParticipations, ActionCaller::Anyone, None, false),
+			ProjectStatus::SettlementFinished(FundingOutcome::Failure) | ProjectStatus::CTMigrationFinished => None,
+		};
+
+		Some(ProjectTimeline {
+			status: project_details.status,
+			evaluation,
+			auction,
+			community,
+			remainder,
+			funding_end,
+			next_action,
+		})
+	}
 }
@@ -1,5 +1,120 @@
 //! A module that is responsible for migration of storage.
 use frame_support::traits::StorageVersion;
 /// The current storage version
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
 pub const LOG: &str = "runtime::funding::migration";
+
+pub mod v6 {
+	use super::LOG;
+	use crate::{
+		AcceptedFundingAsset, AccountIdOf, Balance, BiddingTicketSizes, Bucket, BucketCurve, BucketOf, Config,
+		ContributingTicketSizes, CurrencyMetadata, FundingAssetInfo, Pallet, PriceKind, PriceOf, ProjectMetadata,
+		ProjectMetadataOf, StringLimitOf,
+	};
+	use frame_support::{migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+	use polimec_common::credentials::Cid;
+	use sp_arithmetic::{FixedPointNumber, Percent};
+	use sp_std::{marker::PhantomData, vec::Vec};
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct OldProjectMetadata<BoundedString, Price: FixedPointNumber, AccountId, Cid> {
+		pub token_information: CurrencyMetadata<BoundedString>,
+		pub mainnet_token_max_supply: Balance,
+		pub total_allocation_size: Balance,
+		pub auction_round_allocation_percentage: Percent,
+		pub minimum_price: Price,
+		pub bidding_ticket_sizes: BiddingTicketSizes<Price>,
+		pub contributing_ticket_sizes: ContributingTicketSizes<Price>,
+		pub participation_currencies: BoundedVec<AcceptedFundingAsset, ConstU32<3>>,
+		pub funding_destination_account: AccountId,
+		pub policy_ipfs_cid: Option<Cid>,
+	}
+	type OldProjectMetadataOf<T> =
+		OldProjectMetadata<BoundedVec<u8, StringLimitOf<T>>, PriceOf<T>, AccountIdOf<T>, Cid>;
+
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct OldBucket<Price> {
+		pub amount_left: Balance,
+		pub current_price: Price,
+		pub initial_price: Price,
+		pub delta_price: Price,
+		pub delta_amount: Balance,
+	}
+
+	/// Also registers `FundingAssets`, the funding assets that used to be hardcoded.
+	pub struct UncheckedMigrationToV6<T: Config, FundingAssets>(PhantomData<(T, FundingAssets)>);
+	impl<T: Config, FundingAssets: Get<Vec<(AcceptedFundingAsset, FundingAssetInfo)>>> UncheckedOnRuntimeUpgrade
+		for UncheckedMigrationToV6<T, FundingAssets>
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let mut items = 0u64;
+			let translate = |_key, old: OldProjectMetadataOf<T>| -> Option<ProjectMetadataOf<T>> {
+				items += 1;
+				Some(ProjectMetadata {
+					token_information: old.token_information,
+					mainnet_token_max_supply: old.mainnet_token_max_supply,
+					total_allocation_size: old.total_allocation_size,
+					auction_round_allocation_percentage: old.auction_round_allocation_percentage,
+					candle_auction_closing_phase: None,
+					// Projects created so far used 10% price and amount steps.
+					bucket_curve: BucketCurve::default(),
+					price_kind: PriceKind::Spot,
+					minimum_price: old.minimum_price,
+					bidding_ticket_sizes: old.bidding_ticket_sizes,
+					contributing_ticket_sizes: old.contributing_ticket_sizes,
+					// A project accepted at most 3 assets, which is below `MAX_PARTICIPATION_CURRENCIES`.
+					participation_currencies: BoundedVec::truncate_from(old.participation_currencies.into_inner()),
+					funding_destination_account: old.funding_destination_account,
+					milestones: BoundedVec::new(),
+					policy_ipfs_cid: old.policy_ipfs_cid,
+				})
+			};
+			crate::ProjectsMetadata::<T>::translate(translate);
+			log::info!(target: LOG, "Number of project metadata migrated: {}", items);
+
+			let mut buckets = 0u64;
+			let translate_bucket = |project_id, old: OldBucket<PriceOf<T>>| -> Option<BucketOf<T>> {
+				buckets += 1;
+				let metadata = crate::ProjectsMetadata::<T>::get(project_id)?;
+				let auction_allocation = metadata.auction_round_allocation_percentage * metadata.total_allocation_size;
+				let index = old
+					.current_price
+					.saturating_sub(old.initial_price)
+					.checked_div(&old.delta_price)
+					.unwrap_or_default()
+					.round()
+					.saturating_mul_int(1u32);
+				let mut bucket = Bucket::new(auction_allocation, old.initial_price, metadata.bucket_curve);
+				bucket.index = index;
+				bucket.current_price = old.current_price;
+				bucket.amount_left = old.amount_left;
+				if index > 0 {
+					bucket.delta_price = old.delta_price;
+					bucket.delta_amount = old.delta_amount;
+				}
+				Some(bucket)
+			};
+			crate::Buckets::<T>::translate(translate_bucket);
+			log::info!(target: LOG, "Number of buckets migrated: {}", buckets);
+
+			let funding_assets = FundingAssets::get();
+			let registered = funding_assets.len() as u64;
+			for (asset, info) in funding_assets {
+				if let Err(e) = Pallet::<T>::do_register_funding_asset(asset, info) {
+					log::error!(target: LOG, "Funding asset {:?} could not be registered: {:?}", asset, e);
+				}
+			}
+			log::info!(target: LOG, "Number of funding assets registered: {}", registered);
+
+			T::DbWeight::get().reads_writes(items + buckets * 2 + registered * 2, items + buckets + registered * 2)
+		}
+	}
+
+	pub type MigrationToV6<T, FundingAssets> = VersionedMigration<
+		5,
+		6,
+		UncheckedMigrationToV6<T, FundingAssets>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
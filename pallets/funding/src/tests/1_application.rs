@@ -563,6 +563,91 @@ mod create_project_extrinsic {
 			});
 		}
 
+		#[test]
+		fn candle_auction_closing_phase_zero() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.candle_auction_closing_phase = Some(Percent::from_percent(0));
+
+			inst.mint_plmc_to(default_plmc_balances());
+			let jwt = get_mock_jwt_with_cid(
+				ISSUER_1,
+				InvestorType::Institutional,
+				generate_did_from_account(ISSUER_1),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+			inst.execute(|| {
+				assert_noop!(
+					Pallet::<TestRuntime>::create_project(RuntimeOrigin::signed(ISSUER_1), jwt, project_metadata),
+					Error::<TestRuntime>::CandleAuctionPhaseError
+				);
+			});
+		}
+
+		#[test]
+		fn bucket_curve_zero_step() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.bucket_curve =
+				BucketCurve::Linear { price_step: Percent::from_percent(0), amount_step: Percent::from_percent(10) };
+
+			inst.mint_plmc_to(default_plmc_balances());
+			let jwt = get_mock_jwt_with_cid(
+				ISSUER_1,
+				InvestorType::Institutional,
+				generate_did_from_account(ISSUER_1),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+			inst.execute(|| {
+				assert_noop!(
+					Pallet::<TestRuntime>::create_project(RuntimeOrigin::signed(ISSUER_1), jwt, project_metadata),
+					Error::<TestRuntime>::BucketCurveError
+				);
+			});
+		}
+
+		#[test]
+		fn milestone_shares_not_adding_up() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			let milestone = |share: u8| Milestone { share: Percent::from_percent(share), deliverable_cid: ipfs_hash() };
+
+			inst.mint_plmc_to(default_plmc_balances());
+			let jwt = get_mock_jwt_with_cid(
+				ISSUER_1,
+				InvestorType::Institutional,
+				generate_did_from_account(ISSUER_1),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+			let wrong_milestones = vec![
+				vec![milestone(30), milestone(30)],
+				vec![milestone(60), milestone(60)],
+				vec![milestone(0), milestone(100)],
+			];
+			for milestones in wrong_milestones {
+				project_metadata.milestones = milestones.try_into().unwrap();
+				inst.execute(|| {
+					assert_noop!(
+						Pallet::<TestRuntime>::create_project(
+							RuntimeOrigin::signed(ISSUER_1),
+							jwt.clone(),
+							project_metadata.clone()
+						),
+						Error::<TestRuntime>::MilestonesError
+					);
+				});
+			}
+
+			project_metadata.milestones = vec![milestone(25), milestone(75)].try_into().unwrap();
+			inst.execute(|| {
+				assert_ok!(Pallet::<TestRuntime>::create_project(
+					RuntimeOrigin::signed(ISSUER_1),
+					jwt,
+					project_metadata
+				));
+			});
+		}
+
 		#[test]
 		fn target_funding_less_than_1000_usd() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
@@ -862,6 +947,9 @@ mod edit_project_extrinsic {
 				mainnet_token_max_supply: 100_000_000 * CT_UNIT,
 				total_allocation_size: 5_000_000 * CT_UNIT,
 				auction_round_allocation_percentage: Percent::from_percent(30u8),
+				candle_auction_closing_phase: None,
+				bucket_curve: BucketCurve::default(),
+				price_kind: PriceKind::Spot,
 				minimum_price: PriceProviderOf::<TestRuntime>::calculate_decimals_aware_price(
 					PriceOf::<TestRuntime>::from_float(20.0),
 					USD_DECIMALS,
@@ -884,6 +972,7 @@ mod edit_project_extrinsic {
 					.unwrap(),
 
 				funding_destination_account: ISSUER_2,
+				milestones: Default::default(),
 				policy_ipfs_cid: Some(new_policy_hash),
 			};
 
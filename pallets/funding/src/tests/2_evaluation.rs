@@ -985,5 +985,44 @@ mod evaluate_extrinsic {
 				);
 			});
 		}
+
+		#[test]
+		fn cannot_evaluate_with_stale_plmc_price() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let jwt = get_mock_jwt_with_cid(
+				EVALUATOR_1,
+				InvestorType::Retail,
+				generate_did_from_account(EVALUATOR_1),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+			inst.mint_plmc_to(vec![(EVALUATOR_1, 2000 * PLMC).into()]);
+
+			ConstPriceProvider::set_price_timestamp(PLMC_FOREIGN_ID, 0);
+			PriceClock::set(MaxPriceAge::get() + 1);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::evaluate(
+						RuntimeOrigin::signed(EVALUATOR_1),
+						jwt.clone(),
+						project_id,
+						500 * USD_UNIT
+					),
+					Error::<TestRuntime>::PriceStale
+				);
+			});
+
+			// A new oracle update makes the price usable again
+			ConstPriceProvider::set_price_timestamp(PLMC_FOREIGN_ID, PriceClock::get());
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::evaluate(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					jwt.clone(),
+					project_id,
+					500 * USD_UNIT
+				));
+			});
+		}
 	}
 }
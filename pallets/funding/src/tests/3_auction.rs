@@ -3,6 +3,24 @@ use frame_support::traits::{fungible::InspectFreeze, fungibles::metadata::Inspec
 use sp_core::bounded_vec;
 use std::collections::HashSet;
 
+fn mint_for_bids(
+	inst: &mut MockInstantiator,
+	project_metadata: ProjectMetadataOf<TestRuntime>,
+	bids: &Vec<BidParams<TestRuntime>>,
+) -> (Vec<UserToPLMCBalance<TestRuntime>>, Vec<UserToFundingAsset<TestRuntime>>) {
+	let necessary_plmc = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+		bids,
+		project_metadata.clone(),
+		None,
+		true,
+	);
+	let necessary_usdt =
+		inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(bids, project_metadata, None);
+	inst.mint_plmc_to(necessary_plmc.clone());
+	inst.mint_funding_asset_to(necessary_usdt.clone());
+	(necessary_plmc, necessary_usdt)
+}
+
 #[cfg(test)]
 mod round_flow {
 	use super::*;
@@ -161,6 +179,7 @@ mod round_flow {
 					AcceptedFundingAsset::USDT => usdt_price,
 					AcceptedFundingAsset::USDC => usdc_price,
 					AcceptedFundingAsset::DOT => dot_price,
+					_ => panic!("Unexpected funding asset"),
 				};
 
 				let mut project_metadata = default_project_metadata.clone();
@@ -886,6 +905,33 @@ mod bid_extrinsic {
 			assert_eq!(bid_held_balance, Zero::zero());
 			assert_eq!(frozen_balance, frozen_amount);
 		}
+
+		#[test]
+		fn bid_bond_uses_twap_when_project_chooses_it() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.price_kind = PriceKind::Twap;
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+
+			let plmc_spot_price = inst.execute(|| <TestRuntime as Config>::PriceProvider::get_price(PLMC_FOREIGN_ID));
+			ConstPriceProvider::set_twap(PLMC_FOREIGN_ID, plmc_spot_price.unwrap() * 2.into());
+
+			let bids = vec![BidParams::new(BIDDER_1, 5000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT)];
+			mint_for_bids(&mut inst, project_metadata.clone(), &bids);
+			inst.bid_for_users(project_id, bids).unwrap();
+
+			inst.execute(|| {
+				let bid = Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_1)).next().unwrap();
+				let ticket_size = bid.original_ct_usd_price.saturating_mul_int(bid.original_ct_amount);
+				let twap_bond =
+					PolimecFunding::calculate_plmc_bond(ticket_size, bid.multiplier, PriceKind::Twap).unwrap();
+				let spot_bond =
+					PolimecFunding::calculate_plmc_bond(ticket_size, bid.multiplier, PriceKind::Spot).unwrap();
+				assert_eq!(bid.plmc_bond, twap_bond);
+				assert!(bid.plmc_bond < spot_bond);
+			});
+		}
 	}
 
 	#[cfg(test)]
@@ -1558,6 +1604,420 @@ mod bid_extrinsic {
 				);
 			});
 		}
+
+		#[test]
+		fn cannot_bid_with_stale_funding_asset_price() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+
+			ConstPriceProvider::set_price_timestamp(AcceptedFundingAsset::USDT.id(), 0);
+			PriceClock::set(MaxPriceAge::get() + 1);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::bid(
+						RuntimeOrigin::signed(BIDDER_1),
+						get_mock_jwt_with_cid(
+							BIDDER_1,
+							InvestorType::Professional,
+							generate_did_from_account(BIDDER_1),
+							project_metadata.clone().policy_ipfs_cid.unwrap()
+						),
+						project_id,
+						5000 * CT_UNIT,
+						1u8.try_into().unwrap(),
+						AcceptedFundingAsset::USDT
+					),
+					Error::<TestRuntime>::PriceStale
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod cancel_bid_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn cancelling_latest_bid_restores_bucket() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			let auction_allocation =
+				project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size;
+			let initial_bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+
+			// Fills the first bucket, so the project moves to the next one
+			let bids = vec![BidParams::new(BIDDER_1, auction_allocation, 1u8, AcceptedFundingAsset::USDT)];
+			let (necessary_plmc, necessary_usdt) = mint_for_bids(&mut inst, project_metadata.clone(), &bids);
+			inst.bid_for_users(project_id, bids).unwrap();
+			let bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			assert_ne!(bucket.current_price, initial_bucket.current_price);
+
+			let bid = inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_1)).next().unwrap());
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::cancel_bid(
+					RuntimeOrigin::signed(BIDDER_1),
+					get_mock_jwt_with_cid(
+						BIDDER_1,
+						InvestorType::Institutional,
+						generate_did_from_account(BIDDER_1),
+						project_metadata.clone().policy_ipfs_cid.unwrap()
+					),
+					project_id,
+					bid.id
+				));
+			});
+
+			let bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			assert_eq!(bucket, initial_bucket);
+			inst.execute(|| {
+				assert!(Bids::<TestRuntime>::get((project_id, BIDDER_1, bid.id)).is_none());
+				assert_eq!(BidCounts::<TestRuntime>::get(project_id), 0);
+				assert_eq!(AuctionBoughtUSD::<TestRuntime>::get((project_id, generate_did_from_account(BIDDER_1))), 0);
+			});
+			inst.do_free_plmc_assertions(necessary_plmc);
+			inst.do_free_funding_asset_assertions(necessary_usdt);
+		}
+
+		#[test]
+		fn cancelling_latest_bid_split_into_multiple_buckets_restores_bucket() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			let auction_allocation =
+				project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size;
+
+			// The second bid takes what is left of the first bucket and spills into the next one
+			let bid_1 = BidParams::new(BIDDER_1, auction_allocation / 2, 1u8, AcceptedFundingAsset::USDT);
+			let bid_2 =
+				BidParams::new(BIDDER_2, auction_allocation / 2 + 100 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			mint_for_bids(&mut inst, project_metadata.clone(), &vec![bid_1.clone(), bid_2.clone()]);
+			inst.bid_for_users(project_id, vec![bid_1]).unwrap();
+			let bucket_before_bid = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			inst.bid_for_users(project_id, vec![bid_2]).unwrap();
+
+			let mut bids =
+				inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_2)).collect::<Vec<_>>());
+			assert_eq!(bids.len(), 2);
+			bids.sort_by_key(|bid| bid.id);
+			inst.execute(|| {
+				assert_eq!(LastBidIds::<TestRuntime>::get(project_id), Some((bids[0].id, bids[1].id)));
+			});
+
+			for bid in bids.iter().rev() {
+				inst.execute(|| {
+					assert_ok!(PolimecFunding::cancel_bid(
+						RuntimeOrigin::signed(BIDDER_2),
+						get_mock_jwt_with_cid(
+							BIDDER_2,
+							InvestorType::Institutional,
+							generate_did_from_account(BIDDER_2),
+							project_metadata.clone().policy_ipfs_cid.unwrap()
+						),
+						project_id,
+						bid.id
+					));
+				});
+			}
+
+			let bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			assert_eq!(bucket, bucket_before_bid);
+		}
+
+		#[test]
+		fn bids_on_other_projects_do_not_prevent_restoring_the_bucket() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata_1 = default_project_metadata(ISSUER_1);
+			let project_metadata_2 = default_project_metadata(ISSUER_2);
+			let project_id_1 =
+				inst.create_auctioning_project(project_metadata_1.clone(), ISSUER_1, None, default_evaluations());
+			let project_id_2 =
+				inst.create_auctioning_project(project_metadata_2.clone(), ISSUER_2, None, default_evaluations());
+			let bucket_before_bid = inst.execute(|| Buckets::<TestRuntime>::get(project_id_1)).unwrap();
+
+			let bids_1 = vec![BidParams::new(BIDDER_1, 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT)];
+			mint_for_bids(&mut inst, project_metadata_1.clone(), &bids_1);
+			inst.bid_for_users(project_id_1, bids_1).unwrap();
+			let bid =
+				inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id_1, BIDDER_1)).next().unwrap());
+
+			// A later bid on another project doesn't change which bid is the latest one of the first project
+			let bids_2 = vec![BidParams::new(BIDDER_2, 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT)];
+			mint_for_bids(&mut inst, project_metadata_2.clone(), &bids_2);
+			inst.bid_for_users(project_id_2, bids_2).unwrap();
+			inst.execute(|| assert_eq!(LastBidIds::<TestRuntime>::get(project_id_1), Some((bid.id, bid.id))));
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::cancel_bid(
+					RuntimeOrigin::signed(BIDDER_1),
+					get_mock_jwt_with_cid(
+						BIDDER_1,
+						InvestorType::Institutional,
+						generate_did_from_account(BIDDER_1),
+						project_metadata_1.clone().policy_ipfs_cid.unwrap()
+					),
+					project_id_1,
+					bid.id
+				));
+			});
+
+			let bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id_1)).unwrap();
+			assert_eq!(bucket, bucket_before_bid);
+		}
+
+		#[test]
+		fn cancelling_older_bid_keeps_bucket() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+
+			let bids = vec![
+				BidParams::new(BIDDER_1, 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT),
+				BidParams::new(BIDDER_2, 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT),
+			];
+			mint_for_bids(&mut inst, project_metadata.clone(), &bids);
+			inst.bid_for_users(project_id, bids).unwrap();
+			let bucket_before_cancel = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+
+			let bid = inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_1)).next().unwrap());
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::cancel_bid(
+					RuntimeOrigin::signed(BIDDER_1),
+					get_mock_jwt_with_cid(
+						BIDDER_1,
+						InvestorType::Institutional,
+						generate_did_from_account(BIDDER_1),
+						project_metadata.clone().policy_ipfs_cid.unwrap()
+					),
+					project_id,
+					bid.id
+				));
+			});
+
+			let bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			assert_eq!(bucket, bucket_before_cancel);
+			inst.execute(|| {
+				assert_eq!(BidCounts::<TestRuntime>::get(project_id), 1);
+				assert_eq!(AuctionBoughtUSD::<TestRuntime>::get((project_id, generate_did_from_account(BIDDER_1))), 0);
+				assert_eq!(
+					AuctionBoughtUSD::<TestRuntime>::get((project_id, generate_did_from_account(BIDDER_2))),
+					project_metadata.minimum_price.saturating_mul_int(1000 * CT_UNIT)
+				);
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_cancel_after_auction_round() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_community_contributing_project(
+				project_metadata.clone(),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+			);
+
+			let bid = inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_1)).next().unwrap());
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::cancel_bid(
+						RuntimeOrigin::signed(BIDDER_1),
+						get_mock_jwt_with_cid(
+							BIDDER_1,
+							InvestorType::Institutional,
+							generate_did_from_account(BIDDER_1),
+							project_metadata.clone().policy_ipfs_cid.unwrap()
+						),
+						project_id,
+						bid.id
+					),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_cancel_bid_of_another_user() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+
+			let bids = vec![BidParams::new(BIDDER_1, 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT)];
+			mint_for_bids(&mut inst, project_metadata.clone(), &bids);
+			inst.bid_for_users(project_id, bids).unwrap();
+
+			let bid = inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_1)).next().unwrap());
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::cancel_bid(
+						RuntimeOrigin::signed(BIDDER_2),
+						get_mock_jwt_with_cid(
+							BIDDER_2,
+							InvestorType::Institutional,
+							generate_did_from_account(BIDDER_2),
+							project_metadata.clone().policy_ipfs_cid.unwrap()
+						),
+						project_id,
+						bid.id
+					),
+					Error::<TestRuntime>::ParticipationNotFound
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod amend_bid_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn lowering_bid_releases_the_difference() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			let auction_allocation =
+				project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size;
+
+			let bids = vec![BidParams::new(BIDDER_1, 2000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT)];
+			let (necessary_plmc, necessary_usdt) = mint_for_bids(&mut inst, project_metadata.clone(), &bids);
+			inst.bid_for_users(project_id, bids).unwrap();
+
+			let bid = inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_1)).next().unwrap());
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::amend_bid(
+					RuntimeOrigin::signed(BIDDER_1),
+					get_mock_jwt_with_cid(
+						BIDDER_1,
+						InvestorType::Institutional,
+						generate_did_from_account(BIDDER_1),
+						project_metadata.clone().policy_ipfs_cid.unwrap()
+					),
+					project_id,
+					bid.id,
+					1000 * CT_UNIT
+				));
+			});
+
+			let amended_bid = inst.execute(|| Bids::<TestRuntime>::get((project_id, BIDDER_1, bid.id)).unwrap());
+			assert_eq!(amended_bid.original_ct_amount, 1000 * CT_UNIT);
+			assert_eq!(amended_bid.plmc_bond, Perquintill::from_percent(50) * bid.plmc_bond);
+			assert_eq!(
+				amended_bid.funding_asset_amount_locked,
+				Perquintill::from_percent(50) * bid.funding_asset_amount_locked
+			);
+
+			let bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			assert_eq!(bucket.amount_left, auction_allocation - 1000 * CT_UNIT);
+			inst.execute(|| {
+				assert_eq!(
+					AuctionBoughtUSD::<TestRuntime>::get((project_id, generate_did_from_account(BIDDER_1))),
+					project_metadata.minimum_price.saturating_mul_int(1000 * CT_UNIT)
+				);
+			});
+
+			inst.do_reserved_plmc_assertions(
+				vec![UserToPLMCBalance::new(BIDDER_1, amended_bid.plmc_bond)],
+				HoldReason::Participation.into(),
+			);
+			inst.assert_funding_asset_free_balance(
+				BIDDER_1,
+				AcceptedFundingAsset::USDT.id(),
+				necessary_usdt[0].asset_amount - amended_bid.funding_asset_amount_locked,
+			);
+			let free_plmc = inst.get_free_plmc_balances_for(vec![BIDDER_1])[0].plmc_amount;
+			assert_eq!(free_plmc, necessary_plmc[0].plmc_amount - amended_bid.plmc_bond);
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_raise_bid() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+
+			let bids = vec![BidParams::new(BIDDER_1, 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT)];
+			mint_for_bids(&mut inst, project_metadata.clone(), &bids);
+			inst.bid_for_users(project_id, bids).unwrap();
+
+			let bid = inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_1)).next().unwrap());
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_amend_bid(
+						&BIDDER_1,
+						project_id,
+						bid.id,
+						1000 * CT_UNIT,
+						InvestorType::Institutional
+					),
+					Error::<TestRuntime>::TooHigh
+				);
+				assert_noop!(
+					PolimecFunding::do_amend_bid(
+						&BIDDER_1,
+						project_id,
+						bid.id,
+						2000 * CT_UNIT,
+						InvestorType::Institutional
+					),
+					Error::<TestRuntime>::TooHigh
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_lower_bid_below_minimum_ticket() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+
+			let bids = vec![BidParams::new(BIDDER_1, 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT)];
+			mint_for_bids(&mut inst, project_metadata.clone(), &bids);
+			inst.bid_for_users(project_id, bids).unwrap();
+
+			// Minimum ticket is 5000 USD, at 10 USD per CT
+			let bid = inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_1)).next().unwrap());
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_amend_bid(
+						&BIDDER_1,
+						project_id,
+						bid.id,
+						499 * CT_UNIT,
+						InvestorType::Institutional
+					),
+					Error::<TestRuntime>::TooLow
+				);
+			});
+		}
 	}
 }
 
@@ -1602,6 +2062,9 @@ mod end_auction_extrinsic {
 				mainnet_token_max_supply: 8_000_000 * CT_UNIT,
 				total_allocation_size: 100_000 * CT_UNIT,
 				auction_round_allocation_percentage: Percent::from_percent(50u8),
+				candle_auction_closing_phase: None,
+				bucket_curve: BucketCurve::default(),
+				price_kind: PriceKind::Spot,
 				minimum_price: decimal_aware_price,
 				bidding_ticket_sizes: BiddingTicketSizes {
 					professional: TicketSize::new(5000 * USD_UNIT, None),
@@ -1616,6 +2079,7 @@ mod end_auction_extrinsic {
 				},
 				participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 				funding_destination_account: ISSUER_1,
+				milestones: Default::default(),
 				policy_ipfs_cid: Some(metadata_hash),
 			};
 
@@ -1781,6 +2245,8 @@ mod end_auction_extrinsic {
 				inst.execute(|| Bids::<TestRuntime>::get((project_id, BIDDER_2, 1)).unwrap()).status,
 				BidStatus::PartiallyAccepted(32_000 * CT_UNIT)
 			);
+			// Around half of the target was raised, so the issuer has to decide on the outcome
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::AwaitingDecision);
 			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
 			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
 
@@ -1821,7 +2287,7 @@ mod end_auction_extrinsic {
 				.collect_vec();
 
 			let fundings = [AcceptedFundingAsset::USDT, AcceptedFundingAsset::USDC, AcceptedFundingAsset::DOT];
-			assert_eq!(fundings.len(), AcceptedFundingAsset::VARIANT_COUNT);
+			assert_eq!(fundings.len(), inst.execute(|| FundingAssets::<TestRuntime>::iter().count()));
 			let mut fundings = fundings.into_iter().cycle();
 
 			let usdt_fundings = accounts
@@ -1865,6 +2331,122 @@ mod end_auction_extrinsic {
 				Perquintill::from_float(0.99)
 			);
 		}
+
+		#[test]
+		fn candle_auction_rejects_bids_after_effective_end() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			// The end is picked one block before the last auction block
+			inst.execute(|| RandomSeed::set(&1u32));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.candle_auction_closing_phase = Some(Percent::from_percent(100));
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			let auction_allocation =
+				project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size;
+			let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+
+			// The late bid buys the whole allocation, so it moves the price if it counts towards the WAP
+			let early_bid = BidParams::new(BIDDER_1, 10_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			let late_bid = BidParams::new(BIDDER_2, auction_allocation, 1u8, AcceptedFundingAsset::USDT);
+			mint_for_bids(&mut inst, project_metadata.clone(), &vec![early_bid.clone(), late_bid.clone()]);
+
+			inst.bid_for_users(project_id, vec![early_bid]).unwrap();
+			inst.jump_to_block(auction_end);
+			inst.bid_for_users(project_id, vec![late_bid]).unwrap();
+
+			assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound(..)));
+
+			let candle_end = inst.execute(|| CandleAuctionEnds::<TestRuntime>::get(project_id));
+			assert_eq!(candle_end, Some(auction_end - 1));
+
+			let early_bids =
+				inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_1)).collect_vec());
+			assert_eq!(early_bids.len(), 1);
+			assert_eq!(early_bids[0].status, BidStatus::Accepted);
+
+			let late_bids =
+				inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_2)).collect_vec());
+			assert!(!late_bids.is_empty());
+			assert!(late_bids.iter().all(|bid| bid.status == BidStatus::Rejected));
+
+			let wap = inst.get_project_details(project_id).weighted_average_price.unwrap();
+			assert_eq!(wap, project_metadata.minimum_price);
+		}
+
+		#[test]
+		fn candle_auction_ending_on_the_last_block_keeps_all_bids() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			// An offset of zero keeps the original auction end
+			inst.execute(|| RandomSeed::set(&0u32));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.candle_auction_closing_phase = Some(Percent::from_percent(100));
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+
+			let early_bid = BidParams::new(BIDDER_1, 10_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			let late_bid = BidParams::new(BIDDER_2, 10_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			mint_for_bids(&mut inst, project_metadata.clone(), &vec![early_bid.clone(), late_bid.clone()]);
+
+			inst.bid_for_users(project_id, vec![early_bid]).unwrap();
+			inst.jump_to_block(auction_end);
+			inst.bid_for_users(project_id, vec![late_bid]).unwrap();
+
+			assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound(..)));
+
+			let candle_end = inst.execute(|| CandleAuctionEnds::<TestRuntime>::get(project_id));
+			assert_eq!(candle_end, Some(auction_end));
+
+			let bids = inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id,)).collect_vec());
+			assert_eq!(bids.len(), 2);
+			assert!(bids.iter().all(|bid| bid.status == BidStatus::Accepted));
+		}
+
+		#[test]
+		fn candle_auction_end_waits_for_randomness_revealed_after_the_auction() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.execute(|| RandomSeed::set(&0u32));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.candle_auction_closing_phase = Some(Percent::from_percent(100));
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+
+			// The randomness was already known during the auction, so the automatic transition is retried next block
+			inst.execute(|| RandomnessKnownSince::set(&Some(auction_end)));
+			inst.jump_to_block(auction_end + 1);
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionRound);
+			inst.execute(|| {
+				assert!(CandleAuctionEnds::<TestRuntime>::get(project_id).is_none());
+				assert_eq!(
+					ProjectsToUpdate::<TestRuntime>::get(auction_end + 2).to_vec(),
+					vec![(project_id, UpdateType::AuctionEnd)]
+				);
+				assert_noop!(
+					PolimecFunding::end_auction(RuntimeOrigin::signed(420), project_id),
+					Error::<TestRuntime>::CandleAuctionRandomnessNotReady
+				);
+			});
+
+			// New randomness is revealed, and the retry decides the end with it
+			inst.execute(|| RandomnessKnownSince::set(&Some(auction_end + 2)));
+			inst.jump_to_block(auction_end + 2);
+			assert!(matches!(inst.get_project_details(project_id).status, ProjectStatus::CommunityRound(..)));
+			inst.execute(|| assert_eq!(CandleAuctionEnds::<TestRuntime>::get(project_id), Some(auction_end)));
+		}
+
+		#[test]
+		fn regular_auction_has_no_candle_end() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_community_contributing_project(
+				default_project_metadata(ISSUER_1),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+			);
+			assert!(inst.execute(|| CandleAuctionEnds::<TestRuntime>::get(project_id)).is_none());
+		}
 	}
 
 	#[cfg(test)]
@@ -223,6 +223,7 @@ mod round_flow {
 					AcceptedFundingAsset::USDT => usdt_price,
 					AcceptedFundingAsset::USDC => usdc_price,
 					AcceptedFundingAsset::DOT => dot_price,
+					_ => panic!("Unexpected funding asset"),
 				};
 
 				let mut project_metadata = default_project_metadata.clone();
@@ -932,6 +933,8 @@ mod contribute_extrinsic {
 				));
 			});
 
+			// Less than the automatic success threshold was raised, so the default decision applies
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::AwaitingDecision);
 			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
 			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
 
@@ -1742,6 +1745,40 @@ mod contribute_extrinsic {
 			});
 		}
 
+		#[test]
+		fn cannot_contribute_with_stale_plmc_price() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_community_contributing_project(
+				project_metadata.clone(),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+			);
+
+			ConstPriceProvider::set_price_timestamp(PLMC_FOREIGN_ID, 0);
+			PriceClock::set(MaxPriceAge::get() + 1);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::contribute(
+						RuntimeOrigin::signed(BUYER_1),
+						get_mock_jwt_with_cid(
+							BUYER_1,
+							InvestorType::Retail,
+							generate_did_from_account(BUYER_1),
+							project_metadata.clone().policy_ipfs_cid.unwrap()
+						),
+						project_id,
+						5000 * CT_UNIT,
+						1u8.try_into().unwrap(),
+						AcceptedFundingAsset::USDT
+					),
+					Error::<TestRuntime>::PriceStale
+				);
+			});
+		}
+
 		#[test]
 		fn ct_sold_out() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
@@ -195,3 +195,216 @@ mod end_funding_extrinsic {
 		}
 	}
 }
+
+#[cfg(test)]
+mod decide_project_outcome_extrinsic {
+	use super::*;
+
+	fn create_project_awaiting_decision(percentage: u64) -> (MockInstantiator, ProjectId) {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let min_price = project_metadata.minimum_price;
+		let percentage_funded_usd = Perquintill::from_percent(percentage) *
+			(project_metadata.minimum_price.checked_mul_int(project_metadata.total_allocation_size).unwrap());
+		let bids = inst.generate_bids_from_total_usd(
+			Percent::from_percent(50u8) * percentage_funded_usd,
+			min_price,
+			default_weights(),
+			default_bidders(),
+			default_multipliers(),
+		);
+		let contributions = inst.generate_contributions_from_total_usd(
+			Percent::from_percent(50u8) * percentage_funded_usd,
+			min_price,
+			default_weights(),
+			default_community_contributors(),
+			default_multipliers(),
+		);
+		let project_id = inst.create_remainder_contributing_project(
+			project_metadata,
+			ISSUER_1,
+			None,
+			default_evaluations(),
+			bids,
+			contributions,
+		);
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::AwaitingDecision);
+
+		(inst, project_id)
+	}
+
+	fn issuer_jwt() -> UntrustedToken {
+		get_mock_jwt_with_cid(
+			ISSUER_1,
+			InvestorType::Institutional,
+			generate_did_from_account(ISSUER_1),
+			default_project_metadata(ISSUER_1).policy_ipfs_cid.unwrap(),
+		)
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn funding_between_thresholds_awaits_decision() {
+			let (mut inst, project_id) = create_project_awaiting_decision(60);
+			let project_details = inst.get_project_details(project_id);
+			let decision_start = project_details.round_duration.start().unwrap();
+
+			assert_eq!(project_details.evaluation_round_info.evaluators_outcome, None);
+			assert_eq!(
+				project_details.round_duration.end(),
+				Some(decision_start + <TestRuntime as Config>::ManualAcceptanceDuration::get() - 1)
+			);
+			inst.execute(|| {
+				assert!(ProjectsToUpdate::<TestRuntime>::get(project_details.round_duration.end().unwrap() + 1)
+					.contains(&(project_id, UpdateType::ProjectDecision)));
+			});
+		}
+
+		#[test]
+		fn issuer_accepts_funding() {
+			let (mut inst, project_id) = create_project_awaiting_decision(60);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::decide_project_outcome(
+					RuntimeOrigin::signed(ISSUER_1),
+					issuer_jwt(),
+					project_id,
+					FundingOutcomeDecision::AcceptFunding
+				));
+				System::assert_last_event(
+					Event::ProjectOutcomeDecided { project_id, decision: FundingOutcomeDecision::AcceptFunding }.into(),
+				);
+			});
+
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.status, ProjectStatus::FundingSuccessful);
+			assert!(matches!(
+				project_details.evaluation_round_info.evaluators_outcome,
+				Some(EvaluatorsOutcome::Rewarded(..))
+			));
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+		}
+
+		#[test]
+		fn issuer_rejects_funding() {
+			let (mut inst, project_id) = create_project_awaiting_decision(60);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::decide_project_outcome(
+					RuntimeOrigin::signed(ISSUER_1),
+					issuer_jwt(),
+					project_id,
+					FundingOutcomeDecision::RejectFunding
+				));
+			});
+
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.status, ProjectStatus::FundingFailed);
+			assert_eq!(project_details.evaluation_round_info.evaluators_outcome, Some(EvaluatorsOutcome::Slashed));
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Failure));
+		}
+
+		#[test]
+		fn default_decision_applies_after_the_window() {
+			let (mut inst, project_id) = create_project_awaiting_decision(60);
+			DefaultFundingDecision::set(FundingOutcomeDecision::RejectFunding);
+
+			let decision_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+			inst.jump_to_block(decision_end);
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AwaitingDecision);
+			inst.advance_time(1u64);
+
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.status, ProjectStatus::FundingFailed);
+			assert_eq!(project_details.evaluation_round_info.evaluators_outcome, Some(EvaluatorsOutcome::Slashed));
+			inst.execute(|| {
+				System::assert_has_event(
+					Event::ProjectOutcomeDecided { project_id, decision: FundingOutcomeDecision::RejectFunding }.into(),
+				);
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn called_by_non_issuer() {
+			let (mut inst, project_id) = create_project_awaiting_decision(60);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::decide_project_outcome(
+						RuntimeOrigin::signed(ISSUER_2),
+						get_mock_jwt_with_cid(
+							ISSUER_2,
+							InvestorType::Institutional,
+							generate_did_from_account(ISSUER_2),
+							default_project_metadata(ISSUER_2).policy_ipfs_cid.unwrap(),
+						),
+						project_id,
+						FundingOutcomeDecision::AcceptFunding
+					),
+					Error::<TestRuntime>::NotIssuer
+				);
+			});
+		}
+
+		#[test]
+		fn called_with_non_institutional_credential() {
+			let (mut inst, project_id) = create_project_awaiting_decision(60);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::decide_project_outcome(
+						RuntimeOrigin::signed(ISSUER_1),
+						get_mock_jwt_with_cid(
+							ISSUER_1,
+							InvestorType::Professional,
+							generate_did_from_account(ISSUER_1),
+							default_project_metadata(ISSUER_1).policy_ipfs_cid.unwrap(),
+						),
+						project_id,
+						FundingOutcomeDecision::AcceptFunding
+					),
+					Error::<TestRuntime>::WrongInvestorType
+				);
+			});
+		}
+
+		#[test]
+		fn project_not_awaiting_decision() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(95, false);
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::FundingSuccessful);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::decide_project_outcome(
+						RuntimeOrigin::signed(ISSUER_1),
+						issuer_jwt(),
+						project_id,
+						FundingOutcomeDecision::RejectFunding
+					),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+
+		#[test]
+		fn called_after_the_window() {
+			let (mut inst, project_id) = create_project_awaiting_decision(60);
+			let decision_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+			inst.jump_to_block(decision_end);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::decide_project_outcome(
+						RuntimeOrigin::signed(ISSUER_1),
+						issuer_jwt(),
+						project_id,
+						FundingOutcomeDecision::RejectFunding
+					),
+					Error::<TestRuntime>::TooLateForRound
+				);
+			});
+		}
+	}
+}
@@ -464,6 +464,8 @@ mod settle_bid_extrinsic {
 			let hold_reason: RuntimeHoldReason = HoldReason::Participation.into();
 			let vesting_time = Multiplier::force_new(3).calculate_vesting_duration::<TestRuntime>();
 			let now = inst.current_block();
+			// The second bid is settled manually below, so `on_idle` must not settle it while time passes
+			inst.execute(|| ProjectsToSettleOnIdle::<TestRuntime>::remove(project_id));
 			inst.jump_to_block(now + vesting_time + 1u64);
 			inst.execute(|| LinearRelease::vest(RuntimeOrigin::signed(BIDDER_1), hold_reason).expect("Vesting failed"));
 
@@ -1260,6 +1262,206 @@ mod settle_contribution_extrinsic {
 	}
 }
 
+#[cfg(test)]
+mod settle_project_batch_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn batches_settle_whole_project() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(100, true);
+			let evaluations = inst.get_evaluations(project_id);
+			let bids = inst.get_bids(project_id);
+			let contributions = inst.get_contributions(project_id);
+
+			while inst.execute(|| SettlementCursors::<TestRuntime>::get(project_id)) != SettlementCursor::Finished {
+				inst.execute(|| {
+					assert_ok!(PolimecFunding::settle_project_batch(RuntimeOrigin::signed(80085), project_id, 5));
+				});
+			}
+
+			assert!(inst.get_evaluations(project_id).is_empty());
+			assert!(inst.get_bids(project_id).is_empty());
+			assert!(inst.get_contributions(project_id).is_empty());
+			inst.assert_total_funding_paid_out(project_id, bids.clone(), contributions.clone());
+			inst.assert_evaluations_migrations_created(project_id, evaluations, true);
+			inst.assert_bids_migrations_created(project_id, bids, true);
+			inst.assert_contributions_migrations_created(project_id, contributions, true);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::mark_project_as_settled(RuntimeOrigin::signed(80085), project_id));
+				assert_eq!(SettlementCursors::<TestRuntime>::get(project_id), SettlementCursor::default());
+			});
+		}
+
+		#[test]
+		fn batch_settles_up_to_limit() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(32, true);
+			let evaluations = inst.get_evaluations(project_id);
+			assert_eq!(evaluations.len(), 3);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::settle_project_batch(RuntimeOrigin::signed(80085), project_id, 2));
+			});
+
+			assert_eq!(inst.get_evaluations(project_id).len(), 1);
+			assert!(matches!(
+				inst.execute(|| SettlementCursors::<TestRuntime>::get(project_id)),
+				SettlementCursor::Evaluations(Some(_))
+			));
+		}
+
+		#[test]
+		fn skipped_participations_are_retried_after_the_cursor_finished() {
+			use frame_support::traits::{fungible::MutateHold, tokens::Precision};
+
+			let (mut inst, project_id) = create_project_with_funding_percentage(100, true);
+			let evaluation = inst.get_evaluations(project_id)[0].clone();
+			let evaluation_reason: RuntimeHoldReason = HoldReason::Evaluation.into();
+
+			// Without its PLMC on hold, the evaluation cannot be settled
+			inst.execute(|| {
+				assert_ok!(<TestRuntime as Config>::NativeCurrency::release(
+					&evaluation_reason,
+					&evaluation.evaluator,
+					evaluation.current_plmc_bond,
+					Precision::Exact
+				));
+				assert_ok!(PolimecFunding::settle_project_batch(RuntimeOrigin::signed(80085), project_id, u32::MAX));
+				assert_eq!(SettlementCursors::<TestRuntime>::get(project_id), SettlementCursor::Finished);
+			});
+			assert_eq!(inst.get_evaluations(project_id), vec![evaluation.clone()]);
+			assert!(inst.get_bids(project_id).is_empty());
+			assert!(inst.get_contributions(project_id).is_empty());
+
+			inst.execute(|| {
+				assert_ok!(<TestRuntime as Config>::NativeCurrency::hold(
+					&evaluation_reason,
+					&evaluation.evaluator,
+					evaluation.current_plmc_bond
+				));
+				assert_ok!(PolimecFunding::settle_project_batch(RuntimeOrigin::signed(80085), project_id, 1));
+			});
+			assert!(inst.get_evaluations(project_id).is_empty());
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::mark_project_as_settled(RuntimeOrigin::signed(80085), project_id));
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_be_called_before_settlement_started() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(64, false);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::settle_project_batch(RuntimeOrigin::signed(80085), project_id, 10),
+					Error::<TestRuntime>::SettlementNotStarted
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod on_idle_settlement {
+	use super::*;
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	#[test]
+	fn successful_project_is_settled_on_idle() {
+		let (mut inst, project_id) = create_project_with_funding_percentage(100, true);
+		let evaluations = inst.get_evaluations(project_id);
+		let bids = inst.get_bids(project_id);
+		let contributions = inst.get_contributions(project_id);
+
+		inst.advance_time(1u64);
+
+		assert_eq!(
+			inst.get_project_details(project_id).status,
+			ProjectStatus::SettlementFinished(FundingOutcome::Success)
+		);
+		inst.assert_total_funding_paid_out(project_id, bids.clone(), contributions.clone());
+		inst.assert_evaluations_migrations_created(project_id, evaluations, true);
+		inst.assert_bids_migrations_created(project_id, bids, true);
+		inst.assert_contributions_migrations_created(project_id, contributions, true);
+		inst.execute(|| {
+			assert!(!SettlementCursors::<TestRuntime>::contains_key(project_id));
+			assert!(!ProjectsToSettleOnIdle::<TestRuntime>::contains_key(project_id));
+		});
+	}
+
+	#[test]
+	fn failed_project_is_settled_on_idle() {
+		let (mut inst, project_id) = create_project_with_funding_percentage(32, true);
+		let evaluations = inst.get_evaluations(project_id);
+		let bids = inst.get_bids(project_id);
+		let contributions = inst.get_contributions(project_id);
+
+		inst.advance_time(1u64);
+
+		assert_eq!(
+			inst.get_project_details(project_id).status,
+			ProjectStatus::SettlementFinished(FundingOutcome::Failure)
+		);
+		inst.assert_evaluations_migrations_created(project_id, evaluations, false);
+		inst.assert_bids_migrations_created(project_id, bids, false);
+		inst.assert_contributions_migrations_created(project_id, contributions, false);
+	}
+
+	#[test]
+	fn only_leftover_weight_is_used() {
+		let (mut inst, project_id) = create_project_with_funding_percentage(100, true);
+		let evaluations = inst.get_evaluations(project_id);
+
+		inst.execute(|| {
+			let now = System::block_number();
+			PolimecFunding::on_idle(now, Weight::zero());
+			assert_eq!(evaluations_left(project_id), evaluations.len());
+
+			// Enough weight for a single settlement
+			PolimecFunding::on_idle(now, PolimecFunding::settlement_operation_weight());
+			assert_eq!(evaluations_left(project_id), evaluations.len() - 1);
+		});
+		assert_eq!(
+			inst.get_project_details(project_id).status,
+			ProjectStatus::SettlementStarted(FundingOutcome::Success)
+		);
+	}
+
+	#[test]
+	fn projects_settled_with_extrinsics_leave_the_queue() {
+		let (mut inst, project_id) = create_project_with_funding_percentage(100, true);
+		inst.settle_project(project_id, true);
+		inst.execute(|| {
+			assert!(!ProjectsToSettleOnIdle::<TestRuntime>::contains_key(project_id));
+		});
+
+		// Manually settled, but not marked as settled yet
+		let (mut inst, project_id) = create_project_with_funding_percentage(100, true);
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::settle_project_batch(RuntimeOrigin::signed(80085), project_id, u32::MAX));
+			PolimecFunding::on_idle(System::block_number(), Weight::MAX);
+			assert!(!ProjectsToSettleOnIdle::<TestRuntime>::contains_key(project_id));
+		});
+		assert_eq!(
+			inst.get_project_details(project_id).status,
+			ProjectStatus::SettlementFinished(FundingOutcome::Success)
+		);
+	}
+
+	fn evaluations_left(project_id: ProjectId) -> usize {
+		Evaluations::<TestRuntime>::iter_prefix((project_id,)).count()
+	}
+}
+
 #[cfg(test)]
 mod mark_project_as_settled_extrinsic {
 	use super::*;
@@ -0,0 +1,594 @@
+use super::*;
+use sp_core::ConstU32;
+use sp_runtime::DispatchError;
+
+fn milestones() -> BoundedVec<Milestone<Cid>, ConstU32<MAX_MILESTONES>> {
+	vec![
+		Milestone { share: Percent::from_percent(40u8), deliverable_cid: ipfs_hash() },
+		Milestone { share: Percent::from_percent(60u8), deliverable_cid: ipfs_hash() },
+	]
+	.try_into()
+	.unwrap()
+}
+
+fn create_project_with_milestones() -> (MockInstantiator, ProjectId) {
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let mut project_metadata = default_project_metadata(ISSUER_1);
+	project_metadata.milestones = milestones();
+	let project_id = inst.create_settled_project(
+		project_metadata,
+		ISSUER_1,
+		None,
+		default_evaluations(),
+		default_bids(),
+		default_community_contributions(),
+		default_remainder_contributions(),
+		true,
+	);
+	(inst, project_id)
+}
+
+fn issuer_jwt() -> UntrustedToken {
+	get_mock_jwt_with_cid(
+		ISSUER_1,
+		InvestorType::Institutional,
+		generate_did_from_account(ISSUER_1),
+		default_project_metadata(ISSUER_1).policy_ipfs_cid.unwrap(),
+	)
+}
+
+fn shareholders(inst: &mut MockInstantiator, project_id: ProjectId) -> Vec<(AccountId, Balance)> {
+	inst.execute(|| EscrowShares::<TestRuntime>::iter_prefix(project_id).collect())
+}
+
+fn issuer_usdt(inst: &mut MockInstantiator) -> Balance {
+	inst.get_free_funding_asset_balance_for(AcceptedFundingAsset::USDT.id(), ISSUER_1)
+}
+
+fn submit_and_vote(inst: &mut MockInstantiator, project_id: ProjectId, milestone: u8, approve: bool) {
+	let voters = shareholders(inst, project_id);
+	inst.execute(|| {
+		assert_ok!(PolimecFunding::submit_milestone(
+			RuntimeOrigin::signed(ISSUER_1),
+			issuer_jwt(),
+			project_id,
+			milestone
+		));
+		for (voter, _) in voters {
+			assert_ok!(PolimecFunding::vote_milestone(RuntimeOrigin::signed(voter), project_id, milestone, approve));
+		}
+	});
+	let now = inst.current_block();
+	inst.jump_to_block(now + <TestRuntime as Config>::MilestoneVotingDuration::get() + 1);
+}
+
+#[cfg(test)]
+mod round_flow {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn settlement_holds_funds_in_escrow() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.milestones = milestones();
+			let project_id = inst.create_finished_project(
+				project_metadata,
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+			);
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+			let issuer_usdt_before = issuer_usdt(&mut inst);
+
+			inst.settle_project(project_id, true);
+
+			assert_eq!(issuer_usdt(&mut inst), issuer_usdt_before);
+			let escrow = inst.execute(|| EscrowedFunds::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT));
+			assert!(escrow.deposited > 0);
+			assert_eq!(escrow.remaining, escrow.deposited);
+
+			let total_shares = inst.execute(|| ProjectEscrows::<TestRuntime>::get(project_id).total_shares);
+			let holders = shareholders(&mut inst, project_id);
+			assert_eq!(holders.iter().map(|(_, shares)| shares).sum::<Balance>(), total_shares);
+			let ct_sold = inst.get_project_details(project_id).remaining_contribution_tokens;
+			let total_allocation = inst.get_project_metadata(project_id).total_allocation_size;
+			assert_eq!(total_shares, total_allocation - ct_sold);
+		}
+
+		#[test]
+		fn approved_milestones_release_funds_to_issuer() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			let escrow = inst.execute(|| EscrowedFunds::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT));
+			let issuer_usdt_before = issuer_usdt(&mut inst);
+
+			submit_and_vote(&mut inst, project_id, 0, true);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::resolve_milestone(RuntimeOrigin::signed(BUYER_1), project_id, 0));
+				System::assert_last_event(Event::MilestoneApproved { project_id, milestone: 0 }.into());
+			});
+			let first_release = Percent::from_percent(40u8) * escrow.deposited;
+			assert_eq!(issuer_usdt(&mut inst), issuer_usdt_before + first_release);
+
+			submit_and_vote(&mut inst, project_id, 1, true);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::resolve_milestone(RuntimeOrigin::signed(BUYER_1), project_id, 1));
+			});
+			assert_eq!(issuer_usdt(&mut inst), issuer_usdt_before + escrow.deposited);
+			let escrow = inst.execute(|| EscrowedFunds::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT));
+			assert_eq!(escrow.remaining, 0);
+		}
+
+		#[test]
+		fn votes_are_weighted_by_escrow_shares() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			let mut holders = shareholders(&mut inst, project_id);
+			holders.sort_by_key(|(_, shares)| *shares);
+			let (biggest_holder, biggest_shares) = holders.pop().unwrap();
+			let others_shares = holders.iter().map(|(_, shares)| shares).sum::<Balance>();
+			assert!(biggest_shares < others_shares);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::submit_milestone(
+					RuntimeOrigin::signed(ISSUER_1),
+					issuer_jwt(),
+					project_id,
+					0
+				));
+				assert_ok!(PolimecFunding::vote_milestone(RuntimeOrigin::signed(biggest_holder), project_id, 0, true));
+				System::assert_last_event(
+					Event::MilestoneVoted {
+						project_id,
+						milestone: 0,
+						voter: biggest_holder,
+						approve: true,
+						weight: biggest_shares,
+					}
+					.into(),
+				);
+				for (voter, _) in holders {
+					assert_ok!(PolimecFunding::vote_milestone(RuntimeOrigin::signed(voter), project_id, 0, false));
+				}
+			});
+			let status = inst.execute(|| MilestoneStatuses::<TestRuntime>::get(project_id, 0));
+			assert!(matches!(
+				status,
+				Some(MilestoneStatus::Voting { ayes, nays, .. }) if ayes == biggest_shares && nays == others_shares
+			));
+
+			let now = inst.current_block();
+			inst.jump_to_block(now + <TestRuntime as Config>::MilestoneVotingDuration::get() + 1);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::resolve_milestone(RuntimeOrigin::signed(ISSUER_1), project_id, 0));
+				assert_eq!(MilestoneStatuses::<TestRuntime>::get(project_id, 0), Some(MilestoneStatus::Rejected));
+			});
+		}
+
+		#[test]
+		fn milestone_without_votes_is_rejected() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::submit_milestone(
+					RuntimeOrigin::signed(ISSUER_1),
+					issuer_jwt(),
+					project_id,
+					0
+				));
+			});
+			let now = inst.current_block();
+			inst.jump_to_block(now + <TestRuntime as Config>::MilestoneVotingDuration::get() + 1);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::resolve_milestone(RuntimeOrigin::signed(BUYER_1), project_id, 0));
+				System::assert_last_event(Event::MilestoneRejected { project_id, milestone: 0 }.into());
+				assert!(ProjectEscrows::<TestRuntime>::get(project_id).refunding);
+			});
+		}
+
+		#[test]
+		fn governance_can_force_resolve_a_milestone() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			let escrow = inst.execute(|| EscrowedFunds::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT));
+			let issuer_usdt_before = issuer_usdt(&mut inst);
+
+			// Governance can approve a milestone the issuer never submitted
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::force_resolve_milestone(RuntimeOrigin::root(), project_id, 0, true));
+			});
+			assert_eq!(issuer_usdt(&mut inst), issuer_usdt_before + Percent::from_percent(40u8) * escrow.deposited);
+
+			// And override an ongoing vote
+			submit_and_vote(&mut inst, project_id, 1, true);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::force_resolve_milestone(RuntimeOrigin::root(), project_id, 1, false));
+				System::assert_last_event(Event::MilestoneRejected { project_id, milestone: 1 }.into());
+				assert!(ProjectEscrows::<TestRuntime>::get(project_id).refunding);
+			});
+		}
+
+		#[test]
+		fn rejected_milestone_refunds_participants_pro_rata() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			submit_and_vote(&mut inst, project_id, 0, true);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::resolve_milestone(RuntimeOrigin::signed(BUYER_1), project_id, 0));
+			});
+			submit_and_vote(&mut inst, project_id, 1, false);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::resolve_milestone(RuntimeOrigin::signed(BUYER_1), project_id, 1));
+			});
+
+			let escrow = inst.execute(|| EscrowedFunds::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT));
+			let mut total_refunded = 0u128;
+			for (participant, shares) in shareholders(&mut inst, project_id) {
+				let usdt_paid = inst.execute(|| {
+					EscrowDeposits::<TestRuntime>::get((project_id, participant, AcceptedFundingAsset::USDT))
+				});
+				let usdt_before = inst.get_free_funding_asset_balance_for(AcceptedFundingAsset::USDT.id(), participant);
+				inst.execute(|| {
+					assert_ok!(PolimecFunding::claim_escrow_refund(RuntimeOrigin::signed(participant), project_id));
+					System::assert_last_event(
+						Event::EscrowRefunded { project_id, account: participant, shares }.into(),
+					);
+				});
+				let refunded =
+					inst.get_free_funding_asset_balance_for(AcceptedFundingAsset::USDT.id(), participant) - usdt_before;
+				assert_eq!(refunded, Perquintill::from_rational(usdt_paid, escrow.deposited) * escrow.remaining);
+				let deposits_left =
+					inst.execute(|| EscrowDeposits::<TestRuntime>::iter_prefix((project_id, participant)).count());
+				assert_eq!(deposits_left, 0);
+				total_refunded += refunded;
+			}
+			assert!(total_refunded <= escrow.remaining);
+			assert_close_enough!(total_refunded, escrow.remaining, Perquintill::from_float(0.999));
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn project_without_milestones_has_no_escrow() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_settled_project(
+				default_project_metadata(ISSUER_1),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+				true,
+			);
+			assert!(shareholders(&mut inst, project_id).is_empty());
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::submit_milestone(RuntimeOrigin::signed(ISSUER_1), issuer_jwt(), project_id, 0),
+					Error::<TestRuntime>::MilestoneNotFound
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod submit_milestone_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn only_issuer_can_submit() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::submit_milestone(
+						RuntimeOrigin::signed(ISSUER_2),
+						get_mock_jwt_with_cid(
+							ISSUER_2,
+							InvestorType::Institutional,
+							generate_did_from_account(ISSUER_2),
+							default_project_metadata(ISSUER_2).policy_ipfs_cid.unwrap(),
+						),
+						project_id,
+						0
+					),
+					Error::<TestRuntime>::NotIssuer
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_submit_before_settlement() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.milestones = milestones();
+			let project_id = inst.create_finished_project(
+				project_metadata,
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+			);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::submit_milestone(RuntimeOrigin::signed(ISSUER_1), issuer_jwt(), project_id, 0),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+
+		#[test]
+		fn milestones_are_submitted_in_order() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::submit_milestone(RuntimeOrigin::signed(ISSUER_1), issuer_jwt(), project_id, 1),
+					Error::<TestRuntime>::PreviousMilestoneNotApproved
+				);
+				assert_noop!(
+					PolimecFunding::submit_milestone(RuntimeOrigin::signed(ISSUER_1), issuer_jwt(), project_id, 2),
+					Error::<TestRuntime>::MilestoneNotFound
+				);
+				assert_ok!(PolimecFunding::submit_milestone(
+					RuntimeOrigin::signed(ISSUER_1),
+					issuer_jwt(),
+					project_id,
+					0
+				));
+				assert_noop!(
+					PolimecFunding::submit_milestone(RuntimeOrigin::signed(ISSUER_1), issuer_jwt(), project_id, 0),
+					Error::<TestRuntime>::MilestoneAlreadySubmitted
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_submit_after_rejection() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::force_resolve_milestone(RuntimeOrigin::root(), project_id, 0, false));
+				assert_noop!(
+					PolimecFunding::submit_milestone(RuntimeOrigin::signed(ISSUER_1), issuer_jwt(), project_id, 1),
+					Error::<TestRuntime>::EscrowRefunding
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod vote_milestone_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_vote_on_milestone_not_in_voting() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			let (voter, _) = shareholders(&mut inst, project_id)[0];
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::vote_milestone(RuntimeOrigin::signed(voter), project_id, 0, true),
+					Error::<TestRuntime>::MilestoneNotInVoting
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_vote_without_escrow_shares() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::submit_milestone(
+					RuntimeOrigin::signed(ISSUER_1),
+					issuer_jwt(),
+					project_id,
+					0
+				));
+				assert_noop!(
+					PolimecFunding::vote_milestone(RuntimeOrigin::signed(EVALUATOR_1), project_id, 0, true),
+					Error::<TestRuntime>::NoEscrowShares
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_vote_twice() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			let (voter, _) = shareholders(&mut inst, project_id)[0];
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::submit_milestone(
+					RuntimeOrigin::signed(ISSUER_1),
+					issuer_jwt(),
+					project_id,
+					0
+				));
+				assert_ok!(PolimecFunding::vote_milestone(RuntimeOrigin::signed(voter), project_id, 0, true));
+				assert_noop!(
+					PolimecFunding::vote_milestone(RuntimeOrigin::signed(voter), project_id, 0, false),
+					Error::<TestRuntime>::MilestoneAlreadyVoted
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_vote_after_voting_period() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			let (voter, _) = shareholders(&mut inst, project_id)[0];
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::submit_milestone(
+					RuntimeOrigin::signed(ISSUER_1),
+					issuer_jwt(),
+					project_id,
+					0
+				));
+			});
+			let now = inst.current_block();
+			inst.jump_to_block(now + <TestRuntime as Config>::MilestoneVotingDuration::get() + 1);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::vote_milestone(RuntimeOrigin::signed(voter), project_id, 0, true),
+					Error::<TestRuntime>::TooLateForRound
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod resolve_milestone_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_resolve_during_voting_period() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::submit_milestone(
+					RuntimeOrigin::signed(ISSUER_1),
+					issuer_jwt(),
+					project_id,
+					0
+				));
+				assert_noop!(
+					PolimecFunding::resolve_milestone(RuntimeOrigin::signed(BUYER_1), project_id, 0),
+					Error::<TestRuntime>::TooEarlyForRound
+				);
+			});
+		}
+
+		#[test]
+		fn only_milestone_origin_can_force_resolve() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::force_resolve_milestone(RuntimeOrigin::signed(ISSUER_1), project_id, 0, true),
+					DispatchError::BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_force_resolve_before_settlement() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.milestones = milestones();
+			let project_id = inst.create_finished_project(
+				project_metadata,
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+			);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::force_resolve_milestone(RuntimeOrigin::root(), project_id, 0, true),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_force_resolve_an_already_resolved_milestone() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::force_resolve_milestone(RuntimeOrigin::root(), project_id, 0, true));
+				assert_noop!(
+					PolimecFunding::force_resolve_milestone(RuntimeOrigin::root(), project_id, 0, false),
+					Error::<TestRuntime>::MilestoneAlreadySubmitted
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod claim_escrow_refund_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn refunds_the_account_cannot_receive_can_be_claimed_later() {
+			use sp_runtime::traits::AccountIdConversion;
+
+			let (mut inst, project_id) = create_project_with_milestones();
+			let (participant, shares) = shareholders(&mut inst, project_id)[0];
+			let usdt_id = AcceptedFundingAsset::USDT.id();
+			let usdt_deposit = (project_id, participant, AcceptedFundingAsset::USDT);
+			let asset_owner: AccountId = <TestRuntime as Config>::PalletId::get().into_account_truncating();
+			inst.mint_funding_asset_to(vec![UserToFundingAsset::new(participant, 100, usdt_id)]);
+
+			// A blocked account cannot receive its USDT refund
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::force_resolve_milestone(RuntimeOrigin::root(), project_id, 0, false));
+				assert_ok!(ForeignAssets::block(RuntimeOrigin::signed(asset_owner), usdt_id.into(), participant));
+				assert_ok!(PolimecFunding::claim_escrow_refund(RuntimeOrigin::signed(participant), project_id));
+				assert!(EscrowDeposits::<TestRuntime>::contains_key(usdt_deposit));
+				assert_eq!(EscrowShares::<TestRuntime>::get(project_id, participant), shares);
+			});
+			let usdt_before = inst.get_free_funding_asset_balance_for(usdt_id, participant);
+
+			inst.execute(|| {
+				assert_ok!(ForeignAssets::thaw(RuntimeOrigin::signed(asset_owner), usdt_id.into(), participant));
+				assert_ok!(PolimecFunding::claim_escrow_refund(RuntimeOrigin::signed(participant), project_id));
+				assert_eq!(EscrowDeposits::<TestRuntime>::iter_prefix((project_id, participant)).count(), 0);
+				assert_noop!(
+					PolimecFunding::claim_escrow_refund(RuntimeOrigin::signed(participant), project_id),
+					Error::<TestRuntime>::NoEscrowShares
+				);
+			});
+			assert!(inst.get_free_funding_asset_balance_for(usdt_id, participant) > usdt_before);
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_claim_while_escrow_is_not_refunding() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			let (participant, _) = shareholders(&mut inst, project_id)[0];
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::claim_escrow_refund(RuntimeOrigin::signed(participant), project_id),
+					Error::<TestRuntime>::EscrowNotRefunding
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_claim_twice() {
+			let (mut inst, project_id) = create_project_with_milestones();
+			let (participant, _) = shareholders(&mut inst, project_id)[0];
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::force_resolve_milestone(RuntimeOrigin::root(), project_id, 0, false));
+				assert_ok!(PolimecFunding::claim_escrow_refund(RuntimeOrigin::signed(participant), project_id));
+				assert_noop!(
+					PolimecFunding::claim_escrow_refund(RuntimeOrigin::signed(participant), project_id),
+					Error::<TestRuntime>::NoEscrowShares
+				);
+			});
+		}
+	}
+}
@@ -169,6 +169,9 @@ mod helper_functions {
 			mainnet_token_max_supply: 8_000_000 * CT_UNIT,
 			total_allocation_size: 100_000 * CT_UNIT,
 			auction_round_allocation_percentage: Percent::from_percent(50u8),
+			candle_auction_closing_phase: None,
+			bucket_curve: BucketCurve::default(),
+			price_kind: PriceKind::Spot,
 			minimum_price: PriceProviderOf::<TestRuntime>::calculate_decimals_aware_price(
 				PriceOf::<TestRuntime>::from_float(10.0),
 				USD_DECIMALS,
@@ -188,6 +191,7 @@ mod helper_functions {
 			},
 			participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 			funding_destination_account: ISSUER_1,
+			milestones: Default::default(),
 			policy_ipfs_cid: Some(ipfs_hash()),
 		};
 
@@ -224,7 +228,7 @@ mod helper_functions {
 	#[test]
 	fn bucket_wap_calculation() {
 		let initial_price = FixedU128::from_float(10.0);
-		let mut bucket = Bucket::new(100u128, initial_price, FixedU128::from_float(1.0), 10u128);
+		let mut bucket = Bucket::new(100u128, initial_price, BucketCurve::default());
 		let wap = bucket.calculate_wap(100u128);
 		assert!(wap == initial_price);
 
@@ -240,6 +244,60 @@ mod helper_functions {
 		assert!(diff <= FixedU128::from_float(0.001));
 	}
 
+	#[test]
+	fn geometric_bucket_curve() {
+		let initial_price = FixedU128::from_float(10.0);
+		let curve =
+			BucketCurve::Geometric { price_growth: Percent::from_percent(20), amount_step: Percent::from_percent(10) };
+		let mut bucket = Bucket::new(100u128, initial_price, curve);
+
+		bucket.update(100u128);
+		assert_eq!(bucket.current_price, FixedU128::from_float(12.0));
+		assert_eq!(bucket.amount_left, 10u128);
+
+		// Each step grows on top of the previous price
+		bucket.update(10u128);
+		assert_eq!(bucket.delta_price, FixedU128::from_float(2.4));
+		assert_eq!(bucket.current_price, FixedU128::from_float(14.4));
+
+		// 5 CTs at 14.4, 10 at 12 and 85 at 10
+		bucket.update(5u128);
+		assert_eq!(bucket.calculate_wap(100u128), FixedU128::from_float(10.42));
+
+		// Exhausting the bucket and reverting it goes back to the previous step
+		bucket.update(5u128);
+		assert_eq!(bucket.current_price, FixedU128::from_float(17.28));
+		assert!(bucket.revert(5u128, FixedU128::from_float(14.4)));
+		assert_eq!(bucket.current_price, FixedU128::from_float(14.4));
+		assert_eq!(bucket.delta_price, FixedU128::from_float(2.4));
+		assert_eq!(bucket.amount_left, 5u128);
+	}
+
+	#[test]
+	fn custom_bucket_curve() {
+		let initial_price = FixedU128::from_float(10.0);
+		let curve = BucketCurve::custom(vec![
+			BucketStep { price_step: Percent::from_percent(10), amount_step: Percent::from_percent(10) },
+			BucketStep { price_step: Percent::from_percent(50), amount_step: Percent::from_percent(20) },
+		])
+		.unwrap();
+		let mut bucket = Bucket::new(100u128, initial_price, curve);
+
+		bucket.update(100u128);
+		assert_eq!((bucket.current_price, bucket.amount_left), (FixedU128::from_float(11.0), 10u128));
+		bucket.update(10u128);
+		assert_eq!((bucket.current_price, bucket.amount_left), (FixedU128::from_float(16.0), 20u128));
+		// The last step of the table is repeated
+		bucket.update(20u128);
+		assert_eq!((bucket.current_price, bucket.amount_left), (FixedU128::from_float(21.0), 20u128));
+
+		// 5 CTs at 21, 20 at 16, 10 at 11 and 65 at 10
+		bucket.update(5u128);
+		assert_eq!(bucket.calculate_wap(100u128), FixedU128::from_float(11.85));
+
+		assert!(BucketCurve::custom(vec![]).is_none());
+	}
+
 	#[test]
 	fn calculate_contributed_plmc_spent() {
 		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
@@ -409,3 +467,756 @@ fn project_state_transition_event() {
 		assert_eq!(event, Event::ProjectPhaseTransition { project_id, phase: desired_transitions.next().unwrap() });
 	});
 }
+
+// on_initialize moving projects to their next round once the current one ended
+mod automatic_transitions {
+	use super::*;
+	use frame_support::weights::Weight;
+
+	#[test]
+	fn project_goes_through_all_rounds_automatically() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id =
+			inst.create_auctioning_project(default_project_metadata(ISSUER_1), ISSUER_1, None, default_evaluations());
+
+		let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+		let now = inst.current_block();
+		inst.advance_time(auction_end - now);
+		assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionRound);
+		inst.advance_time(1u64);
+		assert!(matches!(inst.get_project_details(project_id).status, ProjectStatus::CommunityRound(..)));
+
+		let funding_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+		let now = inst.current_block();
+		inst.advance_time(funding_end - now);
+		assert!(matches!(inst.get_project_details(project_id).status, ProjectStatus::CommunityRound(..)));
+		inst.advance_time(1u64);
+		assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::FundingFailed);
+
+		inst.advance_time(1u64);
+		assert_eq!(
+			inst.get_project_details(project_id).status,
+			ProjectStatus::SettlementStarted(FundingOutcome::Failure)
+		);
+	}
+
+	#[test]
+	fn manual_transition_discards_scheduled_update() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id =
+			inst.create_auctioning_project(default_project_metadata(ISSUER_1), ISSUER_1, None, default_evaluations());
+
+		let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+		inst.jump_to_block(auction_end);
+		inst.execute(|| {
+			assert_eq!(
+				ProjectsToUpdate::<TestRuntime>::get(auction_end + 1).to_vec(),
+				vec![(project_id, UpdateType::AuctionEnd)]
+			);
+			assert_ok!(PolimecFunding::end_auction(RuntimeOrigin::signed(420), project_id));
+		});
+		let community_status = inst.get_project_details(project_id).status;
+		assert!(matches!(community_status, ProjectStatus::CommunityRound(..)));
+
+		inst.advance_time(1u64);
+		assert_eq!(inst.get_project_details(project_id).status, community_status);
+		inst.execute(|| assert!(ProjectsToUpdate::<TestRuntime>::get(auction_end + 1).is_empty()));
+	}
+
+	#[test]
+	fn updates_over_the_weight_budget_are_deferred() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		inst.execute(|| MaxTransitionWeightPerBlock::set(&Weight::zero()));
+
+		let project_1 = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+		let project_2 = inst.create_evaluating_project(default_project_metadata(ISSUER_2), ISSUER_2, None);
+		let evaluation_end = inst.get_project_details(project_1).round_duration.end().unwrap();
+		assert_eq!(inst.get_project_details(project_2).round_duration.end().unwrap(), evaluation_end);
+
+		// Only the first update is executed when the budget is exceeded
+		inst.jump_to_block(evaluation_end + 1);
+		assert_eq!(inst.get_project_details(project_1).status, ProjectStatus::FundingFailed);
+		assert_eq!(inst.get_project_details(project_2).status, ProjectStatus::EvaluationRound);
+		inst.execute(|| {
+			assert!(ProjectsToUpdate::<TestRuntime>::get(evaluation_end + 2)
+				.contains(&(project_2, UpdateType::EvaluationEnd)));
+		});
+
+		inst.execute(|| MaxTransitionWeightPerBlock::set(&Weight::MAX));
+		inst.advance_time(1u64);
+		assert_eq!(inst.get_project_details(project_2).status, ProjectStatus::FundingFailed);
+	}
+}
+
+#[cfg(test)]
+mod set_reward_parameters_extrinsic {
+	use super::*;
+	use sp_runtime::DispatchError;
+
+	fn new_reward_parameters() -> RewardParameters {
+		RewardParameters {
+			fee_brackets: vec![(Percent::from_percent(5), 1_000_000 * USD_UNIT), (Percent::from_percent(4), u128::MAX)]
+				.try_into()
+				.unwrap(),
+			evaluators_share: Perquintill::from_percent(40),
+			early_evaluators_share: Perquintill::from_percent(50),
+			normal_evaluators_share: Perquintill::from_percent(50),
+			liquidity_pools_share: Perquintill::from_percent(40),
+			long_term_holders_share: Perquintill::from_percent(20),
+		}
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn defaults_use_the_configured_fee_brackets() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.execute(|| {
+				let parameters = PolimecFunding::active_reward_parameters().unwrap();
+				assert!(parameters.is_valid());
+				assert_eq!(parameters.fee_brackets.into_inner(), <TestRuntime as Config>::FeeBrackets::get());
+				assert_eq!(ActiveRewardParameters::<TestRuntime>::get(), None);
+			});
+		}
+
+		#[test]
+		fn new_parameters_only_apply_to_later_projects() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let default_parameters = inst.execute(|| PolimecFunding::active_reward_parameters().unwrap());
+			let project_1 = inst.create_finished_project(
+				default_project_metadata(ISSUER_1),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+			);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::set_reward_parameters(RuntimeOrigin::root(), new_reward_parameters()));
+				System::assert_last_event(Event::RewardParametersSet { parameters: new_reward_parameters() }.into());
+			});
+
+			let project_2 = inst.create_finished_project(
+				default_project_metadata(ISSUER_2),
+				ISSUER_2,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+			);
+
+			inst.execute(|| {
+				assert_eq!(ProjectRewardParameters::<TestRuntime>::get(project_1), Some(default_parameters));
+				assert_eq!(ProjectRewardParameters::<TestRuntime>::get(project_2), Some(new_reward_parameters()));
+			});
+
+			let reward_pots = |inst: &mut MockInstantiator, project_id| {
+				let Some(EvaluatorsOutcome::Rewarded(reward_info)) =
+					inst.get_project_details(project_id).evaluation_round_info.evaluators_outcome
+				else {
+					panic!("Evaluators should be rewarded");
+				};
+				(reward_info.early_evaluator_reward_pot, reward_info.normal_evaluator_reward_pot)
+			};
+			let (early_pot_1, normal_pot_1) = reward_pots(&mut inst, project_1);
+			assert_close_enough!(early_pot_1 * 4, normal_pot_1, Perquintill::from_float(0.9999));
+			let (early_pot_2, normal_pot_2) = reward_pots(&mut inst, project_2);
+			assert_eq!(early_pot_2, normal_pot_2);
+
+			// Both projects raised the same amount, so the fee in CTs only differs by the brackets and splits
+			let evaluators_pot_1 = early_pot_1 + normal_pot_1;
+			let evaluators_pot_2 = early_pot_2 + normal_pot_2;
+			assert!(evaluators_pot_1 > evaluators_pot_2);
+
+			let (liquidity_pools_2, long_term_holders_2) = inst
+				.execute(|| PolimecFunding::generate_liquidity_pools_and_long_term_holder_rewards(project_2).unwrap());
+			assert_close_enough!(liquidity_pools_2, evaluators_pot_2, Perquintill::from_float(0.9999));
+			assert_close_enough!(long_term_holders_2 * 2, evaluators_pot_2, Perquintill::from_float(0.9999));
+		}
+
+		#[test]
+		fn projects_funded_before_the_snapshot_keep_the_default_parameters() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_finished_project(
+				default_project_metadata(ISSUER_1),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+			);
+
+			inst.execute(|| {
+				// Projects that reached `FundingSuccessful` before the upgrade have no snapshot
+				ProjectRewardParameters::<TestRuntime>::remove(project_id);
+				assert_ok!(PolimecFunding::set_reward_parameters(RuntimeOrigin::root(), new_reward_parameters()));
+
+				let default_parameters =
+					RewardParameters::with_fee_brackets(<TestRuntime as Config>::FeeBrackets::get()).unwrap();
+				assert_eq!(PolimecFunding::project_reward_parameters(project_id), Ok(default_parameters));
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn only_reward_parameters_origin_can_set() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::set_reward_parameters(RuntimeOrigin::signed(ISSUER_1), new_reward_parameters()),
+					DispatchError::BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn invalid_parameters_are_rejected() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+
+			let mut no_brackets = new_reward_parameters();
+			no_brackets.fee_brackets = Default::default();
+
+			let mut capped_brackets = new_reward_parameters();
+			capped_brackets.fee_brackets = vec![(Percent::from_percent(5), 1_000_000 * USD_UNIT)].try_into().unwrap();
+
+			let mut empty_bracket = new_reward_parameters();
+			empty_bracket.fee_brackets =
+				vec![(Percent::from_percent(5), 0), (Percent::from_percent(4), u128::MAX)].try_into().unwrap();
+
+			let mut evaluator_split_too_high = new_reward_parameters();
+			evaluator_split_too_high.early_evaluators_share = Perquintill::from_percent(60);
+
+			let mut fee_split_too_low = new_reward_parameters();
+			fee_split_too_low.liquidity_pools_share = Perquintill::from_percent(30);
+
+			for parameters in
+				vec![no_brackets, capped_brackets, empty_bracket, evaluator_split_too_high, fee_split_too_low]
+			{
+				inst.execute(|| {
+					assert_noop!(
+						PolimecFunding::set_reward_parameters(RuntimeOrigin::root(), parameters),
+						Error::<TestRuntime>::InvalidRewardParameters
+					);
+				});
+			}
+		}
+
+		#[test]
+		fn too_many_fee_brackets_are_not_truncated() {
+			let fee_brackets = (1..=MAX_FEE_BRACKETS + 1)
+				.map(|i| (Percent::from_percent(1), i as u128 * USD_UNIT))
+				.collect::<Vec<(Percent, Balance)>>();
+			assert_eq!(RewardParameters::with_fee_brackets(fee_brackets.clone()), Err(fee_brackets));
+		}
+	}
+}
+
+#[cfg(test)]
+mod freeze_project_extrinsic {
+	use super::*;
+	use sp_runtime::DispatchError;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn frozen_project_rejects_participations() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::freeze_project(RuntimeOrigin::root(), project_id));
+				System::assert_last_event(Event::ProjectFrozen { project_id }.into());
+			});
+			assert_err!(
+				inst.evaluate_for_users(project_id, default_evaluations()),
+				Error::<TestRuntime>::ProjectFrozenByAdmin
+			);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::unfreeze_project(RuntimeOrigin::root(), project_id));
+				System::assert_last_event(Event::ProjectUnfrozen { project_id }.into());
+			});
+			let plmc_for_evaluations = inst.calculate_evaluation_plmc_spent(default_evaluations(), true);
+			inst.mint_plmc_to(plmc_for_evaluations);
+			assert_ok!(inst.evaluate_for_users(project_id, default_evaluations()));
+		}
+
+		#[test]
+		fn frozen_project_does_not_transition_until_unfrozen() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_auctioning_project(
+				default_project_metadata(ISSUER_1),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+			);
+
+			inst.execute(|| assert_ok!(PolimecFunding::freeze_project(RuntimeOrigin::root(), project_id)));
+
+			let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+			inst.jump_to_block(auction_end + 10);
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionRound);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::end_auction(RuntimeOrigin::signed(ISSUER_1), project_id),
+					Error::<TestRuntime>::ProjectFrozenByAdmin
+				);
+				assert_ok!(PolimecFunding::unfreeze_project(RuntimeOrigin::root(), project_id));
+			});
+
+			inst.advance_time(1);
+			assert!(matches!(inst.get_project_details(project_id).status, ProjectStatus::CommunityRound(..)));
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn only_admin_origin_can_freeze() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::freeze_project(RuntimeOrigin::signed(ISSUER_1), project_id),
+					DispatchError::BadOrigin
+				);
+				assert_ok!(PolimecFunding::freeze_project(RuntimeOrigin::root(), project_id));
+				assert_noop!(
+					PolimecFunding::unfreeze_project(RuntimeOrigin::signed(ISSUER_1), project_id),
+					DispatchError::BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_freeze_twice_or_unfreeze_unfrozen_project() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::unfreeze_project(RuntimeOrigin::root(), project_id),
+					Error::<TestRuntime>::ProjectNotFrozenByAdmin
+				);
+				assert_ok!(PolimecFunding::freeze_project(RuntimeOrigin::root(), project_id));
+				assert_noop!(
+					PolimecFunding::freeze_project(RuntimeOrigin::root(), project_id),
+					Error::<TestRuntime>::ProjectFrozenByAdmin
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod force_fail_project_extrinsic {
+	use super::*;
+	use sp_runtime::DispatchError;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn force_failed_auction_refunds_everyone() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+
+			let bids = default_bids();
+			let plmc_for_bids = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+				&bids,
+				project_metadata.clone(),
+				None,
+				true,
+			);
+			let usdt_for_bids = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+				&bids,
+				project_metadata,
+				None,
+			);
+			inst.mint_plmc_to(plmc_for_bids.clone());
+			inst.mint_funding_asset_to(usdt_for_bids.clone());
+			assert_ok!(inst.bid_for_users(project_id, bids));
+
+			let evaluations = inst.get_evaluations(project_id);
+			let evaluators = evaluations.iter().map(|evaluation| evaluation.evaluator).collect::<Vec<_>>();
+			let evaluators_free_plmc_before: Balance =
+				inst.get_free_plmc_balances_for(evaluators.clone()).iter().map(|balance| balance.plmc_amount).sum();
+			let total_evaluation_bonds: Balance =
+				evaluations.iter().map(|evaluation| evaluation.current_plmc_bond).sum();
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::force_fail_project(RuntimeOrigin::root(), project_id));
+				System::assert_last_event(Event::ProjectForceFailed { project_id }.into());
+			});
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.status, ProjectStatus::FundingFailed);
+			assert_eq!(project_details.evaluation_round_info.evaluators_outcome, None);
+
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Failure));
+			inst.settle_project(project_id, true);
+			assert_eq!(
+				inst.get_project_details(project_id).status,
+				ProjectStatus::SettlementFinished(FundingOutcome::Failure)
+			);
+
+			inst.do_free_plmc_assertions(plmc_for_bids);
+			inst.do_free_funding_asset_assertions(usdt_for_bids);
+			let evaluators_free_plmc_after: Balance =
+				inst.get_free_plmc_balances_for(evaluators).iter().map(|balance| balance.plmc_amount).sum();
+			assert_eq!(evaluators_free_plmc_after, evaluators_free_plmc_before + total_evaluation_bonds);
+		}
+
+		#[test]
+		fn issuer_can_create_a_new_project_after_force_fail() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::force_fail_project(RuntimeOrigin::root(), project_id));
+				assert!(DidWithActiveProjects::<TestRuntime>::get(generate_did_from_account(ISSUER_1)).is_none());
+			});
+
+			let new_project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+			inst.execute(|| {
+				assert_eq!(
+					DidWithActiveProjects::<TestRuntime>::get(generate_did_from_account(ISSUER_1)),
+					Some(new_project_id)
+				);
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn only_admin_origin_can_force_fail() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::force_fail_project(RuntimeOrigin::signed(ISSUER_1), project_id),
+					DispatchError::BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_force_fail_after_settlement_started() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_settled_project(
+				default_project_metadata(ISSUER_1),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+				true,
+			);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::force_fail_project(RuntimeOrigin::root(), project_id),
+					Error::<TestRuntime>::SettlementAlreadyStarted
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_force_fail_a_failed_project() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::force_fail_project(RuntimeOrigin::root(), project_id));
+				assert_noop!(
+					PolimecFunding::force_fail_project(RuntimeOrigin::root(), project_id),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod pause_participations_extrinsic {
+	use super::*;
+	use sp_runtime::DispatchError;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn paused_pallet_rejects_participations_until_unpaused() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::pause_participations(RuntimeOrigin::root()));
+				System::assert_last_event(Event::ParticipationsPaused.into());
+			});
+			assert_err!(
+				inst.evaluate_for_users(project_id, default_evaluations()),
+				Error::<TestRuntime>::ParticipationsArePaused
+			);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::unpause_participations(RuntimeOrigin::root()));
+				System::assert_last_event(Event::ParticipationsUnpaused.into());
+			});
+			let plmc_for_evaluations = inst.calculate_evaluation_plmc_spent(default_evaluations(), true);
+			inst.mint_plmc_to(plmc_for_evaluations);
+			assert_ok!(inst.evaluate_for_users(project_id, default_evaluations()));
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn only_admin_origin_can_pause() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::pause_participations(RuntimeOrigin::signed(ISSUER_1)),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					PolimecFunding::unpause_participations(RuntimeOrigin::signed(ISSUER_1)),
+					DispatchError::BadOrigin
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod funding_asset_registry_extrinsics {
+	use super::*;
+	use parity_scale_codec::Encode;
+	use sp_runtime::DispatchError;
+	use xcm::v4::{Junction::*, Location};
+
+	const EURC: AcceptedFundingAsset = AcceptedFundingAsset::Other(4242);
+
+	fn eurc_info() -> FundingAssetInfo {
+		FundingAssetInfo {
+			location: Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(4242)]),
+			decimals: 6,
+			oracle_key: *b"EURC\0\0\0\0",
+			enabled: true,
+		}
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn registered_funding_asset_can_be_accepted_by_projects() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::register_funding_asset(RuntimeOrigin::root(), EURC, eurc_info()));
+				System::assert_last_event(Event::FundingAssetRegistered { asset: EURC, info: eurc_info() }.into());
+				assert_eq!(FundingAssets::<TestRuntime>::get(EURC), Some(eurc_info()));
+				assert_eq!(FundingAssetLocations::<TestRuntime>::get(eurc_info().location), Some(EURC));
+				assert_eq!(PolimecFunding::funding_asset_by_oracle_key(*b"EURC\0\0\0\0"), Some(EURC));
+			});
+
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.participation_currencies = vec![AcceptedFundingAsset::USDT, EURC].try_into().unwrap();
+			inst.mint_plmc_to(default_plmc_balances());
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::do_create_project(
+					&ISSUER_1,
+					project_metadata,
+					generate_did_from_account(ISSUER_1)
+				));
+			});
+		}
+
+		#[test]
+		fn legacy_funding_assets_keep_their_encoding() {
+			assert_eq!(AcceptedFundingAsset::USDT.encode(), vec![0u8]);
+			assert_eq!(AcceptedFundingAsset::USDC.encode(), vec![1u8]);
+			assert_eq!(AcceptedFundingAsset::DOT.encode(), vec![2u8]);
+			assert_eq!(AcceptedFundingAsset::from_id(1984), AcceptedFundingAsset::USDT);
+			assert_eq!(AcceptedFundingAsset::from_id(4242), AcceptedFundingAsset::Other(4242));
+		}
+
+		#[test]
+		fn disabled_funding_asset_cannot_be_used_for_new_projects_or_bids() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::set_funding_asset_enabled(
+					RuntimeOrigin::root(),
+					AcceptedFundingAsset::USDT,
+					false
+				));
+				System::assert_last_event(
+					Event::FundingAssetEnabledSet { asset: AcceptedFundingAsset::USDT, enabled: false }.into(),
+				);
+			});
+
+			let bid = BidParams::<TestRuntime>::new(BIDDER_1, 10_000, 1u8, AcceptedFundingAsset::USDT);
+			let outcome = inst.execute(|| {
+				PolimecFunding::do_bid(DoBidParams::<TestRuntime> {
+					bidder: bid.bidder,
+					project_id,
+					ct_amount: bid.amount,
+					multiplier: bid.multiplier,
+					funding_asset: bid.asset,
+					did: generate_did_from_account(bid.bidder),
+					investor_type: InvestorType::Institutional,
+					whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
+				})
+			});
+			assert_err!(outcome, Error::<TestRuntime>::FundingAssetDisabled);
+
+			inst.mint_plmc_to(default_plmc_balances());
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_create_project(
+						&ISSUER_2,
+						default_project_metadata(ISSUER_2),
+						generate_did_from_account(ISSUER_2)
+					),
+					Error::<TestRuntime>::FundingAssetDisabled
+				);
+				assert_ok!(PolimecFunding::set_funding_asset_enabled(
+					RuntimeOrigin::root(),
+					AcceptedFundingAsset::USDT,
+					true
+				));
+				assert_ok!(PolimecFunding::do_create_project(
+					&ISSUER_2,
+					default_project_metadata(ISSUER_2),
+					generate_did_from_account(ISSUER_2)
+				));
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn only_funding_asset_origin_can_manage_the_registry() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::register_funding_asset(RuntimeOrigin::signed(ISSUER_1), EURC, eurc_info()),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					PolimecFunding::set_funding_asset_enabled(
+						RuntimeOrigin::signed(ISSUER_1),
+						AcceptedFundingAsset::USDT,
+						false
+					),
+					DispatchError::BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_register_a_taken_id_or_location() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::register_funding_asset(
+						RuntimeOrigin::root(),
+						AcceptedFundingAsset::USDT,
+						eurc_info()
+					),
+					Error::<TestRuntime>::FundingAssetAlreadyRegistered
+				);
+				let usdt_location = FundingAssets::<TestRuntime>::get(AcceptedFundingAsset::USDT).unwrap().location;
+				assert_noop!(
+					PolimecFunding::register_funding_asset(
+						RuntimeOrigin::root(),
+						EURC,
+						FundingAssetInfo { location: usdt_location, ..eurc_info() }
+					),
+					Error::<TestRuntime>::FundingAssetAlreadyRegistered
+				);
+				assert_noop!(
+					PolimecFunding::register_funding_asset(
+						RuntimeOrigin::root(),
+						AcceptedFundingAsset::Other(AcceptedFundingAsset::USDT.id()),
+						eurc_info()
+					),
+					Error::<TestRuntime>::FundingAssetIdNotCanonical
+				);
+				assert_noop!(
+					PolimecFunding::register_funding_asset(
+						RuntimeOrigin::root(),
+						EURC,
+						FundingAssetInfo { oracle_key: *b"USDT\0\0\0\0", ..eurc_info() }
+					),
+					Error::<TestRuntime>::FundingAssetAlreadyRegistered
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_register_more_than_max_funding_assets() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.execute(|| {
+				// The mock registers 3 funding assets at genesis, and allows 4.
+				assert_ok!(PolimecFunding::register_funding_asset(RuntimeOrigin::root(), EURC, eurc_info()));
+				let other = AcceptedFundingAsset::Other(4243);
+				let other_info = FundingAssetInfo {
+					location: Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(4243)]),
+					oracle_key: *b"OTHER\0\0\0",
+					..eurc_info()
+				};
+				assert_noop!(
+					PolimecFunding::register_funding_asset(RuntimeOrigin::root(), other, other_info),
+					Error::<TestRuntime>::TooManyFundingAssets
+				);
+			});
+		}
+
+		#[test]
+		fn unregistered_funding_asset_is_rejected() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.participation_currencies = vec![EURC].try_into().unwrap();
+			inst.mint_plmc_to(default_plmc_balances());
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::set_funding_asset_enabled(RuntimeOrigin::root(), EURC, true),
+					Error::<TestRuntime>::FundingAssetNotRegistered
+				);
+				assert_noop!(
+					PolimecFunding::do_create_project(&ISSUER_1, project_metadata, generate_did_from_account(ISSUER_1)),
+					Error::<TestRuntime>::FundingAssetNotRegistered
+				);
+			});
+		}
+	}
+}
@@ -34,6 +34,8 @@ mod ct_migration;
 mod evaluation;
 #[path = "5_funding_end.rs"]
 mod funding_end;
+#[path = "8_milestones.rs"]
+mod milestones;
 mod misc;
 mod runtime_api;
 #[path = "6_settlement.rs"]
@@ -94,6 +96,9 @@ pub mod defaults {
 			mainnet_token_max_supply: 8_000_000 * CT_UNIT,
 			total_allocation_size: 1_000_000 * CT_UNIT,
 			auction_round_allocation_percentage: Percent::from_percent(50u8),
+			candle_auction_closing_phase: None,
+			bucket_curve: BucketCurve::default(),
+			price_kind: PriceKind::Spot,
 			minimum_price: decimal_aware_price,
 			bidding_ticket_sizes: BiddingTicketSizes {
 				professional: TicketSize::new(5000 * USD_UNIT, None),
@@ -108,6 +113,7 @@ pub mod defaults {
 			},
 			participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 			funding_destination_account: issuer,
+			milestones: Default::default(),
 			policy_ipfs_cid: Some(metadata_hash),
 		}
 	}
@@ -128,6 +134,9 @@ pub mod defaults {
 			mainnet_token_max_supply: 8_000_000 * CT_UNIT,
 			total_allocation_size: 100_000 * CT_UNIT,
 			auction_round_allocation_percentage: Percent::from_percent(50u8),
+			candle_auction_closing_phase: None,
+			bucket_curve: BucketCurve::default(),
+			price_kind: PriceKind::Spot,
 			minimum_price: decimal_aware_price,
 			bidding_ticket_sizes: BiddingTicketSizes {
 				professional: TicketSize::new(5000 * USD_UNIT, None),
@@ -142,6 +151,7 @@ pub mod defaults {
 			},
 			participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 			funding_destination_account: ISSUER_1,
+			milestones: Default::default(),
 			policy_ipfs_cid: Some(metadata_hash),
 		};
 		project_metadata
@@ -456,7 +466,12 @@ pub fn create_finished_project_with_usd_raised(
 	inst.mint_funding_asset_to(usdt_required);
 	inst.contribute_for_users(project_id, community_contributions).unwrap();
 
-	assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+	let mut status = inst.go_to_next_state(project_id);
+	// Projects funded between the success thresholds are accepted by default
+	if status == ProjectStatus::AwaitingDecision {
+		status = inst.go_to_next_state(project_id);
+	}
+	assert_eq!(status, ProjectStatus::FundingSuccessful);
 
 	let project_details = inst.get_project_details(project_id);
 
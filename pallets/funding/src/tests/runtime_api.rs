@@ -400,6 +400,7 @@ fn funding_asset_to_ct_amount() {
 	// Price should be at 16 USD/CT
 	bucket.current_price = bucket.initial_price + bucket.delta_price * FixedU128::from_float(6.0f64);
 	bucket.amount_left = bucket.delta_amount;
+	bucket.index = 6;
 	let bids = inst.generate_bids_from_bucket(
 		project_metadata_3.clone(),
 		bucket,
@@ -771,3 +772,254 @@ fn projects_by_did() {
 		assert_eq!(project_ids, vec![project_id_1, project_id_3]);
 	});
 }
+
+#[test]
+fn project_timeline() {
+	use crate::runtime_api::{ActionCaller, NextAction, ProjectAction};
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let project_metadata = default_project_metadata(ISSUER_1);
+
+	let project_id = inst.create_new_project(project_metadata.clone(), ISSUER_1, None);
+	inst.execute(|| {
+		let block_hash = System::block_hash(System::block_number());
+		let timeline = TestRuntime::project_timeline(&TestRuntime, block_hash, project_id).unwrap().unwrap();
+		assert_eq!(timeline.evaluation, BlockNumberPair::new(None, None));
+		assert_eq!(timeline.remainder, BlockNumberPair::new(None, None));
+		assert_eq!(
+			timeline.next_action,
+			Some(NextAction {
+				action: ProjectAction::StartEvaluation,
+				caller: ActionCaller::Issuer,
+				available_from: None,
+				automatic: false
+			})
+		);
+	});
+
+	// Future phases are projected from the end of the evaluation round
+	let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_2, None);
+	let evaluation_round = inst.get_project_details(project_id).round_duration;
+	let evaluation_end = evaluation_round.end().unwrap();
+	inst.execute(|| {
+		let block_hash = System::block_hash(System::block_number());
+		let timeline = TestRuntime::project_timeline(&TestRuntime, block_hash, project_id).unwrap().unwrap();
+		let auction_end = evaluation_end + <TestRuntime as Config>::AuctionRoundDuration::get();
+		let community_end = auction_end + <TestRuntime as Config>::CommunityRoundDuration::get();
+		let remainder_end = community_end + <TestRuntime as Config>::RemainderRoundDuration::get();
+		assert_eq!(timeline.evaluation, evaluation_round);
+		assert_eq!(timeline.auction, BlockNumberPair::new(Some(evaluation_end + 1), Some(auction_end)));
+		assert_eq!(timeline.community, BlockNumberPair::new(Some(auction_end + 1), Some(community_end)));
+		assert_eq!(timeline.remainder, BlockNumberPair::new(Some(community_end + 1), Some(remainder_end)));
+		assert_eq!(
+			timeline.next_action,
+			Some(NextAction {
+				action: ProjectAction::EndEvaluation,
+				caller: ActionCaller::Anyone,
+				available_from: Some(evaluation_end + 1),
+				automatic: true
+			})
+		);
+	});
+
+	// The evaluation is over, so it is not reported
+	let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_4, None, default_evaluations());
+	let auction_round = inst.get_project_details(project_id).round_duration;
+	inst.execute(|| {
+		let block_hash = System::block_hash(System::block_number());
+		let timeline = TestRuntime::project_timeline(&TestRuntime, block_hash, project_id).unwrap().unwrap();
+		assert_eq!(timeline.evaluation, BlockNumberPair::new(None, None));
+		assert_eq!(timeline.auction, auction_round);
+	});
+
+	// Neither are the evaluation and auction once the community round started
+	let project_id = inst.create_community_contributing_project(
+		project_metadata.clone(),
+		ISSUER_3,
+		None,
+		default_evaluations(),
+		default_bids(),
+	);
+	let project_details = inst.get_project_details(project_id);
+	let ProjectStatus::CommunityRound(remainder_start) = project_details.status else { panic!("Wrong status") };
+	let community_start = project_details.round_duration.start().unwrap();
+	let remainder_end = project_details.round_duration.end().unwrap();
+	inst.execute(|| {
+		let block_hash = System::block_hash(System::block_number());
+		let timeline = TestRuntime::project_timeline(&TestRuntime, block_hash, project_id).unwrap().unwrap();
+		assert_eq!(timeline.evaluation, BlockNumberPair::new(None, None));
+		assert_eq!(timeline.auction, BlockNumberPair::new(None, None));
+		assert_eq!(timeline.community, BlockNumberPair::new(Some(community_start), Some(remainder_start - 1)));
+		assert_eq!(timeline.remainder, BlockNumberPair::new(Some(remainder_start), Some(remainder_end)));
+		assert_eq!(timeline.funding_end, None);
+		assert_eq!(
+			timeline.next_action,
+			Some(NextAction {
+				action: ProjectAction::EndFunding,
+				caller: ActionCaller::Anyone,
+				available_from: Some(remainder_end + 1),
+				automatic: true
+			})
+		);
+
+		// Unknown projects have no timeline
+		assert_eq!(TestRuntime::project_timeline(&TestRuntime, block_hash, project_id + 1).unwrap(), None);
+	});
+}
+
+#[test]
+fn participation_pages() {
+	use crate::runtime_api::ParticipationFilter;
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let project_id = inst.create_finished_project(
+		default_project_metadata(ISSUER_1),
+		ISSUER_1,
+		None,
+		default_evaluations(),
+		default_bids(),
+		default_community_contributions(),
+		default_remainder_contributions(),
+	);
+
+	inst.execute(|| {
+		let block_hash = System::block_hash(System::block_number());
+
+		// Paging through the whole map returns every contribution exactly once
+		let mut cursor = None;
+		let mut paged_contributions = vec![];
+		loop {
+			let page = TestRuntime::contributions_page(
+				&TestRuntime,
+				block_hash,
+				project_id,
+				ParticipationFilter::default(),
+				cursor,
+				3,
+			)
+			.unwrap();
+			assert!(page.items.len() <= 3);
+			paged_contributions.extend(page.items);
+			cursor = page.next_cursor;
+			if cursor.is_none() {
+				break;
+			}
+		}
+		let stored_contributions = Contributions::<TestRuntime>::iter_prefix_values((project_id,)).collect_vec();
+		assert_eq!(paged_contributions.len(), stored_contributions.len());
+		assert_eq!(
+			paged_contributions.iter().map(|c| c.id).sorted().collect_vec(),
+			stored_contributions.iter().map(|c| c.id).sorted().collect_vec()
+		);
+
+		// Filter by account
+		let filter = ParticipationFilter { account: Some(BUYER_2), ..Default::default() };
+		let page = TestRuntime::contributions_page(&TestRuntime, block_hash, project_id, filter, None, 100).unwrap();
+		assert_eq!(page.items.len(), 2);
+		assert!(page.items.iter().all(|c| c.contributor == BUYER_2));
+
+		// Filter by block range
+		let last_contribution_block = stored_contributions.iter().map(|c| c.when).max().unwrap();
+		let filter = ParticipationFilter { from_block: Some(last_contribution_block + 1), ..Default::default() };
+		let page = TestRuntime::contributions_page(&TestRuntime, block_hash, project_id, filter, None, 100).unwrap();
+		assert!(page.items.is_empty());
+		assert_eq!(page.next_cursor, None);
+
+		// Filter bids by status and funding asset
+		let accepted_bids = Bids::<TestRuntime>::iter_prefix_values((project_id,))
+			.filter(|bid| bid.status == BidStatus::Accepted)
+			.count();
+		let filter = ParticipationFilter { bid_status: Some(BidStatus::Accepted), ..Default::default() };
+		let page = TestRuntime::bids_page(&TestRuntime, block_hash, project_id, filter, None, 100).unwrap();
+		assert_eq!(page.items.len(), accepted_bids);
+		let filter = ParticipationFilter { funding_asset: Some(AcceptedFundingAsset::USDC), ..Default::default() };
+		let page = TestRuntime::bids_page(&TestRuntime, block_hash, project_id, filter, None, 100).unwrap();
+		assert!(page.items.is_empty());
+
+		// Filter evaluations by DID
+		let evaluation = Evaluations::<TestRuntime>::iter_prefix_values((project_id,)).next().unwrap();
+		let filter = ParticipationFilter { did: Some(evaluation.did.clone()), ..Default::default() };
+		let page = TestRuntime::evaluations_page(&TestRuntime, block_hash, project_id, filter, None, 100).unwrap();
+		assert!(page.items.contains(&evaluation));
+		assert!(page.items.iter().all(|e| e.did == evaluation.did));
+	});
+}
+
+#[test]
+fn simulate_participations() {
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let project_metadata = default_project_metadata(ISSUER_1);
+	let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+
+	let bids = vec![BidParams::new(BIDDER_1, 400_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT)];
+	let necessary_plmc = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+		&bids,
+		project_metadata.clone(),
+		None,
+		true,
+	);
+	let necessary_usdt = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+		&bids,
+		project_metadata.clone(),
+		None,
+	);
+	inst.mint_plmc_to(necessary_plmc.clone());
+	inst.mint_funding_asset_to(necessary_usdt.clone());
+
+	let expected_plmc = necessary_plmc[0].plmc_amount - inst.get_ed();
+	let expected_purchases = inst
+		.get_actual_price_charged_for_bucketed_bids(&bids, project_metadata.clone(), None)
+		.into_iter()
+		.map(|(bid, price)| (bid.amount, price))
+		.collect_vec();
+	let plmc_before = inst.get_free_plmc_balance_for(BIDDER_1);
+
+	inst.execute(|| {
+		let block_hash = System::block_hash(System::block_number());
+		let simulation = TestRuntime::simulate_bid(
+			&TestRuntime,
+			block_hash,
+			BIDDER_1,
+			generate_did_from_account(BIDDER_1),
+			InvestorType::Professional,
+			project_id,
+			400_000 * CT_UNIT,
+			1u8.try_into().unwrap(),
+			AcceptedFundingAsset::USDT,
+		)
+		.unwrap()
+		.unwrap();
+		assert_eq!(simulation.plmc_held, expected_plmc);
+		assert_eq!(simulation.funding_asset_amount, necessary_usdt[0].asset_amount);
+		assert_eq!(simulation.ct_purchases, expected_purchases);
+
+		// Not enough funds for a bigger bid
+		let simulation = TestRuntime::simulate_bid(
+			&TestRuntime,
+			block_hash,
+			BIDDER_1,
+			generate_did_from_account(BIDDER_1),
+			InvestorType::Professional,
+			project_id,
+			450_000 * CT_UNIT,
+			1u8.try_into().unwrap(),
+			AcceptedFundingAsset::USDT,
+		)
+		.unwrap();
+		assert_eq!(simulation, Err(Error::<TestRuntime>::ParticipantNotEnoughFunds.into()));
+
+		// Evaluations are no longer possible
+		let simulation = TestRuntime::simulate_evaluate(
+			&TestRuntime,
+			block_hash,
+			EVALUATOR_4,
+			generate_did_from_account(EVALUATOR_4),
+			project_id,
+			500 * USD_UNIT,
+		)
+		.unwrap();
+		assert_eq!(simulation, Err(Error::<TestRuntime>::IncorrectRound.into()));
+
+		// Nothing was stored
+		assert_eq!(Bids::<TestRuntime>::iter_prefix_values((project_id,)).count(), 0);
+	});
+	assert_eq!(inst.get_free_plmc_balance_for(BIDDER_1), plmc_before);
+}
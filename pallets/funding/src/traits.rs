@@ -14,11 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Balance, Config, ProjectId};
+use crate::{Balance, Config, Error, PriceKind, ProjectId};
 use frame_support::weights::Weight;
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_arithmetic::{
-	traits::{CheckedDiv, CheckedMul},
+	traits::{CheckedDiv, CheckedMul, Saturating},
 	FixedPointNumber,
 };
 use sp_runtime::DispatchError;
@@ -31,10 +31,62 @@ pub trait VestingDurationCalculation {
 	fn calculate_vesting_duration<T: Config>(&self) -> BlockNumberFor<T>;
 }
 
+/// Why a price could not be used to value a new participation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceError {
+	/// There is no price for the asset.
+	NotFound,
+	/// The price was last updated longer ago than the maximum age allowed for the asset.
+	Stale,
+}
+
+impl<T: Config> From<PriceError> for Error<T> {
+	fn from(error: PriceError) -> Self {
+		match error {
+			PriceError::NotFound => Error::<T>::PriceNotFound,
+			PriceError::Stale => Error::<T>::PriceStale,
+		}
+	}
+}
+
 pub trait ProvideAssetPrice {
 	type AssetId;
 	type Price: FixedPointNumber;
-	fn get_price(asset_id: Self::AssetId) -> Option<Self::Price>;
+	/// Unit of the price timestamps and of the maximum price age.
+	type Moment: Copy + PartialOrd + Saturating;
+
+	/// The latest price of the asset, together with the moment it was last updated.
+	fn get_timestamped_price(asset_id: Self::AssetId) -> Option<(Self::Price, Self::Moment)>;
+
+	/// The current moment, used to determine the age of a price.
+	fn now() -> Self::Moment;
+
+	/// How old a price of the asset can be before it is considered stale.
+	fn max_price_age(asset_id: &Self::AssetId) -> Self::Moment;
+
+	/// The time-weighted average of the recent prices of the asset.
+	fn get_twap(asset_id: Self::AssetId) -> Option<Self::Price>;
+
+	fn get_price(asset_id: Self::AssetId) -> Option<Self::Price> {
+		Self::get_timestamped_price(asset_id).map(|(price, _)| price)
+	}
+
+	fn get_price_of_kind(asset_id: Self::AssetId, kind: PriceKind) -> Option<Self::Price> {
+		match kind {
+			PriceKind::Spot => Self::get_price(asset_id),
+			PriceKind::Twap => Self::get_twap(asset_id),
+		}
+	}
+
+	/// Same as [`Self::get_price`], but fails if the price is older than [`Self::max_price_age`].
+	fn get_fresh_price(asset_id: Self::AssetId) -> Result<Self::Price, PriceError> {
+		let max_age = Self::max_price_age(&asset_id);
+		let (price, updated_at) = Self::get_timestamped_price(asset_id).ok_or(PriceError::NotFound)?;
+		if Self::now().saturating_sub(updated_at) > max_age {
+			return Err(PriceError::Stale);
+		}
+		Ok(price)
+	}
 
 	/// Prices define the relationship between USD/Asset. When to and from that asset, we need to be aware that they might
 	/// have different decimals. This function calculates the relationship having in mind the decimals. For example:
@@ -73,6 +125,25 @@ pub trait ProvideAssetPrice {
 		let original_price = Self::get_price(asset_id)?;
 		Self::calculate_decimals_aware_price(original_price, usd_decimals, asset_decimals)
 	}
+
+	fn get_decimals_aware_price_of_kind(
+		asset_id: Self::AssetId,
+		kind: PriceKind,
+		usd_decimals: u8,
+		asset_decimals: u8,
+	) -> Option<Self::Price> {
+		let original_price = Self::get_price_of_kind(asset_id, kind)?;
+		Self::calculate_decimals_aware_price(original_price, usd_decimals, asset_decimals)
+	}
+
+	fn get_fresh_decimals_aware_price(
+		asset_id: Self::AssetId,
+		usd_decimals: u8,
+		asset_decimals: u8,
+	) -> Result<Self::Price, PriceError> {
+		let original_price = Self::get_fresh_price(asset_id)?;
+		Self::calculate_decimals_aware_price(original_price, usd_decimals, asset_decimals).ok_or(PriceError::NotFound)
+	}
 }
 
 pub trait DoRemainingOperation<T: Config> {
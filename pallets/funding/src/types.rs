@@ -28,7 +28,7 @@ use parachains_common::DAYS;
 use polimec_common::USD_DECIMALS;
 use polkadot_parachain_primitives::primitives::Id as ParaId;
 use serde::{Deserialize, Serialize};
-use sp_arithmetic::{traits::Saturating, FixedPointNumber, FixedU128, Percent};
+use sp_arithmetic::{traits::Saturating, FixedPointNumber, FixedU128, PerThing, Percent, Perquintill};
 use sp_runtime::traits::{Convert, One};
 use sp_std::{cmp::Eq, prelude::*};
 pub use storage::*;
@@ -166,6 +166,13 @@ pub mod storage {
 		pub total_allocation_size: Balance,
 		/// Percentage of the total allocation of Contribution Tokens available for the Auction Round
 		pub auction_round_allocation_percentage: Percent,
+		/// If set, the auction runs as a candle auction. Its effective end is picked at random after the round ends,
+		/// from this last percentage of the auction round. Bids made after the effective end are rejected.
+		pub candle_auction_closing_phase: Option<Percent>,
+		/// How the price and size of the auction buckets evolve as tokens are bid for.
+		pub bucket_curve: BucketCurve,
+		/// Whether PLMC bonds and funding asset amounts are computed from the spot or the time-weighted average price.
+		pub price_kind: PriceKind,
 		/// The minimum price per token in USD, decimal-aware. See [`calculate_decimals_aware_price()`](crate::traits::ProvideAssetPrice::calculate_decimals_aware_price) for more information.
 		pub minimum_price: Price,
 		/// Maximum and minimum ticket sizes for auction round
@@ -175,9 +182,11 @@ pub mod storage {
 		/// Participation currencies (e.g stablecoin, DOT, KSM)
 		/// e.g. https://github.com/paritytech/substrate/blob/427fd09bcb193c1e79dec85b1e207c718b686c35/frame/uniques/src/types.rs#L110
 		/// For now is easier to handle the case where only just one Currency is accepted
-		pub participation_currencies:
-			BoundedVec<AcceptedFundingAsset, ConstU32<{ AcceptedFundingAsset::VARIANT_COUNT as u32 }>>,
+		pub participation_currencies: BoundedVec<AcceptedFundingAsset, ConstU32<MAX_PARTICIPATION_CURRENCIES>>,
 		pub funding_destination_account: AccountId,
+		/// If not empty, the raised funds are held in the project pot and paid out to the
+		/// `funding_destination_account` as each milestone gets approved.
+		pub milestones: BoundedVec<Milestone<Cid>, ConstU32<MAX_MILESTONES>>,
 		/// Additional metadata
 		pub policy_ipfs_cid: Option<Cid>,
 	}
@@ -218,6 +227,27 @@ pub mod storage {
 				return Err(MetadataError::AuctionRoundPercentageError);
 			}
 
+			if self.candle_auction_closing_phase.is_some_and(|phase| phase.is_zero()) {
+				return Err(MetadataError::CandleAuctionPhaseError);
+			}
+
+			if !self.bucket_curve.is_valid() {
+				return Err(MetadataError::BucketCurveError);
+			}
+
+			if !self.milestones.is_empty() {
+				let mut total_share = 0u32;
+				for milestone in self.milestones.iter() {
+					if milestone.share.is_zero() {
+						return Err(MetadataError::MilestonesError);
+					}
+					total_share = total_share.saturating_add(milestone.share.deconstruct().into());
+				}
+				if total_share != 100 {
+					return Err(MetadataError::MilestonesError);
+				}
+			}
+
 			let mut deduped = self.participation_currencies.clone().to_vec();
 			deduped.sort();
 			deduped.dedup();
@@ -320,14 +350,40 @@ pub mod storage {
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub enum UpdateType {
 		EvaluationEnd,
-		AuctionOpeningStart,
-		AuctionClosingStart,
-		AuctionClosingEnd,
-		CommunityFundingStart,
-		RemainderFundingStart,
+		AuctionEnd,
 		FundingEnd,
-		ProjectDecision(FundingOutcomeDecision),
 		StartSettlement,
+		ProjectDecision,
+	}
+
+	impl UpdateType {
+		/// The automatic update that moves a project out of `status`, if there is one.
+		pub fn for_status<BlockNumber>(status: &ProjectStatus<BlockNumber>) -> Option<Self> {
+			match status {
+				ProjectStatus::EvaluationRound => Some(UpdateType::EvaluationEnd),
+				ProjectStatus::AuctionRound => Some(UpdateType::AuctionEnd),
+				ProjectStatus::CommunityRound(..) => Some(UpdateType::FundingEnd),
+				ProjectStatus::AwaitingDecision => Some(UpdateType::ProjectDecision),
+				ProjectStatus::FundingSuccessful | ProjectStatus::FundingFailed => Some(UpdateType::StartSettlement),
+				_ => None,
+			}
+		}
+	}
+
+	/// Progress of the batch settlement of a project. Each variant holds the key of the last participation
+	/// visited in that storage map, so the next batch continues right after it.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum SettlementCursor<AccountId> {
+		Evaluations(Option<(AccountId, u32)>),
+		Bids(Option<(AccountId, u32)>),
+		Contributions(Option<(AccountId, u32)>),
+		Finished,
+	}
+
+	impl<AccountId> Default for SettlementCursor<AccountId> {
+		fn default() -> Self {
+			SettlementCursor::Evaluations(None)
+		}
 	}
 
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Ord, PartialOrd)]
@@ -417,21 +473,33 @@ pub mod storage {
 		pub current_price: Price,
 		/// The initial price of tokens in the bucket.
 		pub initial_price: Price,
-		/// Defines the price increment for each subsequent bucket.
+		/// The price increment of the current bucket over the previous one.
+		/// While still in the first bucket, the increment of the second one.
 		pub delta_price: Price,
-		/// Defines the amount increment for each subsequent bucket.
+		/// The size of the current bucket. While still in the first bucket, the size of the second one.
 		pub delta_amount: Balance,
+		/// The position of the current bucket in the sequence, the first one being 0.
+		pub index: u32,
+		/// The total amount of tokens offered in the auction. The size of the first bucket.
+		pub auction_allocation: Balance,
+		/// How the price and size of the buckets evolve.
+		pub curve: BucketCurve,
 	}
 
 	impl<Price: FixedPointNumber> Bucket<Price> {
-		/// Creates a new bucket with the given parameters.
-		pub const fn new(
-			amount_left: Balance,
-			initial_price: Price,
-			delta_price: Price,
-			delta_amount: Balance,
-		) -> Self {
-			Self { amount_left, current_price: initial_price, initial_price, delta_price, delta_amount }
+		/// Creates a new bucket holding the whole auction allocation at the initial price.
+		pub fn new(auction_allocation: Balance, initial_price: Price, curve: BucketCurve) -> Self {
+			let (delta_price, delta_amount) = curve.step(1, initial_price, initial_price, auction_allocation);
+			Self {
+				amount_left: auction_allocation,
+				current_price: initial_price,
+				initial_price,
+				delta_price,
+				delta_amount,
+				index: 0,
+				auction_allocation,
+				curve,
+			}
 		}
 
 		/// Update the bucket
@@ -442,27 +510,95 @@ pub mod storage {
 			}
 		}
 
+		/// Give back `returned_amount` tokens that were bought at `price` by the most recent bid.
+		/// If that bid exhausted the previous bucket, the bucket is moved back to it.
+		/// Returns false if the bucket could not be restored, i.e. the bid was not the latest one.
+		pub fn revert(&mut self, returned_amount: Balance, price: Price) -> bool {
+			if self.current_price == price {
+				self.amount_left.saturating_accrue(returned_amount);
+				true
+			} else if self.index > 0 &&
+				self.current_price.saturating_sub(self.delta_price) == price &&
+				self.amount_left == self.delta_amount
+			{
+				self.previous();
+				self.amount_left = returned_amount;
+				true
+			} else {
+				false
+			}
+		}
+
 		/// Updates the bucket to represent the next one in the sequence. This involves:
+		/// - calculating the price increment and size of the next bucket from the `curve`,
 		/// - resetting the amount left,
-		/// - recalculating the price based on the current price and the price increments defined by the `delta_price`.
+		/// - recalculating the price based on the current price and the price increment.
 		fn next(&mut self) {
+			self.index.saturating_inc();
+			let (delta_price, delta_amount) =
+				self.curve.step(self.index, self.current_price, self.initial_price, self.auction_allocation);
+			self.delta_price = delta_price;
+			self.delta_amount = delta_amount;
 			self.amount_left = self.delta_amount;
 			self.current_price = self.current_price.saturating_add(self.delta_price);
 		}
 
+		/// Moves the bucket back to a full previous one in the sequence.
+		pub fn previous(&mut self) {
+			if self.index.is_zero() {
+				return;
+			}
+			self.index.saturating_dec();
+			self.current_price = self.current_price.saturating_sub(self.delta_price);
+			if self.index.is_zero() {
+				self.amount_left = self.auction_allocation;
+				let (delta_price, delta_amount) =
+					self.curve.step(1, self.initial_price, self.initial_price, self.auction_allocation);
+				self.delta_price = delta_price;
+				self.delta_amount = delta_amount;
+			} else {
+				let previous_price = self
+					.buckets_until(self.index.saturating_sub(1))
+					.last()
+					.map(|(_, price)| *price)
+					.unwrap_or(self.initial_price);
+				let (delta_price, delta_amount) =
+					self.curve.step(self.index, previous_price, self.initial_price, self.auction_allocation);
+				self.delta_price = delta_price;
+				self.delta_amount = delta_amount;
+				self.amount_left = self.delta_amount;
+			}
+		}
+
+		/// Returns the size and price of every bucket after the first one, up to and including `index`.
+		pub fn buckets_until(&self, index: u32) -> Vec<(Balance, Price)> {
+			let mut price = self.initial_price;
+			(1..=index)
+				.map(|i| {
+					let (delta_price, delta_amount) =
+						self.curve.step(i, price, self.initial_price, self.auction_allocation);
+					price = price.saturating_add(delta_price);
+					(delta_amount, price)
+				})
+				.collect()
+		}
+
 		pub fn calculate_wap(self, mut total_amount: Balance) -> Price {
 			// First bucket is not empty so wap is the same as the initial price
-			if self.current_price == self.initial_price {
+			if self.index.is_zero() {
 				return self.current_price;
 			}
-			let mut amount: Balance = self.delta_amount.saturating_sub(self.amount_left);
-			let mut price: Price = self.current_price;
+			let mut buckets = self.buckets_until(self.index);
+			if let Some(current) = buckets.last_mut() {
+				current.0 = self.delta_amount.saturating_sub(self.amount_left);
+			}
 			let mut bucket_sizes: Vec<(Balance, Price)> = Vec::new();
-			while price > self.initial_price && total_amount > Balance::zero() {
+			for (amount, price) in buckets.into_iter().rev() {
+				if total_amount.is_zero() {
+					break;
+				}
 				total_amount.saturating_reduce(amount);
 				bucket_sizes.push((price.saturating_mul_int(amount), price));
-				price = price.saturating_sub(self.delta_price);
-				amount = self.delta_amount;
 			}
 
 			if total_amount > Balance::zero() {
@@ -483,8 +619,7 @@ pub mod inner {
 	#[allow(clippy::wildcard_imports)]
 	use super::*;
 	use crate::Balance;
-	use variant_count::VariantCount;
-	use xcm::v4::QueryId;
+	use xcm::v4::{Location, QueryId};
 
 	pub enum MetadataError {
 		/// The minimum price per token is too low.
@@ -497,6 +632,12 @@ pub mod inner {
 		AllocationSizeError,
 		/// The auction round percentage cannot be zero.
 		AuctionRoundPercentageError,
+		/// The candle auction closing phase cannot be zero.
+		CandleAuctionPhaseError,
+		/// The bucket curve steps cannot be zero, and a custom curve needs at least one step.
+		BucketCurveError,
+		/// The milestone shares cannot be zero, and have to add up to 100%.
+		MilestonesError,
 		/// The funding target has to be higher than 1000 USD.
 		FundingTargetTooLow,
 		/// The funding target has to be lower than 1bn USD.
@@ -522,6 +663,155 @@ pub mod inner {
 		pub decimals: u8,
 	}
 
+	/// Oracle price used to value the PLMC bond and the funding assets of a participation.
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize,
+	)]
+	pub enum PriceKind {
+		/// The latest price accepted by the oracle.
+		Spot,
+		/// The time-weighted average of the prices accepted by the oracle. It is harder to move in thin markets.
+		Twap,
+	}
+
+	/// Maximum number of milestones a project can declare.
+	pub const MAX_MILESTONES: u32 = 10;
+
+	/// A deliverable of the project, which releases a share of the raised funds once approved.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize)]
+	pub struct Milestone<Cid> {
+		/// Share of the raised funds released to the issuer when the milestone is approved.
+		pub share: Percent,
+		/// IPFS CID describing the deliverable.
+		pub deliverable_cid: Cid,
+	}
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub enum MilestoneStatus<BlockNumber> {
+		/// The issuer submitted the deliverable, and the participants vote on it until `end`.
+		Voting {
+			end: BlockNumber,
+			ayes: Balance,
+			nays: Balance,
+		},
+		Approved,
+		Rejected,
+	}
+
+	/// Funds held in the project pot until the milestones are approved.
+	#[derive(Default, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct ProjectEscrow {
+		/// Sum of the CTs bought by the participants. Used as voting power and to split refunds.
+		pub total_shares: Balance,
+		/// A milestone was rejected, so the remaining funds are refunded to the participants.
+		pub refunding: bool,
+	}
+
+	#[derive(Default, Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct EscrowBalance {
+		/// Amount paid into the escrow during the settlement.
+		pub deposited: Balance,
+		/// Amount not yet released to the issuer.
+		pub remaining: Balance,
+	}
+
+	/// Maximum number of steps a [`BucketCurve::Custom`] table can hold.
+	pub const MAX_CUSTOM_BUCKET_STEPS: usize = 8;
+
+	#[derive(
+		Default,
+		Clone,
+		Copy,
+		Encode,
+		Decode,
+		Eq,
+		PartialEq,
+		RuntimeDebug,
+		MaxEncodedLen,
+		TypeInfo,
+		Serialize,
+		Deserialize,
+	)]
+	pub struct BucketStep {
+		/// Price increment over the previous bucket, as a percentage of the minimum price.
+		pub price_step: Percent,
+		/// Size of the bucket, as a percentage of the auction allocation.
+		pub amount_step: Percent,
+	}
+
+	/// Defines how the price and size of the auction buckets evolve after the first one.
+	/// The first bucket always holds the whole auction allocation at the minimum price.
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize,
+	)]
+	pub enum BucketCurve {
+		/// Each bucket increases the price by `price_step` of the minimum price,
+		/// and holds `amount_step` of the auction allocation.
+		Linear { price_step: Percent, amount_step: Percent },
+		/// Each bucket increases the price by `price_growth` of the previous bucket's price,
+		/// and holds `amount_step` of the auction allocation.
+		Geometric { price_growth: Percent, amount_step: Percent },
+		/// The n-th bucket after the first one uses the n-th step of the table.
+		/// Once the first `len` steps are used up, the last one is repeated.
+		Custom { steps: [BucketStep; MAX_CUSTOM_BUCKET_STEPS], len: u8 },
+	}
+
+	impl Default for BucketCurve {
+		fn default() -> Self {
+			BucketCurve::Linear { price_step: Percent::from_percent(10), amount_step: Percent::from_percent(10) }
+		}
+	}
+
+	impl BucketCurve {
+		/// Builds a custom curve out of a step table. Returns `None` if the table is empty or too long.
+		pub fn custom(table: Vec<BucketStep>) -> Option<Self> {
+			if table.is_empty() || table.len() > MAX_CUSTOM_BUCKET_STEPS {
+				return None;
+			}
+			let mut steps = [BucketStep::default(); MAX_CUSTOM_BUCKET_STEPS];
+			steps[..table.len()].copy_from_slice(&table);
+			Some(BucketCurve::Custom { steps, len: table.len() as u8 })
+		}
+
+		pub fn is_valid(&self) -> bool {
+			match self {
+				BucketCurve::Linear { price_step, amount_step } => !price_step.is_zero() && !amount_step.is_zero(),
+				BucketCurve::Geometric { price_growth, amount_step } => !price_growth.is_zero() && !amount_step.is_zero(),
+				BucketCurve::Custom { steps, len } =>
+					*len > 0 &&
+						(*len as usize) <= MAX_CUSTOM_BUCKET_STEPS &&
+						steps[..*len as usize]
+							.iter()
+							.all(|step| !step.price_step.is_zero() && !step.amount_step.is_zero()),
+			}
+		}
+
+		/// Returns the price increment and the size of the bucket at `index`, given the price of the one before it.
+		/// Index 0 is the first bucket, so `index` is expected to be at least 1.
+		pub fn step<Price: FixedPointNumber>(
+			&self,
+			index: u32,
+			previous_price: Price,
+			initial_price: Price,
+			auction_allocation: Balance,
+		) -> (Price, Balance) {
+			let percent_of = |percent: Percent, price: Price| {
+				Price::saturating_from_rational(percent.deconstruct(), 100u8).saturating_mul(price)
+			};
+			match self {
+				BucketCurve::Linear { price_step, amount_step } =>
+					(percent_of(*price_step, initial_price), *amount_step * auction_allocation),
+				BucketCurve::Geometric { price_growth, amount_step } =>
+					(percent_of(*price_growth, previous_price), *amount_step * auction_allocation),
+				BucketCurve::Custom { steps, len } => {
+					let last = (*len as usize).clamp(1, MAX_CUSTOM_BUCKET_STEPS).saturating_sub(1);
+					let step = steps[(index.saturating_sub(1) as usize).min(last)];
+					(percent_of(step.price_step, initial_price), step.amount_step * auction_allocation)
+				},
+			}
+		}
+	}
+
 	#[derive(
 		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize,
 	)]
@@ -624,8 +914,10 @@ pub mod inner {
 		}
 	}
 
+	/// A funding asset, identified by its id in the `FundingCurrency`. Only the assets in the
+	/// [`FundingAssets`](crate::FundingAssets) registry can be used by projects.
+	/// The first variants keep the encoding of the assets supported before the registry existed.
 	#[derive(
-		VariantCount,
 		Clone,
 		Copy,
 		Encode,
@@ -647,6 +939,9 @@ pub mod inner {
 		USDC,
 		#[codec(index = 2)]
 		DOT,
+		/// Any other asset of the `FundingCurrency`. Never holds the id of one of the variants above.
+		#[codec(index = 3)]
+		Other(u32),
 	}
 	impl AcceptedFundingAsset {
 		pub const fn id(&self) -> u32 {
@@ -654,10 +949,37 @@ pub mod inner {
 				AcceptedFundingAsset::USDT => 1984,
 				AcceptedFundingAsset::DOT => 10,
 				AcceptedFundingAsset::USDC => 1337,
+				AcceptedFundingAsset::Other(id) => *id,
+			}
+		}
+
+		/// The funding asset with the asset id `id`, using the named variant when there is one.
+		pub const fn from_id(id: u32) -> Self {
+			match id {
+				1984 => AcceptedFundingAsset::USDT,
+				10 => AcceptedFundingAsset::DOT,
+				1337 => AcceptedFundingAsset::USDC,
+				id => AcceptedFundingAsset::Other(id),
 			}
 		}
 	}
 
+	/// Maximum number of funding assets a single project can accept.
+	pub const MAX_PARTICIPATION_CURRENCIES: u32 = 8;
+
+	/// Registry entry of a funding asset.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Serialize, Deserialize)]
+	pub struct FundingAssetInfo {
+		/// Location of the asset, used to recognise it in XCM messages.
+		pub location: Location,
+		/// Decimals of the asset, used to convert the USD price given by the oracle.
+		pub decimals: u8,
+		/// Ticker under which the oracle publishes the USD price of the asset, right-padded with zeros.
+		pub oracle_key: [u8; 8],
+		/// Whether new projects and participations can use the asset. Existing participations are settled regardless.
+		pub enabled: bool,
+	}
+
 	#[derive(
 		Default, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
 	)]
@@ -673,6 +995,10 @@ pub mod inner {
 		SettlementFinished(FundingOutcome),
 		CTMigrationStarted,
 		CTMigrationFinished,
+		/// The funding ended between the [`FundingSuccessThreshold`](crate::Config::FundingSuccessThreshold) and the
+		/// [`FundingAutoSuccessThreshold`](crate::Config::FundingAutoSuccessThreshold). The issuer has until the end of
+		/// the round to accept or reject the funding.
+		AwaitingDecision,
 	}
 
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Serialize, Deserialize)]
@@ -752,6 +1078,55 @@ pub mod inner {
 		pub normal_evaluator_total_bonded_usd: Balance,
 	}
 
+	/// Maximum number of fee brackets in the [`RewardParameters`].
+	pub const MAX_FEE_BRACKETS: u32 = 10;
+
+	/// Parameters deciding how much fee is charged on the funds raised, and how the CTs of the fee get split.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RewardParameters {
+		/// Fee percentage charged on each slice of the USD raised. The last bracket should have `Balance::MAX` as limit.
+		pub fee_brackets: BoundedVec<(Percent, Balance), ConstU32<MAX_FEE_BRACKETS>>,
+		/// Share of the fee given to the evaluators.
+		pub evaluators_share: Perquintill,
+		/// Share of the evaluators rewards given to the early evaluators.
+		pub early_evaluators_share: Perquintill,
+		/// Share of the evaluators rewards given to the normal evaluators.
+		pub normal_evaluators_share: Perquintill,
+		/// Share of the fee given to the liquidity pools.
+		pub liquidity_pools_share: Perquintill,
+		/// Share of the fee given to the long term holders.
+		pub long_term_holders_share: Perquintill,
+	}
+
+	impl RewardParameters {
+		/// The parameters used before governance sets any, with the fee brackets from the runtime configuration.
+		/// Gives the fee brackets back if there are more than [`MAX_FEE_BRACKETS`].
+		pub fn with_fee_brackets(fee_brackets: Vec<(Percent, Balance)>) -> Result<Self, Vec<(Percent, Balance)>> {
+			Ok(Self {
+				fee_brackets: BoundedVec::try_from(fee_brackets)?,
+				evaluators_share: Perquintill::from_percent(30),
+				early_evaluators_share: Perquintill::from_percent(20),
+				normal_evaluators_share: Perquintill::from_percent(80),
+				liquidity_pools_share: Perquintill::from_percent(50),
+				long_term_holders_share: Perquintill::from_percent(20),
+			})
+		}
+
+		/// The fee brackets must cover any amount raised, and each split must add up to 100%.
+		pub fn is_valid(&self) -> bool {
+			let adds_up_to_one = |shares: &[Perquintill]| {
+				shares.iter().map(|share| share.deconstruct() as u128).sum::<u128>() ==
+					Perquintill::one().deconstruct() as u128
+			};
+			let fee_brackets_valid = self.fee_brackets.last().is_some_and(|(_, limit)| *limit == Balance::MAX) &&
+				self.fee_brackets.iter().all(|(_, limit)| !limit.is_zero());
+
+			fee_brackets_valid &&
+				adds_up_to_one(&[self.early_evaluators_share, self.normal_evaluators_share]) &&
+				adds_up_to_one(&[self.evaluators_share, self.liquidity_pools_share, self.long_term_holders_share])
+		}
+	}
+
 	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub enum FundingOutcomeDecision {
 		AcceptFunding,
@@ -804,8 +1179,8 @@ pub mod inner {
 
 pub mod extrinsic {
 	use crate::{
-		AcceptedFundingAsset, AccountIdOf, Balance, Config, MultiplierOf, PriceOf, ProjectDetailsOf, ProjectId,
-		TicketSize,
+		AcceptedFundingAsset, AccountIdOf, Balance, Config, MultiplierOf, PriceKind, PriceOf, ProjectDetailsOf,
+		ProjectId, TicketSize,
 	};
 	use frame_system::pallet_prelude::BlockNumberFor;
 	use polimec_common::credentials::{Cid, Did, InvestorType};
@@ -832,6 +1207,7 @@ pub mod extrinsic {
 		pub now: BlockNumberFor<T>,
 		pub did: Did,
 		pub metadata_ticket_size_bounds: TicketSize,
+		pub price_kind: PriceKind,
 		pub total_bids_by_bidder: u32,
 		pub total_bids_for_project: u32,
 	}
@@ -21,6 +21,7 @@
 #![allow(clippy::type_complexity)]
 
 mod benchmarking;
+pub mod runtime_api;
 pub mod weights;
 
 use frame_support::{
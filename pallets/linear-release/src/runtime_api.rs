@@ -0,0 +1,103 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountIdOf, BalanceOf, Config, Pallet, ReasonOf, Vesting, VestingInfoOf};
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	SaturatedConversion,
+};
+use sp_std::prelude::*;
+
+/// A vesting schedule together with its state evaluated at a given block.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ScheduleStatus<Balance, BlockNumber> {
+	/// The schedule as stored on-chain.
+	pub schedule: crate::VestingInfo<Balance, BlockNumber>,
+	/// Amount still locked at the evaluated block.
+	pub locked: Balance,
+	/// Amount released by the schedule up to the evaluated block.
+	pub releasable: Balance,
+	/// First block at which the schedule has nothing left locked.
+	pub ending_block: BlockNumber,
+}
+pub type ScheduleStatusOf<T> = ScheduleStatus<BalanceOf<T>, BlockNumberFor<T>>;
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait LinearReleaseApi<T: Config> {
+		/// Get every vesting schedule of `account`, grouped by hold reason, evaluated at block `at`.
+		fn vesting_schedules(account: AccountIdOf<T>, at: BlockNumberFor<T>) -> Vec<(ReasonOf<T>, Vec<ScheduleStatusOf<T>>)>;
+
+		/// Get the total amount locked under `reason` for `account` at block `at` and at every later block where
+		/// one of its schedules starts or ends. The locked amount is linear between two consecutive points.
+		fn unlock_curve(account: AccountIdOf<T>, reason: ReasonOf<T>, at: BlockNumberFor<T>) -> Vec<(BlockNumberFor<T>, BalanceOf<T>)>;
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn vesting_schedules(
+		account: AccountIdOf<T>,
+		at: BlockNumberFor<T>,
+	) -> Vec<(ReasonOf<T>, Vec<ScheduleStatusOf<T>>)> {
+		Vesting::<T>::iter_prefix(account)
+			.map(|(reason, schedules)| {
+				(reason, schedules.into_iter().map(|schedule| Self::schedule_status(schedule, at)).collect())
+			})
+			.collect()
+	}
+
+	pub fn unlock_curve(
+		account: AccountIdOf<T>,
+		reason: ReasonOf<T>,
+		at: BlockNumberFor<T>,
+	) -> Vec<(BlockNumberFor<T>, BalanceOf<T>)> {
+		let schedules = Vesting::<T>::get(account, reason).unwrap_or_default();
+
+		let mut breakpoints = schedules
+			.iter()
+			.flat_map(|schedule| [schedule.starting_block(), Self::ending_block(schedule)])
+			.filter(|block| *block > at)
+			.collect::<Vec<_>>();
+		breakpoints.push(at);
+		breakpoints.sort();
+		breakpoints.dedup();
+
+		breakpoints
+			.into_iter()
+			.map(|block| {
+				let locked = schedules.iter().fold(Zero::zero(), |acc: BalanceOf<T>, schedule| {
+					acc.saturating_add(schedule.locked_at::<T::BlockNumberToBalance>(block))
+				});
+				(block, locked)
+			})
+			.collect()
+	}
+
+	fn schedule_status(schedule: VestingInfoOf<T>, at: BlockNumberFor<T>) -> ScheduleStatusOf<T> {
+		ScheduleStatus {
+			locked: schedule.locked_at::<T::BlockNumberToBalance>(at),
+			releasable: schedule.releaseble_at::<T::BlockNumberToBalance>(at),
+			ending_block: Self::ending_block(&schedule),
+			schedule,
+		}
+	}
+
+	fn ending_block(schedule: &VestingInfoOf<T>) -> BlockNumberFor<T> {
+		schedule.ending_block_as_balance::<T::BlockNumberToBalance>().saturated_into::<u128>().saturated_into()
+	}
+}
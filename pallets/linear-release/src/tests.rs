@@ -1484,3 +1484,39 @@ fn manual_vest_all_different_reason() {
 		assert_eq!(user_3_free_balance, 30 * ED);
 	});
 }
+
+#[test]
+fn runtime_api_vesting_schedules_and_unlock_curve() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let user1_vesting_schedule = VestingInfo::new(ED * 5, 128, 0);
+		let user2_vesting_schedule = VestingInfo::new(ED * 20, ED, 10);
+
+		// Half of account 1's schedule is released at block 5.
+		assert_eq!(
+			Vesting::vesting_schedules(1, 5),
+			vec![(
+				MockRuntimeHoldReason::Reason,
+				vec![runtime_api::ScheduleStatus {
+					schedule: user1_vesting_schedule,
+					locked: 640,
+					releasable: 640,
+					ending_block: 10,
+				}]
+			)]
+		);
+		// Evaluating after the end releases everything.
+		assert_eq!(Vesting::vesting_schedules(1, 42)[0].1[0].locked, 0);
+		assert_eq!(Vesting::vesting_schedules(1, 42)[0].1[0].releasable, ED * 5);
+		assert!(Vesting::vesting_schedules(3, 5).is_empty());
+
+		// Account 2 starts releasing at block 10, and has nothing locked from block 30 onwards.
+		assert_eq!(Vesting::vesting_schedules(2, 0)[0].1[0].ending_block, 30);
+		assert_eq!(
+			Vesting::unlock_curve(2, MockRuntimeHoldReason::Reason, 0),
+			vec![(0, ED * 20), (10, ED * 20), (30, 0)]
+		);
+		assert_eq!(Vesting::unlock_curve(2, MockRuntimeHoldReason::Reason, 20), vec![(20, ED * 10), (30, 0)]);
+		assert_eq!(Vesting::unlock_curve(2, MockRuntimeHoldReason::Reason2, 0), vec![(0, 0)]);
+		assert_eq!(Vesting::vesting(2, MockRuntimeHoldReason::Reason).unwrap(), vec![user2_vesting_schedule]);
+	});
+}
@@ -0,0 +1,109 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Combination of the prices answered by the price sources.
+use super::{AggregationStrategy, FixedU128, Saturating, Vec, Zero};
+use sp_runtime::FixedPointNumber;
+
+/// Reason why no price was aggregated for an asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregationError {
+	/// No source reported any volume.
+	NoVolume,
+	/// Fewer sources than required agree on the price.
+	NoQuorum { agreeing: u32, required: u32 },
+}
+
+/// Combine the `(volume weighted price sum, total volume)` answers of the sources into a single
+/// price, following `strategy`. Under every strategy, at least `min_sources` answers must agree on
+/// the price, i.e. not be rejected as outliers.
+pub fn aggregate(
+	strategy: AggregationStrategy,
+	min_sources: u32,
+	answers: Vec<(FixedU128, FixedU128)>,
+) -> Result<FixedU128, AggregationError> {
+	let answers: Vec<(FixedU128, FixedU128)> = answers.into_iter().filter(|(_, volume)| !volume.is_zero()).collect();
+	if answers.is_empty() {
+		return Err(AggregationError::NoVolume);
+	}
+
+	let max_deviations = match strategy {
+		AggregationStrategy::VolumeWeightedWithoutOutliers { max_deviations } => max_deviations,
+		AggregationStrategy::VolumeWeighted | AggregationStrategy::Median => QUORUM_MAX_DEVIATIONS,
+	};
+	let agreeing = reject_outliers(&answers, max_deviations);
+	let agreeing_count = agreeing.len() as u32;
+	if agreeing_count < min_sources {
+		return Err(AggregationError::NoQuorum { agreeing: agreeing_count, required: min_sources });
+	}
+
+	match strategy {
+		AggregationStrategy::VolumeWeighted => Ok(volume_weighted(&answers)),
+		AggregationStrategy::Median =>
+			Ok(median(answers.into_iter().map(|(price_sum, volume)| price_sum / volume).collect())),
+		AggregationStrategy::VolumeWeightedWithoutOutliers { .. } => Ok(volume_weighted(&agreeing)),
+	}
+}
+
+fn volume_weighted(answers: &[(FixedU128, FixedU128)]) -> FixedU128 {
+	let (price_sum, volume) = answers.iter().fold((FixedU128::zero(), FixedU128::zero()), |acc, (price, volume)| {
+		(acc.0.saturating_add(*price), acc.1.saturating_add(*volume))
+	});
+	price_sum / volume
+}
+
+/// Median of a non-empty list, the mean of the two middle values if the length is even.
+fn median(mut values: Vec<FixedU128>) -> FixedU128 {
+	values.sort();
+	let middle = values.len() / 2;
+	if values.len() % 2 == 0 {
+		values[middle - 1].saturating_add(values[middle]) / FixedU128::from_u32(2)
+	} else {
+		values[middle]
+	}
+}
+
+fn abs_diff(a: FixedU128, b: FixedU128) -> FixedU128 {
+	if a > b {
+		a.saturating_sub(b)
+	} else {
+		b.saturating_sub(a)
+	}
+}
+
+/// Lowest MAD used to reject outliers, relative to the median price (1 bp). Without it, when most
+/// sources answer the exact same price, any other answer would be rejected.
+const MIN_RELATIVE_MAD: FixedU128 = FixedU128::from_inner(FixedU128::DIV / 10_000);
+
+/// MAD an answer may deviate from the median price and still count towards the quorum, for the
+/// strategies that do not reject outliers themselves.
+const QUORUM_MAX_DEVIATIONS: FixedU128 = FixedU128::from_inner(3 * FixedU128::DIV);
+
+/// Keep the answers whose price is within `max_deviations` MAD of the median price.
+fn reject_outliers(answers: &[(FixedU128, FixedU128)], max_deviations: FixedU128) -> Vec<(FixedU128, FixedU128)> {
+	let prices: Vec<FixedU128> = answers.iter().map(|(price_sum, volume)| *price_sum / *volume).collect();
+	let median_price = median(prices.clone());
+	let deviations: Vec<FixedU128> = prices.iter().map(|price| abs_diff(*price, median_price)).collect();
+	let mad = median(deviations.clone()).max(median_price.saturating_mul(MIN_RELATIVE_MAD));
+	let max_deviation = max_deviations.saturating_mul(mad);
+
+	answers
+		.iter()
+		.copied()
+		.zip(deviations)
+		.filter_map(|(answer, deviation)| (deviation <= max_deviation).then_some(answer))
+		.collect()
+}
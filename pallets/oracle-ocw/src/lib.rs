@@ -19,12 +19,12 @@
 // Needed due to empty sections raising the warning
 #![allow(unreachable_patterns)]
 
+extern crate alloc;
+
 use crate::{
-	traits::FetchPrice,
-	types::{
-		AssetName, AssetRequest, BitFinexFetcher, BitStampFetcher, CoinbaseFetcher, KrakenFetcher, MexcFetcher,
-		OpenCloseVolume, XTFetcher,
-	},
+	aggregation::aggregate,
+	traits::{fetch_candles, FetchPrice, PriceSources, ProvideTwap},
+	types::{AggregationStrategy, AssetName, AssetRequest, OpenCloseVolume, SourceConfig, SourceName, TwapObservation},
 };
 use core::ops::Rem;
 use frame_support::{pallet_prelude::*, traits::Contains};
@@ -33,6 +33,7 @@ use frame_system::{
 	pallet_prelude::*,
 };
 use orml_oracle::Call as OracleCall;
+use orml_traits::{DataProviderExtended, OnNewData};
 pub use pallet::*;
 use sp_runtime::{
 	offchain::{
@@ -40,23 +41,30 @@ use sp_runtime::{
 		storage_lock::{StorageLock, Time},
 		Duration,
 	},
-	traits::{Convert, IdentifyAccount, Saturating, Zero},
-	FixedU128, RuntimeAppPublic,
+	traits::{CheckedDiv, Convert, IdentifyAccount, SaturatedConversion, Saturating, Zero},
+	FixedPointNumber, FixedU128, RuntimeAppPublic,
 };
 use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
 
 mod mock;
 mod tests;
 
-mod traits;
+pub mod traits;
 
 pub mod types;
 
+pub mod aggregation;
+
 pub mod crypto;
 
+pub mod runtime_api;
+
 const LOG_TARGET: &str = "ocw::oracle";
 // Change values in Fetcher urls when changing this value
 pub(crate) const NUMBER_OF_CANDLES: usize = 15;
+/// Offchain local storage key of the SCALE-encoded `BTreeMap<SourceName, SourceConfig>` overriding
+/// the default configuration of the price sources.
+pub const SOURCES_CONFIG_KEY: &[u8] = b"oracle_ocw::sources";
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -71,13 +79,17 @@ pub mod pallet {
 	pub type GenericPublicOf<T> = <<T as Config>::AppCrypto as AppCrypto<PublicOf<T>, SignatureOf<T>>>::GenericPublic;
 	pub type RuntimeAppPublicOf<T> =
 		<<T as Config>::AppCrypto as AppCrypto<PublicOf<T>, SignatureOf<T>>>::RuntimeAppPublic;
+	pub type MomentOf<T> = <<T as orml_oracle::Config>::Time as frame_support::traits::Time>::Moment;
+	pub type TwapObservationOf<T> = TwapObservation<MomentOf<T>>;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
 	pub trait Config:
-		CreateSignedTransaction<OracleCall<Self>> + frame_system::Config + orml_oracle::Config<()>
+		CreateSignedTransaction<OracleCall<Self>>
+		+ frame_system::Config
+		+ orml_oracle::Config<(), OracleValue = FixedU128>
 	{
 		/// The overarching event type of the runtime.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -95,10 +107,34 @@ pub mod pallet {
 		/// Example: FetchInterval = 10, FetchWindow = 5 => Ocw will try to fetch prices once
 		/// for the next windows: [0, 5), [10, 15), [20, 25), ...
 		type FetchWindow: Get<BlockNumberFor<Self>>;
-		/// Convert AssetName and FixedU128 to OracleKey and OracleValue
-		type ConvertAssetPricePair: Convert<(AssetName, FixedU128), (Self::OracleKey, Self::OracleValue)>;
+		/// Convert AssetName and FixedU128 to OracleKey and OracleValue. Assets without an oracle key convert to `None`.
+		type ConvertAssetPricePair: Convert<(AssetName, FixedU128), Option<(Self::OracleKey, Self::OracleValue)>>;
+		/// Sources the prices are fetched from, as a tuple of [`FetchPrice`] implementations.
+		/// Their configuration can be changed without a runtime upgrade by writing to the offchain
+		/// local storage under [`SOURCES_CONFIG_KEY`], where sources with a [`types::SourceEndpoint`]
+		/// can be added as well.
+		type PriceSources: PriceSources;
+		/// How the prices fetched from the different sources are combined.
+		type AggregationStrategy: Get<AggregationStrategy>;
+		/// Minimum number of sources that must agree on the price of an asset for it to be
+		/// submitted, under every aggregation strategy. Sources whose price is an outlier do not count.
+		type MinimumSources: Get<u32>;
+		/// Period the time-weighted average price is computed over, in the unit of the oracle timestamps.
+		type TwapWindow: Get<MomentOf<Self>>;
+		/// Maximum number of accepted prices kept per asset to compute its time-weighted average price.
+		/// If the oracle accepts more prices than this within `TwapWindow`, the average covers a shorter period.
+		#[pallet::constant]
+		type MaxTwapObservations: Get<u32>;
+		/// Assets whose prices are fetched and fed to the oracle.
+		type TrackedAssets: Get<Vec<AssetName>>;
 	}
 
+	/// Prices accepted by the oracle for the fetched assets, from the oldest to the newest.
+	/// Only the newest observation preceding the `TwapWindow` is kept out of the older ones.
+	#[pallet::storage]
+	pub type TwapObservations<T: Config> =
+		StorageMap<_, Twox64Concat, T::OracleKey, BoundedVec<TwapObservationOf<T>, T::MaxTwapObservations>, ValueQuery>;
+
 	#[pallet::event]
 	pub enum Event<T: Config> {}
 
@@ -147,16 +183,15 @@ pub mod pallet {
 					> = val.get();
 					let mut last_send_for_assets = match last_send_for_assets_result {
 						Ok(Some(v)) => v,
-						_ => BTreeMap::from([
-							(AssetName::USDT, Zero::zero()),
-							(AssetName::USDC, Zero::zero()),
-							(AssetName::DOT, Zero::zero()),
-							(AssetName::PLMC, Zero::zero()),
-						]),
+						_ => BTreeMap::new(),
 					};
 
-					// Fix for missing PLMC in last_send_for_assets for old nodes, that did not have PLMC in the list.
-					last_send_for_assets.entry(AssetName::PLMC).or_insert_with(Zero::zero);
+					// The tracked assets can change at any time, e.g. when a funding asset is registered.
+					let tracked_assets = T::TrackedAssets::get();
+					last_send_for_assets.retain(|asset_name, _| tracked_assets.contains(asset_name));
+					for asset_name in tracked_assets {
+						last_send_for_assets.entry(asset_name).or_insert_with(Zero::zero);
+					}
 
 					let assets = last_send_for_assets
 						.iter()
@@ -203,53 +238,150 @@ pub mod pallet {
 
 	impl<T: Config> Pallet<T> {
 		fn fetch_prices(assets: Vec<AssetName>) -> BTreeMap<AssetName, FixedU128> {
-			let fetchers = vec![
-				KrakenFetcher::get_moving_average,
-				BitFinexFetcher::get_moving_average,
-				BitStampFetcher::get_moving_average,
-				CoinbaseFetcher::get_moving_average,
-				XTFetcher::get_moving_average,
-				MexcFetcher::get_moving_average,
-			];
+			let configs = Self::source_configs();
+
+			let mut answers = T::PriceSources::fetch(&assets, &configs, 5000);
+			for config in configs.values() {
+				if let Some(endpoint) = &config.endpoint {
+					answers.extend(fetch_candles(
+						&assets,
+						config,
+						5000,
+						|pair| endpoint.url(pair),
+						|body| endpoint.parser.parse_body(body),
+					));
+				}
+			}
 
 			let mut aggr_prices: BTreeMap<AssetName, Vec<(FixedU128, FixedU128)>> = BTreeMap::new();
-			for fetcher in fetchers.into_iter() {
-				let fetcher_prices = fetcher(assets.clone(), 5000);
-				for (asset_name, volume_price_sum, tot_vol) in fetcher_prices {
-					aggr_prices
-						.entry(asset_name)
-						.and_modify(|e| e.push((volume_price_sum, tot_vol)))
-						.or_insert(vec![(volume_price_sum, tot_vol)]);
-				}
+			for (asset_name, volume_price_sum, tot_vol) in answers {
+				aggr_prices
+					.entry(asset_name)
+					.and_modify(|e| e.push((volume_price_sum, tot_vol)))
+					.or_insert(vec![(volume_price_sum, tot_vol)]);
 			}
 
 			Self::combine_prices(aggr_prices)
 		}
 
+		/// The configuration of every source in `T::PriceSources`, and of the sources configured with
+		/// an endpoint by the node. The configurations stored in the offchain local storage take
+		/// precedence over the defaults of the sources.
+		pub fn source_configs() -> BTreeMap<SourceName, SourceConfig> {
+			let mut configs = T::PriceSources::default_configs();
+			let stored: Result<Option<BTreeMap<SourceName, SourceConfig>>, StorageRetrievalError> =
+				StorageValueRef::persistent(SOURCES_CONFIG_KEY).get();
+			match stored {
+				Ok(Some(stored)) =>
+					for (name, config) in stored {
+						match configs.get_mut(&name) {
+							// The runtime sources always use their own endpoint
+							Some(default) => *default = SourceConfig { endpoint: None, ..config },
+							None if config.endpoint.is_some() => {
+								configs.insert(name, config);
+							},
+							None => log::warn!(target: LOG_TARGET, "Unknown price source {:?} ignored", name),
+						}
+					},
+				Ok(None) => (),
+				Err(_) => log::error!(target: LOG_TARGET, "Invalid price source configuration, using defaults"),
+			}
+			configs
+		}
+
 		fn combine_prices(prices: BTreeMap<AssetName, Vec<(FixedU128, FixedU128)>>) -> BTreeMap<AssetName, FixedU128> {
+			let strategy = T::AggregationStrategy::get();
+			let min_sources = T::MinimumSources::get();
 			prices
 				.into_iter()
-				.filter_map(|(key, price_list)| {
-					if price_list.is_empty() {
-						return None;
-					}
-					let combined_prices =
-						price_list.into_iter().fold((FixedU128::zero(), FixedU128::zero()), |acc, (price, volume)| {
-							(acc.0 + price, acc.1 + volume)
-						});
-					if combined_prices.1.is_zero() {
-						return None;
-					}
-					Some((key, combined_prices.0.div(combined_prices.1)))
+				.filter_map(|(key, price_list)| match aggregate(strategy, min_sources, price_list) {
+					Ok(price) => Some((key, price)),
+					Err(reason) => {
+						log::warn!(target: LOG_TARGET, "Skipping price submission for {:?}: {:?}", key, reason);
+						None
+					},
 				})
 				.collect::<BTreeMap<AssetName, FixedU128>>()
 		}
 
+		/// Oracle keys of the assets fetched by the offchain worker.
+		pub fn tracked_keys() -> Vec<T::OracleKey> {
+			T::TrackedAssets::get()
+				.into_iter()
+				.filter_map(|asset_name| T::ConvertAssetPricePair::convert((asset_name, FixedU128::zero())))
+				.map(|(key, _)| key)
+				.collect()
+		}
+
+		/// Records the price currently accepted by the oracle for `key`, if it was not recorded yet.
+		pub fn observe_price(key: &T::OracleKey) {
+			if !Self::tracked_keys().contains(key) {
+				return;
+			}
+			let Some(accepted) = <orml_oracle::Pallet<T, ()> as DataProviderExtended<_, _>>::get_no_op(key) else {
+				return;
+			};
+			TwapObservations::<T>::mutate(key, |observations| {
+				let cumulative_price = match observations.last() {
+					// The oracle kept the previous price
+					Some(last) if last.timestamp >= accepted.timestamp => return,
+					Some(last) => last.cumulative_price_at(accepted.timestamp),
+					None => FixedU128::zero(),
+				};
+
+				let window_start = accepted.timestamp.saturating_sub(T::TwapWindow::get());
+				while observations.len() > 1 && observations[1].timestamp <= window_start {
+					observations.remove(0);
+				}
+				if observations.is_full() {
+					observations.remove(0);
+				}
+				let observation =
+					TwapObservation { price: accepted.value, timestamp: accepted.timestamp, cumulative_price };
+				// Cannot fail, as there is room for at least one more observation
+				let _ = observations.try_push(observation);
+			});
+		}
+
+		/// Worst case weight of [`Self::observe_price`]: reading the accepted price and the
+		/// observations of the key, and writing a full list of observations back.
+		pub fn observe_price_weight() -> Weight {
+			let proof_size = BoundedVec::<TwapObservationOf<T>, T::MaxTwapObservations>::max_encoded_len()
+				.saturating_add(orml_oracle::TimestampedValueOf::<T, ()>::max_encoded_len());
+			Weight::from_parts(
+				10_000_000u64.saturating_add(100_000u64.saturating_mul(T::MaxTwapObservations::get().into())),
+				proof_size as u64,
+			)
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+		}
+
+		/// Time-weighted average of the prices accepted by the oracle for `key` over the last `TwapWindow`.
+		/// When the recorded prices span a shorter period, the average starts at the oldest one.
+		pub fn twap(key: &T::OracleKey) -> Option<FixedU128> {
+			let observations = TwapObservations::<T>::get(key);
+			let last = observations.last()?;
+			let now = <<T as orml_oracle::Config>::Time as frame_support::traits::Time>::now().max(last.timestamp);
+			let window_start = now.saturating_sub(T::TwapWindow::get());
+			let start = observations.iter().rev().find(|observation| observation.timestamp <= window_start);
+			let (start_cumulative_price, start_timestamp) = match start {
+				Some(start) => (start.cumulative_price_at(window_start), window_start),
+				None => (observations[0].cumulative_price, observations[0].timestamp),
+			};
+
+			let elapsed: u128 = now.saturating_sub(start_timestamp).saturated_into();
+			if elapsed.is_zero() {
+				return Some(last.price);
+			}
+			last.cumulative_price_at(now)
+				.saturating_sub(start_cumulative_price)
+				.checked_div(&FixedU128::saturating_from_integer(elapsed))
+		}
+
 		fn send_signed_transaction(prices: BTreeMap<AssetName, FixedU128>) -> Result<(), ()> {
 			let signer = Signer::<T, T::AppCrypto>::any_account();
 			let prices = prices
 				.into_iter()
-				.map(|(asset_name, price)| T::ConvertAssetPricePair::convert((asset_name, price)))
+				.filter_map(|(asset_name, price)| T::ConvertAssetPricePair::convert((asset_name, price)))
 				.collect::<Vec<(T::OracleKey, T::OracleValue)>>();
 
 			let call = OracleCall::<T, ()>::feed_values { values: BoundedVec::<_, _>::truncate_from(prices) };
@@ -267,3 +399,28 @@ pub mod pallet {
 		}
 	}
 }
+
+impl<T: Config> OnNewData<T::AccountId, T::OracleKey, FixedU128> for Pallet<T> {
+	fn on_new_data(_who: &T::AccountId, key: &T::OracleKey, _value: &FixedU128) {
+		Self::observe_price(key);
+	}
+}
+
+/// Weights of `orml_oracle` extended with the prices recorded by [`OnNewData`] for every fed value.
+/// Use it as the `WeightInfo` of the oracle this pallet observes, wrapping the oracle's own weights.
+pub struct OracleWeightWithTwap<T, W>(PhantomData<(T, W)>);
+impl<T: Config, W: orml_oracle::WeightInfo> orml_oracle::WeightInfo for OracleWeightWithTwap<T, W> {
+	fn feed_values(c: u32) -> Weight {
+		W::feed_values(c).saturating_add(Pallet::<T>::observe_price_weight().saturating_mul(c.into()))
+	}
+
+	fn on_finalize() -> Weight {
+		W::on_finalize()
+	}
+}
+
+impl<T: Config> ProvideTwap<T::OracleKey, FixedU128> for Pallet<T> {
+	fn twap(key: &T::OracleKey) -> Option<FixedU128> {
+		Pallet::<T>::twap(key)
+	}
+}
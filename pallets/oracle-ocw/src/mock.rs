@@ -17,7 +17,10 @@
 #![cfg(test)]
 
 use super::*;
-use crate as pallet_oracle_ocw;
+use crate::{
+	self as pallet_oracle_ocw,
+	types::{BitFinexFetcher, BitStampFetcher, CoinbaseFetcher, KrakenFetcher, MexcFetcher, XTFetcher},
+};
 
 use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
@@ -88,28 +91,32 @@ impl orml_oracle::Config for Test {
 	type MaxFeedValues = MaxFeedValues;
 	type MaxHasDispatchedSize = ConstU32<20>;
 	type Members = IsInVec<Members>;
-	type OnNewData = ();
+	type OnNewData = OracleOcw;
 	type OracleKey = OracleKey;
 	type OracleValue = OracleValue;
 	type RootOperatorAccountId = RootOperatorAccountId;
 	type RuntimeEvent = RuntimeEvent;
 	type Time = Timestamp;
-	type WeightInfo = ();
+	type WeightInfo = pallet_oracle_ocw::OracleWeightWithTwap<Test, ()>;
 }
 
 pub struct AssetPriceConverter;
-impl Convert<(AssetName, FixedU128), (OracleKey, OracleValue)> for AssetPriceConverter {
-	fn convert((asset, price): (AssetName, FixedU128)) -> (OracleKey, OracleValue) {
+impl Convert<(AssetName, FixedU128), Option<(OracleKey, OracleValue)>> for AssetPriceConverter {
+	fn convert((asset, price): (AssetName, FixedU128)) -> Option<(OracleKey, OracleValue)> {
 		match asset {
-			AssetName::DOT => (10, price),
-			AssetName::USDC => (1337, price),
-			AssetName::USDT => (1984, price),
-			AssetName::PLMC => (3344, price),
+			AssetName::DOT => Some((10, price)),
+			AssetName::USDC => Some((1337, price)),
+			AssetName::USDT => Some((1984, price)),
+			AssetName::PLMC => Some((3344, price)),
+			_ => None,
 		}
 	}
 }
 
 parameter_types! {
+	pub static TrackedAssets: Vec<AssetName> = vec![AssetName::USDT, AssetName::USDC, AssetName::DOT, AssetName::PLMC];
+	pub static Aggregation: AggregationStrategy = AggregationStrategy::VolumeWeighted;
+	pub static MinimumSources: u32 = 1;
 	pub static Members: Vec<AccountId> = vec![
 		get_account_id_from_seed::<crate::crypto::AuthorityId>("Alice"),
 		get_account_id_from_seed::<crate::crypto::AuthorityId>("Bob"),
@@ -117,12 +124,18 @@ parameter_types! {
 	];
 }
 impl Config for Test {
+	type AggregationStrategy = Aggregation;
 	type AppCrypto = crate::crypto::Polimec;
 	type ConvertAssetPricePair = AssetPriceConverter;
 	type FetchInterval = ConstU64<5u64>;
 	type FetchWindow = ConstU64<1u64>;
 	type Members = IsInVec<Members>;
+	type MinimumSources = MinimumSources;
+	type MaxTwapObservations = ConstU32<4>;
+	type PriceSources = (KrakenFetcher, BitFinexFetcher, BitStampFetcher, CoinbaseFetcher, XTFetcher, MexcFetcher);
 	type RuntimeEvent = RuntimeEvent;
+	type TrackedAssets = TrackedAssets;
+	type TwapWindow = ConstU32<100>;
 }
 
 impl frame_system::offchain::SigningTypes for Test {
@@ -157,7 +170,7 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Oracle: orml_oracle::{Pallet, Storage, Call, Event<T>},
-		OracleOcw: pallet_oracle_ocw::{Pallet, Event<T>},
+		OracleOcw: pallet_oracle_ocw::{Pallet, Storage, Event<T>},
 
 	}
 );
@@ -183,57 +196,83 @@ pub fn new_test_ext_with_offchain_storage(
 }
 
 pub fn price_oracle_response(state: &mut testing::OffchainState) {
-	for (asset, response) in KRAKEN_RESPONSES.iter() {
-		state.expect_request(testing::PendingRequest {
-			method: "GET".into(),
-			uri: format!("https://api.kraken.com/0/public/OHLC?pair={}&interval=1", asset).into(),
-			response: Some(response.to_vec()),
-			sent: true,
-			..Default::default()
-		});
+	price_oracle_response_for_sources(
+		state,
+		&[
+			KrakenFetcher::NAME,
+			BitFinexFetcher::NAME,
+			BitStampFetcher::NAME,
+			CoinbaseFetcher::NAME,
+			XTFetcher::NAME,
+			MexcFetcher::NAME,
+		],
+	);
+}
+
+pub fn price_oracle_response_for_sources(state: &mut testing::OffchainState, sources: &[&[u8]]) {
+	if sources.contains(&KrakenFetcher::NAME) {
+		for (asset, response) in KRAKEN_RESPONSES.iter() {
+			state.expect_request(testing::PendingRequest {
+				method: "GET".into(),
+				uri: format!("https://api.kraken.com/0/public/OHLC?pair={}&interval=1", asset).into(),
+				response: Some(response.to_vec()),
+				sent: true,
+				..Default::default()
+			});
+		}
 	}
 
-	for (asset, response) in BITFINEX_RESPONSES.iter() {
-		state.expect_request(testing::PendingRequest {
-			method: "GET".into(),
-			uri: format!("https://api-pub.bitfinex.com/v2/candles/trade%3A1m%3At{}/hist?limit=15", asset).into(),
-			response: Some(response.to_vec()),
-			sent: true,
-			..Default::default()
-		});
+	if sources.contains(&BitFinexFetcher::NAME) {
+		for (asset, response) in BITFINEX_RESPONSES.iter() {
+			state.expect_request(testing::PendingRequest {
+				method: "GET".into(),
+				uri: format!("https://api-pub.bitfinex.com/v2/candles/trade%3A1m%3At{}/hist?limit=15", asset).into(),
+				response: Some(response.to_vec()),
+				sent: true,
+				..Default::default()
+			});
+		}
+	}
+	if sources.contains(&BitStampFetcher::NAME) {
+		for (asset, response) in BITSTAMP_RESPONSES.iter() {
+			state.expect_request(testing::PendingRequest {
+				method: "GET".into(),
+				uri: format!("https://www.bitstamp.net/api/v2/ohlc/{}/?step=60&limit=15", asset).into(),
+				response: Some(response.to_vec()),
+				sent: true,
+				..Default::default()
+			});
+		}
 	}
-	for (asset, response) in BITSTAMP_RESPONSES.iter() {
+	if sources.contains(&CoinbaseFetcher::NAME) {
+		for (asset, response) in COINBASE_RESPONSES.iter() {
+			state.expect_request(testing::PendingRequest {
+				method: "GET".into(),
+				uri: format!("https://api.exchange.coinbase.com/products/{}/candles?granularity=60", asset).into(),
+				response: Some(response.to_vec()),
+				sent: true,
+				..Default::default()
+			});
+		}
+	}
+	if sources.contains(&XTFetcher::NAME) {
 		state.expect_request(testing::PendingRequest {
 			method: "GET".into(),
-			uri: format!("https://www.bitstamp.net/api/v2/ohlc/{}/?step=60&limit=15", asset).into(),
-			response: Some(response.to_vec()),
+			uri: "https://sapi.xt.com/v4/public/kline?symbol=plmc_usdt&interval=30m&limit=10".into(),
+			response: Some(XT_PLMC_CORRECT.to_vec()),
 			sent: true,
 			..Default::default()
 		});
 	}
-	for (asset, response) in COINBASE_RESPONSES.iter() {
+	if sources.contains(&MexcFetcher::NAME) {
 		state.expect_request(testing::PendingRequest {
 			method: "GET".into(),
-			uri: format!("https://api.exchange.coinbase.com/products/{}/candles?granularity=60", asset).into(),
-			response: Some(response.to_vec()),
+			uri: "https://api.mexc.com/api/v3/klines?symbol=PLMCUSDT&interval=30m&limit=10".into(),
+			response: Some(MEXC_PLMC_CORRECT.to_vec()),
 			sent: true,
 			..Default::default()
 		});
 	}
-	state.expect_request(testing::PendingRequest {
-		method: "GET".into(),
-		uri: "https://sapi.xt.com/v4/public/kline?symbol=plmc_usdt&interval=30m&limit=10".into(),
-		response: Some(XT_PLMC_CORRECT.to_vec()),
-		sent: true,
-		..Default::default()
-	});
-	state.expect_request(testing::PendingRequest {
-		method: "GET".into(),
-		uri: "https://api.mexc.com/api/v3/klines?symbol=PLMCUSDT&interval=30m&limit=10".into(),
-		response: Some(MEXC_PLMC_CORRECT.to_vec()),
-		sent: true,
-		..Default::default()
-	});
 }
 pub fn run_to_block(n: u64) {
 	while System::block_number() < n {
@@ -244,6 +283,14 @@ pub fn run_to_block(n: u64) {
 	}
 }
 
+/// Feeds `price` for `key` from every oracle member, then finalizes the block so they can feed again.
+pub fn feed_price(key: u64, price: FixedU128) {
+	for member in Members::get() {
+		Oracle::feed_values(RuntimeOrigin::signed(member), vec![(key, price)].try_into().unwrap()).unwrap();
+	}
+	Oracle::on_finalize(System::block_number());
+}
+
 /// Helper function to generate a crypto pair from seed
 pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
 	TPublic::Pair::from_string(&format!("//{}", seed), None).expect("static values are valid; qed").public()
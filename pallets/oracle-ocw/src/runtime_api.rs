@@ -0,0 +1,58 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API exposing the spot and time-weighted average prices of the fetched assets
+
+use crate::{Config, Pallet, Vec};
+use orml_traits::DataProviderExtended;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{FixedU128, RuntimeDebug};
+
+/// Spot and time-weighted average price of an asset fetched by the offchain worker.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct AssetPrices<Key> {
+	pub key: Key,
+	/// Latest price accepted by the oracle
+	pub spot: Option<FixedU128>,
+	/// Time-weighted average of the prices accepted by the oracle over the TWAP window
+	pub twap: Option<FixedU128>,
+}
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait OracleTwapApi<T: Config> {
+		/// Get the time-weighted average price of the asset identified by `key`.
+		fn twap(key: T::OracleKey) -> Option<FixedU128>;
+
+		/// Get the spot and time-weighted average prices of every asset fetched by the offchain worker.
+		fn prices() -> Vec<AssetPrices<T::OracleKey>>;
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn prices() -> Vec<AssetPrices<T::OracleKey>> {
+		Self::tracked_keys()
+			.into_iter()
+			.map(|key| {
+				let spot = <orml_oracle::Pallet<T, ()> as DataProviderExtended<_, _>>::get_no_op(&key)
+					.map(|accepted| accepted.value);
+				let twap = Self::twap(&key);
+				AssetPrices { key, spot, twap }
+			})
+			.collect()
+	}
+}
@@ -17,13 +17,20 @@
 #![cfg(test)]
 
 use crate::{
+	aggregation::{aggregate, AggregationError},
 	mock::*,
 	traits::FetchPrice,
-	types::{AssetName, BitFinexFetcher, BitStampFetcher, CoinbaseFetcher, KrakenFetcher, MexcFetcher, XTFetcher},
+	types::{
+		is_valid_pair, AggregationStrategy, AssetName, BitFinexFetcher, BitStampFetcher, CoinbaseFetcher,
+		KrakenFetcher, MexcFetcher, ParserKind, SourceConfig, SourceEndpoint, TwapObservation, XTFetcher,
+	},
+	TwapObservations, SOURCES_CONFIG_KEY,
 };
-use parity_scale_codec::Decode;
+use parity_scale_codec::{Decode, Encode};
 use polimec_common_test_utils::do_request;
-use sp_runtime::FixedU128;
+use sp_core::offchain::StorageKind;
+use sp_runtime::{traits::Zero, FixedU128, Percent};
+use std::collections::BTreeMap;
 
 #[test]
 fn call_offchain_worker() {
@@ -52,13 +59,413 @@ fn call_offchain_worker() {
 	});
 }
 
-fn test_fetcher_against_real_api<F: FetchPrice>() {
-	for asset in vec![AssetName::DOT, AssetName::USDC, AssetName::USDT, AssetName::PLMC] {
-		let url = F::get_url(asset);
-		if url == "" {
-			continue;
+#[test]
+fn disabled_price_sources_are_not_queried() {
+	let (mut ext, offchain_state, pool_state) = new_test_ext_with_offchain_storage();
+	price_oracle_response_for_sources(
+		&mut offchain_state.write(),
+		&[KrakenFetcher::NAME, BitFinexFetcher::NAME, BitStampFetcher::NAME, CoinbaseFetcher::NAME],
+	);
+	ext.execute_with(|| {
+		let disabled = |pairs: Vec<(AssetName, &str)>| SourceConfig {
+			enabled: false,
+			weight: Percent::from_percent(100),
+			pairs: pairs.into_iter().map(|(asset, pair)| (asset, pair.as_bytes().to_vec())).collect(),
+			endpoint: None,
+		};
+		let configs = BTreeMap::from([
+			(XTFetcher::NAME.to_vec(), disabled(XTFetcher::default_pairs())),
+			(MexcFetcher::NAME.to_vec(), disabled(MexcFetcher::default_pairs())),
+		]);
+		sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, SOURCES_CONFIG_KEY, &configs.encode());
+
+		run_to_block(6);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		match tx.call {
+			RuntimeCall::Oracle(orml_oracle::Call::feed_values { values }) => {
+				assert_eq!(values.len(), 3);
+				for (asset, price) in values {
+					match asset {
+						10 => assert_close_enough(price, FixedU128::from_float(6.138485575453039783)),
+						1984 => assert_close_enough(price, FixedU128::from_float(1.000154206100002620)),
+						1337 => assert_close_enough(price, FixedU128::from_float(1.000093378020633965)),
+						_ => panic!("Unexpected asset"),
+					}
+				}
+			},
+			_ => panic!("Unexpected call"),
+		}
+	});
+}
+
+#[test]
+fn stored_source_configs_override_defaults() {
+	let (mut ext, _, _) = new_test_ext_with_offchain_storage();
+	ext.execute_with(|| {
+		assert_eq!(OracleOcw::source_configs().get(KrakenFetcher::NAME), Some(&KrakenFetcher::default_config()));
+
+		let kraken = SourceConfig {
+			enabled: true,
+			weight: Percent::from_percent(50),
+			pairs: BTreeMap::from([(AssetName::DOT, b"XDOTZUSD".to_vec())]),
+			endpoint: None,
+		};
+		let configs = BTreeMap::from([
+			(KrakenFetcher::NAME.to_vec(), kraken.clone()),
+			(b"unknown".to_vec(), KrakenFetcher::default_config()),
+		]);
+		sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, SOURCES_CONFIG_KEY, &configs.encode());
+
+		let configs = OracleOcw::source_configs();
+		assert_eq!(configs.len(), 6);
+		assert_eq!(configs.get(KrakenFetcher::NAME), Some(&kraken));
+		assert_eq!(configs.get(MexcFetcher::NAME), Some(&MexcFetcher::default_config()));
+	});
+}
+
+#[test]
+fn sources_with_an_endpoint_are_queried_without_being_in_the_runtime() {
+	let (mut ext, offchain_state, pool_state) = new_test_ext_with_offchain_storage();
+	// Only the Kraken urls answer, queried by the configured source
+	price_oracle_response_for_sources(&mut offchain_state.write(), &[KrakenFetcher::NAME]);
+	ext.execute_with(|| {
+		let disabled = |config: SourceConfig| SourceConfig { enabled: false, ..config };
+		let mirror = SourceConfig {
+			endpoint: Some(SourceEndpoint {
+				url_template: b"https://api.kraken.com/0/public/OHLC?pair={pair}&interval=1".to_vec(),
+				parser: ParserKind::Kraken,
+			}),
+			..KrakenFetcher::default_config()
+		};
+		let configs = BTreeMap::from([
+			(KrakenFetcher::NAME.to_vec(), disabled(KrakenFetcher::default_config())),
+			(BitFinexFetcher::NAME.to_vec(), disabled(BitFinexFetcher::default_config())),
+			(BitStampFetcher::NAME.to_vec(), disabled(BitStampFetcher::default_config())),
+			(CoinbaseFetcher::NAME.to_vec(), disabled(CoinbaseFetcher::default_config())),
+			(XTFetcher::NAME.to_vec(), disabled(XTFetcher::default_config())),
+			(MexcFetcher::NAME.to_vec(), disabled(MexcFetcher::default_config())),
+			(b"kraken-mirror".to_vec(), mirror.clone()),
+		]);
+		sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, SOURCES_CONFIG_KEY, &configs.encode());
+		assert_eq!(OracleOcw::source_configs().get(&b"kraken-mirror"[..]), Some(&mirror));
+
+		run_to_block(6);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		match tx.call {
+			RuntimeCall::Oracle(orml_oracle::Call::feed_values { values }) => {
+				let assets = values.iter().map(|(asset, _)| *asset).collect::<Vec<_>>();
+				assert_eq!(assets, vec![10, 1337, 1984]);
+			},
+			_ => panic!("Unexpected call"),
 		}
-		let body = do_request(url);
+	});
+}
+
+#[test]
+fn runtime_sources_ignore_stored_endpoints() {
+	let (mut ext, _, _) = new_test_ext_with_offchain_storage();
+	ext.execute_with(|| {
+		let kraken = SourceConfig {
+			endpoint: Some(SourceEndpoint {
+				url_template: b"https://example.com/{pair}".to_vec(),
+				parser: ParserKind::Kraken,
+			}),
+			..KrakenFetcher::default_config()
+		};
+		let configs = BTreeMap::from([(KrakenFetcher::NAME.to_vec(), kraken)]);
+		sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, SOURCES_CONFIG_KEY, &configs.encode());
+
+		assert_eq!(OracleOcw::source_configs().get(KrakenFetcher::NAME), Some(&KrakenFetcher::default_config()));
+	});
+}
+
+#[test]
+fn pairs_with_url_delimiters_are_invalid() {
+	assert!(is_valid_pair("DOTUSD"));
+	assert!(is_valid_pair("USDT-USD"));
+	for pair in ["", "DOT/USD", "DOT?a=b", "DOT&x", "DOT#", "DOT USD", "DOT\tUSD", "DOT\n"] {
+		assert!(!is_valid_pair(pair), "{:?} should be invalid", pair);
+	}
+}
+
+#[test]
+fn parser_kinds_are_named_after_runtime_sources() {
+	for parser in ParserKind::ALL {
+		assert_eq!(ParserKind::from_source_name(parser.source_name()), Some(parser));
+	}
+	assert_eq!(ParserKind::from_source_name(b"unknown"), None);
+}
+
+#[test]
+fn kraken_parses_any_pair_name() {
+	let body = std::str::from_utf8(KRAKEN_RESPONSES[2].1).unwrap().replace("DOTUSD", "XDOTZUSD");
+	let data = KrakenFetcher::parse_body(&body).unwrap();
+	assert_eq!(data.len(), 15);
+}
+
+#[test]
+fn assets_without_quorum_are_not_submitted() {
+	let (mut ext, offchain_state, pool_state) = new_test_ext_with_offchain_storage();
+	price_oracle_response(&mut offchain_state.write());
+	ext.execute_with(|| {
+		// PLMC is only fetched from two sources
+		MinimumSources::set(3);
+		run_to_block(6);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		match tx.call {
+			RuntimeCall::Oracle(orml_oracle::Call::feed_values { values }) => {
+				assert_eq!(values.len(), 3);
+				assert!(values.iter().all(|(asset, _)| *asset != 3344));
+			},
+			_ => panic!("Unexpected call"),
+		}
+	});
+}
+
+#[test]
+fn only_tracked_assets_are_fetched() {
+	let (mut ext, offchain_state, pool_state) = new_test_ext_with_offchain_storage();
+	// Only PLMC is fetched from these sources
+	price_oracle_response_for_sources(&mut offchain_state.write(), &[XTFetcher::NAME, MexcFetcher::NAME]);
+	ext.execute_with(|| {
+		TrackedAssets::set(vec![AssetName::PLMC]);
+		run_to_block(6);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		match tx.call {
+			RuntimeCall::Oracle(orml_oracle::Call::feed_values { values }) => {
+				assert_eq!(values.len(), 1);
+				assert_eq!(values[0].0, 3344);
+			},
+			_ => panic!("Unexpected call"),
+		}
+		assert_eq!(OracleOcw::tracked_keys(), vec![3344]);
+	});
+}
+
+/// The `(volume weighted price sum, total volume)` answer of a source for a recorded payload.
+fn recorded_answer<F: FetchPrice>(body: &[u8]) -> (FixedU128, FixedU128) {
+	let candles = F::parse_body(std::str::from_utf8(body).unwrap()).unwrap();
+	candles.into_iter().fold((FixedU128::zero(), FixedU128::zero()), |(price_sum, volume), candle| {
+		(price_sum + candle.vwp(), volume + candle.volume)
+	})
+}
+
+fn recorded_usdt_answers() -> Vec<(FixedU128, FixedU128)> {
+	vec![
+		recorded_answer::<KrakenFetcher>(KRAKEN_RESPONSES[0].1),
+		recorded_answer::<BitFinexFetcher>(BITFINEX_RESPONSES[0].1),
+		recorded_answer::<BitStampFetcher>(BITSTAMP_RESPONSES[0].1),
+		recorded_answer::<CoinbaseFetcher>(COINBASE_RESPONSES[0].1),
+	]
+}
+
+fn recorded_dot_answers() -> Vec<(FixedU128, FixedU128)> {
+	vec![
+		recorded_answer::<KrakenFetcher>(KRAKEN_RESPONSES[2].1),
+		recorded_answer::<BitFinexFetcher>(BITFINEX_RESPONSES[2].1),
+		recorded_answer::<CoinbaseFetcher>(COINBASE_RESPONSES[1].1),
+	]
+}
+
+fn answer_price((price_sum, volume): (FixedU128, FixedU128)) -> FixedU128 {
+	price_sum / volume
+}
+
+#[test]
+fn volume_weighted_aggregation_of_recorded_payloads() {
+	let price = aggregate(AggregationStrategy::VolumeWeighted, 1, recorded_usdt_answers()).unwrap();
+	assert_close_enough(price, FixedU128::from_float(1.000154206100002620));
+}
+
+#[test]
+fn median_aggregation_of_recorded_payloads() {
+	let answers = recorded_usdt_answers();
+	// Coinbase < BitStamp < Kraken < BitFinex
+	let expected = (answer_price(answers[2]) + answer_price(answers[0])) / FixedU128::from_u32(2);
+	assert_eq!(aggregate(AggregationStrategy::Median, 1, answers), Ok(expected));
+}
+
+#[test]
+fn outlier_aggregation_of_recorded_payloads() {
+	let answers = recorded_usdt_answers();
+	let strategy = AggregationStrategy::VolumeWeightedWithoutOutliers { max_deviations: FixedU128::from_u32(3) };
+
+	// BitFinex quotes USDT about 6 bps above the other exchanges
+	let without_bitfinex = vec![answers[0], answers[2], answers[3]];
+	assert_eq!(
+		aggregate(strategy, 3, answers.clone()),
+		aggregate(AggregationStrategy::VolumeWeighted, 1, without_bitfinex)
+	);
+	assert_eq!(aggregate(strategy, 4, answers), Err(AggregationError::NoQuorum { agreeing: 3, required: 4 }));
+}
+
+#[test]
+fn outlier_rejection_tolerates_small_deviations_when_most_answers_agree() {
+	let volume = FixedU128::from_u32(1_000);
+	let answer = |price: f64| (volume * FixedU128::from_float(price), volume);
+	let strategy = AggregationStrategy::VolumeWeightedWithoutOutliers { max_deviations: FixedU128::from_u32(3) };
+
+	// The MAD is 0, but an answer 0.5 bps away is not an outlier
+	let answers = vec![answer(1.0), answer(1.0), answer(1.0), answer(1.00005)];
+	assert_eq!(aggregate(strategy, 4, answers.clone()), aggregate(AggregationStrategy::VolumeWeighted, 1, answers));
+
+	// While one 50 bps away still is
+	let answers = vec![answer(1.0), answer(1.0), answer(1.0), answer(1.005)];
+	assert_eq!(aggregate(strategy, 3, answers), Ok(FixedU128::from_u32(1)));
+}
+
+#[test]
+fn garbage_answer_only_skews_volume_weighted_aggregation() {
+	let mut answers = recorded_dot_answers();
+	let fair_price = aggregate(AggregationStrategy::VolumeWeighted, 1, answers.clone()).unwrap();
+	let volume = FixedU128::from_u32(50_000);
+	answers.push((volume * FixedU128::from_float(61.4), volume));
+
+	let skewed_price = aggregate(AggregationStrategy::VolumeWeighted, 1, answers.clone()).unwrap();
+	assert!(skewed_price > FixedU128::from_u32(7));
+
+	let median_price = aggregate(AggregationStrategy::Median, 1, answers.clone()).unwrap();
+	assert!(median_price > FixedU128::from_float(6.13) && median_price < FixedU128::from_float(6.15));
+
+	let strategy = AggregationStrategy::VolumeWeightedWithoutOutliers { max_deviations: FixedU128::from_u32(3) };
+	assert_eq!(aggregate(strategy, 3, answers), Ok(fair_price));
+}
+
+#[test]
+fn quorum_counts_agreeing_sources_under_every_strategy() {
+	let volume = FixedU128::from_u32(1_000);
+	let answer = |price: f64| (volume * FixedU128::from_float(price), volume);
+	let strategies = [
+		AggregationStrategy::VolumeWeighted,
+		AggregationStrategy::Median,
+		AggregationStrategy::VolumeWeightedWithoutOutliers { max_deviations: FixedU128::from_u32(3) },
+	];
+
+	for strategy in strategies {
+		// A single live source is not a quorum
+		assert_eq!(
+			aggregate(strategy, 2, vec![answer(6.14)]),
+			Err(AggregationError::NoQuorum { agreeing: 1, required: 2 })
+		);
+
+		// A garbage answer does not count towards the quorum
+		let answers = vec![answer(6.14), answer(6.14), answer(61.4)];
+		assert_eq!(
+			aggregate(strategy, 3, answers.clone()),
+			Err(AggregationError::NoQuorum { agreeing: 2, required: 3 })
+		);
+		assert!(aggregate(strategy, 2, answers).is_ok());
+	}
+}
+
+#[test]
+fn aggregation_without_volume_fails() {
+	// BitStamp reports no DOT volume
+	let answers = vec![recorded_answer::<BitStampFetcher>(BITSTAMP_RESPONSES[2].1)];
+	assert_eq!(aggregate(AggregationStrategy::Median, 1, answers), Err(AggregationError::NoVolume));
+}
+
+#[test]
+fn twap_weights_prices_by_how_long_they_were_accepted() {
+	let (mut ext, _, _) = new_test_ext_with_offchain_storage();
+	ext.execute_with(|| {
+		assert_eq!(OracleOcw::twap(&10), None);
+
+		Timestamp::set_timestamp(1000);
+		feed_price(10, FixedU128::from_u32(6));
+		assert_eq!(OracleOcw::twap(&10), Some(FixedU128::from_u32(6)));
+
+		Timestamp::set_timestamp(1030);
+		feed_price(10, FixedU128::from_u32(9));
+
+		// 6 for 30 and 9 for 30, the recorded prices span less than the window
+		Timestamp::set_timestamp(1060);
+		assert_eq!(OracleOcw::twap(&10), Some(FixedU128::from_rational(15, 2)));
+
+		// 6 for 10 and 9 for 90 over the 100 long window
+		Timestamp::set_timestamp(1120);
+		assert_eq!(OracleOcw::twap(&10), Some(FixedU128::from_rational(87, 10)));
+
+		// Only 9 over the window
+		Timestamp::set_timestamp(1200);
+		assert_eq!(OracleOcw::twap(&10), Some(FixedU128::from_u32(9)));
+	});
+}
+
+#[test]
+fn twap_observations_are_pruned() {
+	let (mut ext, _, _) = new_test_ext_with_offchain_storage();
+	ext.execute_with(|| {
+		for (timestamp, price) in [(1000, 1), (1050, 2), (1100, 3), (1150, 4)] {
+			Timestamp::set_timestamp(timestamp);
+			feed_price(1984, FixedU128::from_u32(price));
+		}
+		// The price accepted at 1000 is not needed for the window starting at 1050
+		let timestamps = |observations: Vec<TwapObservation<u32>>| {
+			observations.into_iter().map(|observation| observation.timestamp).collect::<Vec<_>>()
+		};
+		assert_eq!(timestamps(TwapObservations::<Test>::get(1984).into_inner()), vec![1050, 1100, 1150]);
+
+		for (timestamp, price) in [(1160, 5), (1170, 6)] {
+			Timestamp::set_timestamp(timestamp);
+			feed_price(1984, FixedU128::from_u32(price));
+		}
+		// At most 4 observations are kept
+		assert_eq!(timestamps(TwapObservations::<Test>::get(1984).into_inner()), vec![1100, 1150, 1160, 1170]);
+
+		// Only the assets fetched by the offchain worker are tracked
+		feed_price(42, FixedU128::from_u32(1));
+		assert!(TwapObservations::<Test>::get(42).is_empty());
+		assert_eq!(OracleOcw::twap(&42), None);
+	});
+}
+
+#[test]
+fn twap_ignores_repeated_oracle_values() {
+	let (mut ext, _, _) = new_test_ext_with_offchain_storage();
+	ext.execute_with(|| {
+		Timestamp::set_timestamp(1000);
+		feed_price(3344, FixedU128::from_rational(4, 10));
+
+		// A single member feeding does not change the oracle value, as 3 values are needed to combine them
+		Timestamp::set_timestamp(1020);
+		let member = Members::get().remove(0);
+		Oracle::feed_values(RuntimeOrigin::signed(member), vec![(3344, FixedU128::from_u32(1))].try_into().unwrap())
+			.unwrap();
+		assert_eq!(TwapObservations::<Test>::get(3344).len(), 1);
+
+		let prices = OracleOcw::prices();
+		let plmc = prices.into_iter().find(|asset_prices| asset_prices.key == 3344).unwrap();
+		assert_eq!(plmc.spot, Some(FixedU128::from_rational(4, 10)));
+		assert_eq!(plmc.twap, Some(FixedU128::from_rational(4, 10)));
+	});
+}
+
+#[test]
+fn feed_values_weight_includes_twap_observations() {
+	use orml_oracle::WeightInfo;
+	type OracleWeights = crate::OracleWeightWithTwap<Test, ()>;
+
+	let observation = OracleOcw::observe_price_weight();
+	assert!(observation.ref_time() > 0 && observation.proof_size() > 0);
+	assert_eq!(
+		OracleWeights::feed_values(3),
+		<() as WeightInfo>::feed_values(3).saturating_add(observation.saturating_mul(3))
+	);
+}
+
+fn test_fetcher_against_real_api<F: FetchPrice>() {
+	for (_asset, pair) in F::default_pairs() {
+		let url = F::get_url(pair);
+		let body = do_request(&url);
 		let data = F::parse_body(&body);
 		assert!(data.is_some());
 	}
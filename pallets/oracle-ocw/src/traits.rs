@@ -13,73 +13,179 @@
 
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
-use super::{AssetName, AssetRequest, OpenCloseVolume, Vec, Zero};
+use super::{
+	types::is_valid_pair, AssetName, AssetRequest, BTreeMap, OpenCloseVolume, SourceConfig, SourceName, Vec, Zero,
+};
+use alloc::string::String;
 
 use sp_runtime::{
 	offchain::{
 		http::{self, PendingRequest},
 		Duration,
 	},
-	FixedU128, Saturating,
+	FixedU128, Percent, Saturating,
 };
 
-pub(crate) trait FetchPrice {
-	fn get_moving_average(assets: Vec<AssetName>, timeout: u64) -> Vec<(AssetName, FixedU128, FixedU128)> {
-		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(timeout));
-		let asset_requests = assets
-			.into_iter()
-			.filter_map(|asset| {
-				let url = Self::get_url(asset);
-				if url.is_empty() {
-					return None;
-				}
-				let request = http::Request::get(url);
+/// A price source the offchain worker fetches candles from, e.g. an exchange.
+pub trait FetchPrice {
+	/// Unique name of the source, key of its [`SourceConfig`] in offchain storage.
+	const NAME: &'static [u8];
 
-				if let Ok(req) = request.deadline(deadline).send() {
-					return Some(AssetRequest { asset, id: req.id });
-				}
-				None
-			})
-			.collect::<Vec<AssetRequest>>();
-
-		let request: Vec<PendingRequest> = asset_requests.iter().map(|r| PendingRequest { id: r.id }).collect();
-		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(timeout));
-		let maybe_responses = PendingRequest::try_wait_all(request, deadline);
-		maybe_responses
-			.into_iter()
-			.zip(asset_requests.into_iter().map(|r| r.asset))
-			.filter_map(|(maybe_response, asset)| {
-				if let Ok(Ok(response)) = maybe_response {
-					if response.code != 200 {
-						return None;
-					}
-					return Some((asset, response));
-				}
-				None
-			})
-			.filter_map(|(asset, response)| {
-				let body = response.body().collect::<Vec<u8>>();
-				if let Ok(body_str) = sp_std::str::from_utf8(&body) {
-					if let Some(ocv_data) = Self::parse_body(body_str) {
-						return Some((asset, ocv_data));
-					}
-				}
-				None
-			})
-			.filter_map(|(asset, ocv_data)| {
-				let (w_price_sum, total_vol) =
-					ocv_data.into_iter().fold((FixedU128::zero(), FixedU128::zero()), |(w_price_sum, vol_sum), ocv| {
-						(w_price_sum + ocv.vwp(), vol_sum.saturating_add(ocv.volume))
-					});
-				if total_vol.is_zero() {
+	/// Trading pairs queried for each asset when no configuration is stored for the source.
+	fn default_pairs() -> Vec<(AssetName, &'static str)>;
+
+	/// Configuration used when no configuration is stored for the source.
+	fn default_config() -> SourceConfig {
+		SourceConfig {
+			enabled: true,
+			weight: Percent::from_percent(100),
+			pairs: Self::default_pairs().into_iter().map(|(asset, pair)| (asset, pair.as_bytes().to_vec())).collect(),
+			endpoint: None,
+		}
+	}
+
+	/// Fetch the candles of every asset that has a trading pair in `config`, and scale the
+	/// volume weighted price sum and total volume by the source weight.
+	fn fetch_with_config(
+		assets: &[AssetName],
+		config: &SourceConfig,
+		timeout: u64,
+	) -> Vec<(AssetName, FixedU128, FixedU128)> {
+		fetch_candles(assets, config, timeout, |pair| Some(Self::get_url(pair)), Self::parse_body)
+	}
+
+	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>>;
+
+	/// Url of the candles endpoint for a trading pair of the source.
+	fn get_url(pair: &str) -> String;
+}
+
+/// Fetch the candles of every asset that has a trading pair in `config` from the url built by
+/// `get_url`, and scale the volume weighted price sum and total volume by the source weight.
+/// Pairs that are not valid in a url are skipped.
+pub fn fetch_candles(
+	assets: &[AssetName],
+	config: &SourceConfig,
+	timeout: u64,
+	get_url: impl Fn(&str) -> Option<String>,
+	parse_body: impl Fn(&str) -> Option<Vec<OpenCloseVolume>>,
+) -> Vec<(AssetName, FixedU128, FixedU128)> {
+	if !config.enabled || config.weight.is_zero() {
+		return Vec::new();
+	}
+	let requests = assets
+		.iter()
+		.filter_map(|asset| {
+			let pair = sp_std::str::from_utf8(config.pairs.get(asset)?).ok()?;
+			if !is_valid_pair(pair) {
+				return None;
+			}
+			Some((*asset, get_url(pair)?))
+		})
+		.collect::<Vec<(AssetName, String)>>();
+	let weight = FixedU128::from(config.weight);
+	get_moving_average(requests, timeout, parse_body)
+		.into_iter()
+		.map(|(asset, w_price_sum, total_vol)| {
+			(asset, weight.saturating_mul(w_price_sum), weight.saturating_mul(total_vol))
+		})
+		.collect()
+}
+
+fn get_moving_average(
+	requests: Vec<(AssetName, String)>,
+	timeout: u64,
+	parse_body: impl Fn(&str) -> Option<Vec<OpenCloseVolume>>,
+) -> Vec<(AssetName, FixedU128, FixedU128)> {
+	let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(timeout));
+	let asset_requests = requests
+		.into_iter()
+		.filter_map(|(asset, url)| {
+			let request = http::Request::get(&url);
+			if let Ok(req) = request.deadline(deadline).send() {
+				return Some(AssetRequest { asset, id: req.id });
+			}
+			None
+		})
+		.collect::<Vec<AssetRequest>>();
+
+	let request: Vec<PendingRequest> = asset_requests.iter().map(|r| PendingRequest { id: r.id }).collect();
+	let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(timeout));
+	let maybe_responses = PendingRequest::try_wait_all(request, deadline);
+	maybe_responses
+		.into_iter()
+		.zip(asset_requests.into_iter().map(|r| r.asset))
+		.filter_map(|(maybe_response, asset)| {
+			if let Ok(Ok(response)) = maybe_response {
+				if response.code != 200 {
 					return None;
 				}
-				Some((asset, w_price_sum, total_vol))
-			})
-			.collect::<Vec<(AssetName, FixedU128, FixedU128)>>()
+				return Some((asset, response));
+			}
+			None
+		})
+		.filter_map(|(asset, response)| {
+			let body = response.body().collect::<Vec<u8>>();
+			if let Ok(body_str) = sp_std::str::from_utf8(&body) {
+				if let Some(ocv_data) = parse_body(body_str) {
+					return Some((asset, ocv_data));
+				}
+			}
+			None
+		})
+		.filter_map(|(asset, ocv_data)| {
+			let (w_price_sum, total_vol) =
+				ocv_data.into_iter().fold((FixedU128::zero(), FixedU128::zero()), |(w_price_sum, vol_sum), ocv| {
+					(w_price_sum + ocv.vwp(), vol_sum.saturating_add(ocv.volume))
+				});
+			if total_vol.is_zero() {
+				return None;
+			}
+			Some((asset, w_price_sum, total_vol))
+		})
+		.collect::<Vec<(AssetName, FixedU128, FixedU128)>>()
+}
+
+/// The list of price sources of the runtime, implemented for tuples of [`FetchPrice`].
+pub trait PriceSources {
+	/// Configuration of every source of the list, used when none is stored.
+	fn default_configs() -> BTreeMap<SourceName, SourceConfig>;
+
+	/// Fetch the prices of `assets` from every source of the list that has a configuration in
+	/// `configs`. Returns one `(asset, volume weighted price sum, total volume)` per answer.
+	fn fetch(
+		assets: &[AssetName],
+		configs: &BTreeMap<SourceName, SourceConfig>,
+		timeout: u64,
+	) -> Vec<(AssetName, FixedU128, FixedU128)>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(12)]
+#[tuple_types_custom_trait_bound(FetchPrice)]
+impl PriceSources for Tuple {
+	fn default_configs() -> BTreeMap<SourceName, SourceConfig> {
+		let mut configs = BTreeMap::new();
+		for_tuples!( #( configs.insert(Tuple::NAME.to_vec(), Tuple::default_config()); )* );
+		configs
 	}
 
-	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>>;
+	fn fetch(
+		assets: &[AssetName],
+		configs: &BTreeMap<SourceName, SourceConfig>,
+		timeout: u64,
+	) -> Vec<(AssetName, FixedU128, FixedU128)> {
+		let mut prices = Vec::new();
+		for_tuples!( #(
+			if let Some(config) = configs.get(Tuple::NAME) {
+				prices.extend(Tuple::fetch_with_config(assets, config, timeout));
+			}
+		)* );
+		prices
+	}
+}
 
-	fn get_url(name: AssetName) -> &'static str;
+/// Source of time-weighted average prices.
+pub trait ProvideTwap<Key, Price> {
+	/// Time-weighted average price of the asset identified by `key`, if any price was recorded for it.
+	fn twap(key: &Key) -> Option<Price>;
 }
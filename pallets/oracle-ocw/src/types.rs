@@ -13,23 +13,178 @@
 
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
-use super::{FetchPrice, FixedU128, LOG_TARGET, NUMBER_OF_CANDLES};
-use core::{ops::Mul, str::FromStr};
+use super::{BTreeMap, FetchPrice, FixedU128, LOG_TARGET, NUMBER_OF_CANDLES};
+use alloc::{format, string::String};
+use core::{fmt, ops::Mul, str::FromStr};
 use heapless::{LinearMap, Vec as HVec};
-use parity_scale_codec::{Decode, Encode};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use serde::Deserialize;
+use serde::{
+	de::{IgnoredAny, MapAccess, Visitor},
+	Deserialize, Deserializer,
+};
 use sp_core::{offchain::HttpRequestId as RequestId, RuntimeDebug};
-use sp_runtime::{FixedPointNumber, Saturating};
-use sp_std::vec::Vec;
+use sp_runtime::{
+	traits::{AtLeast32Bit, SaturatedConversion},
+	FixedPointNumber, Percent, Saturating,
+};
+use sp_std::{vec, vec::Vec};
 use substrate_fixed::{traits::ToFixed, types::U100F28};
 
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord, Encode, Decode, TypeInfo)]
-pub enum AssetName {
-	USDT,
-	USDC,
-	DOT,
-	PLMC,
+/// Ticker of an asset whose price is fetched, padded with zeros to 8 bytes. Funding assets are
+/// matched to it through the oracle key of the funding asset registry.
+#[derive(Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct AssetName(pub [u8; 8]);
+
+impl AssetName {
+	pub const DOT: Self = Self::from_ticker("DOT");
+	pub const PLMC: Self = Self::from_ticker("PLMC");
+	pub const USDC: Self = Self::from_ticker("USDC");
+	pub const USDT: Self = Self::from_ticker("USDT");
+
+	/// Panics if `ticker` is longer than 8 bytes. Use [`Self::try_from_ticker`] for untrusted input.
+	pub const fn from_ticker(ticker: &str) -> Self {
+		let bytes = ticker.as_bytes();
+		assert!(bytes.len() <= 8, "Asset tickers are at most 8 bytes long");
+		let mut name = [0u8; 8];
+		let mut i = 0;
+		while i < bytes.len() {
+			name[i] = bytes[i];
+			i += 1;
+		}
+		Self(name)
+	}
+
+	pub fn try_from_ticker(ticker: &str) -> Option<Self> {
+		(!ticker.is_empty() && ticker.len() <= 8).then(|| Self::from_ticker(ticker))
+	}
+
+	pub fn ticker(&self) -> &str {
+		let len = self.0.iter().position(|byte| *byte == 0).unwrap_or(self.0.len());
+		core::str::from_utf8(&self.0[..len]).unwrap_or_default()
+	}
+}
+
+impl fmt::Debug for AssetName {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.ticker())
+	}
+}
+
+/// Name of a price source, see [`FetchPrice::NAME`].
+pub type SourceName = Vec<u8>;
+
+/// Settings of a price source. The offchain worker reads them from the offchain local storage
+/// under [`crate::SOURCES_CONFIG_KEY`], and falls back to [`FetchPrice::default_config`].
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct SourceConfig {
+	/// Whether prices are fetched from the source.
+	pub enabled: bool,
+	/// Share of the volume reported by the source that is counted when combining prices.
+	pub weight: Percent,
+	/// Trading pair queried for each asset. Assets without a pair are not fetched from the source.
+	pub pairs: BTreeMap<AssetName, Vec<u8>>,
+	/// Where the candles are fetched from, for sources that are not part of the runtime's
+	/// `PriceSources`. Ignored for the sources of the runtime.
+	pub endpoint: Option<SourceEndpoint>,
+}
+
+/// Whether `pair` can be put in the url of a price source as is. Pairs are path or query
+/// segments, so they must not contain url delimiters nor whitespace.
+pub fn is_valid_pair(pair: &str) -> bool {
+	!pair.is_empty() && !pair.chars().any(|c| matches!(c, '/' | '?' | '&' | '#') || c.is_whitespace())
+}
+
+/// Endpoint of a price source configured by the node instead of the runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct SourceEndpoint {
+	/// Url of the candles endpoint, in which [`SourceEndpoint::PAIR_PLACEHOLDER`] is replaced by
+	/// the trading pair.
+	pub url_template: Vec<u8>,
+	/// Format of the responses of the endpoint.
+	pub parser: ParserKind,
+}
+
+impl SourceEndpoint {
+	pub const PAIR_PLACEHOLDER: &'static str = "{pair}";
+
+	/// Url of the candles endpoint for `pair`, if the template is valid.
+	pub fn url(&self, pair: &str) -> Option<String> {
+		let template = core::str::from_utf8(&self.url_template).ok()?;
+		template.contains(Self::PAIR_PLACEHOLDER).then(|| template.replace(Self::PAIR_PLACEHOLDER, pair))
+	}
+}
+
+/// Response format of a configured price source, named after the source that answers in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum ParserKind {
+	Kraken,
+	BitFinex,
+	BitStamp,
+	Coinbase,
+	XT,
+	Mexc,
+}
+
+impl ParserKind {
+	pub const ALL: [Self; 6] = [Self::Kraken, Self::BitFinex, Self::BitStamp, Self::Coinbase, Self::XT, Self::Mexc];
+
+	/// Name of the runtime source answering in this format, see [`FetchPrice::NAME`].
+	pub fn source_name(&self) -> &'static [u8] {
+		match self {
+			Self::Kraken => KrakenFetcher::NAME,
+			Self::BitFinex => BitFinexFetcher::NAME,
+			Self::BitStamp => BitStampFetcher::NAME,
+			Self::Coinbase => CoinbaseFetcher::NAME,
+			Self::XT => XTFetcher::NAME,
+			Self::Mexc => MexcFetcher::NAME,
+		}
+	}
+
+	pub fn from_source_name(name: &[u8]) -> Option<Self> {
+		Self::ALL.into_iter().find(|parser| parser.source_name() == name)
+	}
+
+	pub fn parse_body(&self, body: &str) -> Option<Vec<OpenCloseVolume>> {
+		match self {
+			Self::Kraken => KrakenFetcher::parse_body(body),
+			Self::BitFinex => BitFinexFetcher::parse_body(body),
+			Self::BitStamp => BitStampFetcher::parse_body(body),
+			Self::Coinbase => CoinbaseFetcher::parse_body(body),
+			Self::XT => XTFetcher::parse_body(body),
+			Self::Mexc => MexcFetcher::parse_body(body),
+		}
+	}
+}
+
+/// How the answers of the different price sources are combined into a single price.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregationStrategy {
+	/// Volume weighted average of every answer.
+	VolumeWeighted,
+	/// Median of the volume weighted average price answered by each source.
+	Median,
+	/// Volume weighted average of the answers whose price is within `max_deviations` median
+	/// absolute deviations (MAD) of the median price. The other answers are rejected as outliers.
+	VolumeWeightedWithoutOutliers { max_deviations: FixedU128 },
+}
+
+/// A price accepted by the oracle, used to compute the time-weighted average price of its asset.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TwapObservation<Moment> {
+	pub price: FixedU128,
+	/// Moment the oracle accepted the price.
+	pub timestamp: Moment,
+	/// Sum of every previously accepted price, multiplied by the time it remained the latest one.
+	pub cumulative_price: FixedU128,
+}
+
+impl<Moment: AtLeast32Bit + Copy> TwapObservation<Moment> {
+	/// The cumulative price at `timestamp`, assuming no other price was accepted after this one.
+	pub fn cumulative_price_at(&self, timestamp: Moment) -> FixedU128 {
+		let elapsed: u128 = timestamp.saturating_sub(self.timestamp).saturated_into();
+		self.cumulative_price.saturating_add(self.price.saturating_mul(FixedU128::saturating_from_integer(elapsed)))
+	}
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -39,7 +194,7 @@ pub(crate) struct AssetRequest {
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct OpenCloseVolume {
+pub struct OpenCloseVolume {
 	pub high: FixedU128,
 	pub low: FixedU128,
 	pub close: FixedU128,
@@ -96,16 +251,45 @@ where
 	Ok(result)
 }
 
-#[derive(Default, Deserialize)]
+struct KrakenCandles(Vec<OpenCloseVolume>);
+impl<'de> Deserialize<'de> for KrakenCandles {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_hloc_kraken(deserializer).map(KrakenCandles)
+	}
+}
+
+/// The candles are keyed by the name of the queried pair, so any key other than `last` is
+/// read as the candle list.
+#[derive(Default)]
 struct KrakenResult {
-	#[serde(alias = "USDTZUSD")]
-	#[serde(alias = "DOTUSD")]
-	#[serde(alias = "USDCUSD")]
-	#[serde(deserialize_with = "deserialize_hloc_kraken")]
 	data: Vec<OpenCloseVolume>,
-	#[serde(skip)]
-	_last: Vec<u8>,
 }
+impl<'de> Deserialize<'de> for KrakenResult {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct KrakenResultVisitor;
+		impl<'de> Visitor<'de> for KrakenResultVisitor {
+			type Value = KrakenResult;
+
+			fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+				formatter.write_str("a Kraken OHLC result")
+			}
+
+			fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+				let mut result = KrakenResult::default();
+				while let Some(key) = map.next_key::<&str>()? {
+					if key == "last" {
+						map.next_value::<IgnoredAny>()?;
+					} else {
+						result.data = map.next_value::<KrakenCandles>()?.0;
+					}
+				}
+				Ok(result)
+			}
+		}
+		deserializer.deserialize_map(KrakenResultVisitor)
+	}
+}
+
 #[derive(Deserialize)]
 struct KrakenResponse {
 	#[serde(skip)]
@@ -113,8 +297,10 @@ struct KrakenResponse {
 	#[serde(default)]
 	result: KrakenResult,
 }
-pub(crate) struct KrakenFetcher;
+pub struct KrakenFetcher;
 impl FetchPrice for KrakenFetcher {
+	const NAME: &'static [u8] = b"kraken";
+
 	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>> {
 		let maybe_response = serde_json_core::from_str::<KrakenResponse>(body);
 		if let Err(e) = maybe_response {
@@ -125,18 +311,19 @@ impl FetchPrice for KrakenFetcher {
 		Some(response.0.result.data.into_iter().rev().take(NUMBER_OF_CANDLES).collect())
 	}
 
-	fn get_url(name: AssetName) -> &'static str {
-		match name {
-			AssetName::USDT => "https://api.kraken.com/0/public/OHLC?pair=USDTZUSD&interval=1",
-			AssetName::DOT => "https://api.kraken.com/0/public/OHLC?pair=DOTUSD&interval=1",
-			AssetName::USDC => "https://api.kraken.com/0/public/OHLC?pair=USDCUSD&interval=1",
-			AssetName::PLMC => "",
-		}
+	fn default_pairs() -> Vec<(AssetName, &'static str)> {
+		vec![(AssetName::USDT, "USDTZUSD"), (AssetName::DOT, "DOTUSD"), (AssetName::USDC, "USDCUSD")]
+	}
+
+	fn get_url(pair: &str) -> String {
+		format!("https://api.kraken.com/0/public/OHLC?pair={}&interval=1", pair)
 	}
 }
 
-pub(crate) struct BitFinexFetcher;
+pub struct BitFinexFetcher;
 impl FetchPrice for BitFinexFetcher {
+	const NAME: &'static [u8] = b"bitfinex";
+
 	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>> {
 		let maybe_response = serde_json_core::from_str::<HVec<(u64, f64, f64, f64, f64, f64), NUMBER_OF_CANDLES>>(body);
 		if let Err(e) = maybe_response {
@@ -153,13 +340,12 @@ impl FetchPrice for BitFinexFetcher {
 		Some(data)
 	}
 
-	fn get_url(name: AssetName) -> &'static str {
-		match name {
-			AssetName::USDT => "https://api-pub.bitfinex.com/v2/candles/trade%3A1m%3AtUSTUSD/hist?limit=15",
-			AssetName::DOT => "https://api-pub.bitfinex.com/v2/candles/trade%3A1m%3AtDOTUSD/hist?limit=15",
-			AssetName::USDC => "https://api-pub.bitfinex.com/v2/candles/trade%3A1m%3AtUDCUSD/hist?limit=15",
-			_ => "",
-		}
+	fn default_pairs() -> Vec<(AssetName, &'static str)> {
+		vec![(AssetName::USDT, "USTUSD"), (AssetName::DOT, "DOTUSD"), (AssetName::USDC, "UDCUSD")]
+	}
+
+	fn get_url(pair: &str) -> String {
+		format!("https://api-pub.bitfinex.com/v2/candles/trade%3A1m%3At{}/hist?limit=15", pair)
 	}
 }
 
@@ -202,8 +388,10 @@ struct BitStampResponse {
 	data: BitStampResult,
 }
 
-pub(crate) struct BitStampFetcher;
+pub struct BitStampFetcher;
 impl FetchPrice for BitStampFetcher {
+	const NAME: &'static [u8] = b"bitstamp";
+
 	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>> {
 		let maybe_response = serde_json_core::from_str::<BitStampResponse>(body);
 		if let Err(e) = maybe_response {
@@ -218,18 +406,19 @@ impl FetchPrice for BitStampFetcher {
 		Some(response.0.data.ohlc.into_iter().rev().collect())
 	}
 
-	fn get_url(name: AssetName) -> &'static str {
-		match name {
-			AssetName::USDT => "https://www.bitstamp.net/api/v2/ohlc/usdtusd/?step=60&limit=15",
-			AssetName::DOT => "https://www.bitstamp.net/api/v2/ohlc/dotusd/?step=60&limit=15",
-			AssetName::USDC => "https://www.bitstamp.net/api/v2/ohlc/usdcusd/?step=60&limit=15",
-			AssetName::PLMC => "",
-		}
+	fn default_pairs() -> Vec<(AssetName, &'static str)> {
+		vec![(AssetName::USDT, "usdtusd"), (AssetName::DOT, "dotusd"), (AssetName::USDC, "usdcusd")]
+	}
+
+	fn get_url(pair: &str) -> String {
+		format!("https://www.bitstamp.net/api/v2/ohlc/{}/?step=60&limit=15", pair)
 	}
 }
 
-pub(crate) struct CoinbaseFetcher;
+pub struct CoinbaseFetcher;
 impl FetchPrice for CoinbaseFetcher {
+	const NAME: &'static [u8] = b"coinbase";
+
 	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>> {
 		let maybe_response = serde_json_core::from_str::<HVec<(u64, f64, f64, f64, f64, f64), 1000>>(body);
 		if let Err(e) = maybe_response {
@@ -250,12 +439,12 @@ impl FetchPrice for CoinbaseFetcher {
 		Some(data)
 	}
 
-	fn get_url(name: AssetName) -> &'static str {
-		match name {
-			AssetName::USDT => "https://api.exchange.coinbase.com/products/USDT-USD/candles?granularity=60",
-			AssetName::DOT => "https://api.exchange.coinbase.com/products/DOT-USD/candles?granularity=60",
-			_ => "",
-		}
+	fn default_pairs() -> Vec<(AssetName, &'static str)> {
+		vec![(AssetName::USDT, "USDT-USD"), (AssetName::DOT, "DOT-USD")]
+	}
+
+	fn get_url(pair: &str) -> String {
+		format!("https://api.exchange.coinbase.com/products/{}/candles?granularity=60", pair)
 	}
 }
 
@@ -295,8 +484,10 @@ struct XTResponse {
 	#[serde(deserialize_with = "deserialize_hloc_xt")]
 	result: Vec<OpenCloseVolume>,
 }
-pub(crate) struct XTFetcher;
+pub struct XTFetcher;
 impl FetchPrice for XTFetcher {
+	const NAME: &'static [u8] = b"xt";
+
 	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>> {
 		let maybe_response = serde_json_core::from_str::<XTResponse>(body);
 		if let Err(e) = maybe_response {
@@ -308,16 +499,19 @@ impl FetchPrice for XTFetcher {
 		Some(response.0.result)
 	}
 
-	fn get_url(name: AssetName) -> &'static str {
-		match name {
-			AssetName::PLMC => "https://sapi.xt.com/v4/public/kline?symbol=plmc_usdt&interval=30m&limit=10",
-			_ => "",
-		}
+	fn default_pairs() -> Vec<(AssetName, &'static str)> {
+		vec![(AssetName::PLMC, "plmc_usdt")]
+	}
+
+	fn get_url(pair: &str) -> String {
+		format!("https://sapi.xt.com/v4/public/kline?symbol={}&interval=30m&limit=10", pair)
 	}
 }
 
-pub(crate) struct MexcFetcher;
+pub struct MexcFetcher;
 impl FetchPrice for MexcFetcher {
+	const NAME: &'static [u8] = b"mexc";
+
 	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>> {
 		let maybe_response =
 			serde_json_core::from_str::<HVec<(u64, &str, &str, &str, &str, &str, u64, &str), 10>>(body);
@@ -334,10 +528,11 @@ impl FetchPrice for MexcFetcher {
 		Some(data)
 	}
 
-	fn get_url(name: AssetName) -> &'static str {
-		match name {
-			AssetName::PLMC => "https://api.mexc.com/api/v3/klines?symbol=PLMCUSDT&interval=30m&limit=10",
-			_ => "",
-		}
+	fn default_pairs() -> Vec<(AssetName, &'static str)> {
+		vec![(AssetName::PLMC, "PLMCUSDT")]
+	}
+
+	fn get_url(pair: &str) -> String {
+		format!("https://api.mexc.com/api/v3/klines?symbol={}&interval=30m&limit=10", pair)
 	}
 }
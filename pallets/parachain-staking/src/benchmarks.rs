@@ -21,7 +21,7 @@
 //! Benchmarking
 use crate::{
 	AwardedPts, BalanceOf, Call, CandidateBondLessRequest, Config, DelegationAction, Pallet, ParachainBondConfig,
-	ParachainBondInfo, Points, Range, RewardPayment, Round, ScheduledRequest, Staked, TopDelegations,
+	ParachainBondInfo, Points, Range, RewardPayment, Round, ScheduledRequest, SlashingInfo, Staked, TopDelegations,
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::traits::{
@@ -189,6 +189,13 @@ benchmarks! {
 		assert_eq!(Pallet::<T>::round().length, 1200u32);
 	}
 
+	set_slashing_config {
+		let config = SlashingInfo { window: 3, fraction: Perbill::from_percent(10) };
+	}: _(RawOrigin::Root, config)
+	verify {
+		assert_eq!(Pallet::<T>::slashing_config(), config);
+	}
+
 	// USER DISPATCHABLES
 
 	join_candidates {
@@ -661,6 +668,99 @@ benchmarks! {
 		);
 	}
 
+	redelegate {
+		// x is the delegation count of both the source and the target candidate
+		let x in 1..(<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get()
+		+ <<T as Config>::MaxBottomDelegationsPerCandidate as Get<u32>>::get());
+		// y is the auto-compounding delegation count of the target candidate after the move
+		let y in 1..(<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get()
+		+ <<T as Config>::MaxBottomDelegationsPerCandidate as Get<u32>>::get());
+		// z is the delegation count of the delegator
+		let z in 1..(<<T as Config>::MaxDelegationsPerDelegator as Get<u32>>::get() - 1u32);
+		let from: T::AccountId = create_funded_collator::<T>(
+			"collator",
+			USER_SEED,
+			0u32.into(),
+			true,
+			1u32
+		)?;
+		let to: T::AccountId = create_funded_collator::<T>(
+			"collator",
+			USER_SEED - 1,
+			0u32.into(),
+			true,
+			2u32
+		)?;
+		let mut collators: Vec<T::AccountId> = Vec::new();
+		for i in 1..z {
+			let collator = create_funded_collator::<T>(
+				"collator",
+				USER_SEED - 1 - i,
+				0u32.into(),
+				true,
+				i + 2u32
+			)?;
+			collators.push(collator);
+		}
+		let bond = min_delegator_stk::<T>();
+		let (caller, _) = create_funded_user::<T>("caller", USER_SEED, bond * (z + 2u32).into());
+		let mut del_del_count = 0u32;
+		for collator in collators {
+			Pallet::<T>::delegate(
+				RawOrigin::Signed(caller.clone()).into(), collator, bond, 0u32, del_del_count
+			)?;
+			del_del_count += 1u32;
+		}
+		Pallet::<T>::delegate_with_auto_compound(
+			RawOrigin::Signed(caller.clone()).into(),
+			from.clone(),
+			bond * 3u32.into(),
+			Percent::from_percent(50),
+			0u32,
+			0u32,
+			del_del_count
+		)?;
+		for i in 1..x {
+			let _ = create_funded_delegator::<T>(
+				"from_delegator",
+				USER_SEED + i,
+				0u32.into(),
+				from.clone(),
+				true,
+				i,
+			)?;
+		}
+		// Worst Case is a full target candidate, where the moved bond bumps the lowest top
+		// delegation to the bottom and kicks the lowest bottom delegation
+		// y-1 of them are auto-compounding, the moved delegation makes it y
+		for i in 0..x {
+			let delegator = create_funded_delegator::<T>(
+				"to_delegator",
+				USER_SEED + i,
+				0u32.into(),
+				to.clone(),
+				true,
+				i,
+			)?;
+			if i + 1 < y {
+				Pallet::<T>::set_auto_compound(
+					RawOrigin::Signed(delegator).into(),
+					to.clone(),
+					Percent::from_percent(100),
+					i + 1,
+					1u32,
+				)?;
+			}
+		}
+		let amount = bond * 2u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), from.clone(), to.clone(), amount, x, y, z)
+	verify {
+		let state = Pallet::<T>::delegator_state(&caller).expect("delegator was created, qed");
+		assert_eq!(state.get_bond_amount(&from), Some(bond));
+		assert_eq!(state.get_bond_amount(&to), Some(amount));
+		assert_eq!(Pallet::<T>::delegation_auto_compound(&to, &caller), Percent::from_percent(50));
+	}
+
 	schedule_delegator_bond_less {
 		let collator: T::AccountId = create_funded_collator::<T>(
 			"collator",
@@ -1267,6 +1367,47 @@ benchmarks! {
 	verify {
 		assert_eq!(T::Currency::balance(&collator), original_free_balance + 50u32.into());
 	}
+
+	slash_collator {
+		// x is the total number of delegations of the slashed collator
+		let x in 0..(<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get()
+		+ <<T as Config>::MaxBottomDelegationsPerCandidate as Get<u32>>::get());
+		let collator: T::AccountId = create_funded_collator::<T>(
+			"collator",
+			USER_SEED - 100,
+			0u32.into(),
+			true,
+			1u32,
+		)?;
+		let mut delegators: Vec<T::AccountId> = Vec::new();
+		for i in 0..x {
+			let delegator = create_funded_delegator::<T>(
+				"delegator",
+				USER_SEED + i,
+				min_delegator_stk::<T>(),
+				collator.clone(),
+				false,
+				i,
+			)?;
+			// Worst case: every delegator has a pending decrease that must be clamped after the slash
+			let total = Pallet::<T>::delegator_state(&delegator).expect("delegator was created, qed").total;
+			Pallet::<T>::schedule_delegator_bond_less(
+				RawOrigin::Signed(delegator.clone()).into(),
+				collator.clone(),
+				total - min_delegator_stk::<T>(),
+			)?;
+			delegators.push(delegator);
+		}
+	}: {
+		Pallet::<T>::slash_collator(&collator, Perbill::from_percent(10), 3u32);
+	}
+	verify {
+		assert!(!Pallet::<T>::candidate_info(&collator).expect("collator was slashed, not removed").is_active());
+		for delegator in delegators {
+			let state = Pallet::<T>::delegator_state(&delegator).expect("delegator was slashed, not removed");
+			assert!(state.total - state.less_total >= min_delegator_stk::<T>());
+		}
+	}
 }
 
 #[cfg(test)]
@@ -1329,6 +1470,13 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn bench_set_slashing_config() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_set_slashing_config());
+		});
+	}
+
 	#[test]
 	fn bench_join_candidates() {
 		new_test_ext().execute_with(|| {
@@ -1441,6 +1589,13 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn bench_redelegate() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_redelegate());
+		});
+	}
+
 	#[test]
 	fn bench_schedule_delegator_bond_less() {
 		new_test_ext().execute_with(|| {
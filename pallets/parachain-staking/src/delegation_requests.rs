@@ -21,8 +21,8 @@
 use crate::{
 	auto_compound::AutoCompoundDelegations,
 	pallet::{
-		BalanceOf, CandidateInfo, Config, DelegationScheduledRequests, DelegatorState, Error, Event, Pallet, Round,
-		RoundIndex, Total,
+		BalanceOf, CandidateInfo, Config, DelegationScheduledRequests, DelegatorState, Error, Event, LastRedelegation,
+		Pallet, Round, RoundIndex, Total,
 	},
 	Delegator, DelegatorStatus,
 };
@@ -236,6 +236,7 @@ impl<T: Config> Pallet<T> {
 				<DelegationScheduledRequests<T>>::insert(collator, scheduled_requests);
 				if leaving {
 					<DelegatorState<T>>::remove(&delegator);
+					<LastRedelegation<T>>::remove(&delegator);
 					Self::deposit_event(Event::DelegatorLeft { delegator, unstaked_amount: amount });
 				} else {
 					<DelegatorState<T>>::insert(&delegator, state);
@@ -423,6 +424,7 @@ impl<T: Config> Pallet<T> {
 				<AutoCompoundDelegations<T>>::remove_auto_compound(&bond.owner, &delegator);
 			}
 			<DelegatorState<T>>::remove(&delegator);
+			<LastRedelegation<T>>::remove(&delegator);
 			Self::deposit_event(Event::DelegatorLeft { delegator, unstaked_amount: state.total });
 			return Ok(().into());
 		}
@@ -473,6 +475,7 @@ impl<T: Config> Pallet<T> {
 
 		Self::deposit_event(Event::DelegatorLeft { delegator: delegator.clone(), unstaked_amount });
 		<DelegatorState<T>>::remove(&delegator);
+		<LastRedelegation<T>>::remove(&delegator);
 
 		Ok(().into())
 	}
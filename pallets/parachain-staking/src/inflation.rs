@@ -29,7 +29,7 @@ const SECONDS_PER_YEAR: u32 = 31557600;
 const SECONDS_PER_BLOCK: u32 = 12;
 pub const BLOCKS_PER_YEAR: u32 = SECONDS_PER_YEAR / SECONDS_PER_BLOCK;
 
-fn rounds_per_year<T: Config>() -> u32 {
+pub(crate) fn rounds_per_year<T: Config>() -> u32 {
 	let blocks_per_round = <Pallet<T>>::round().length;
 	BLOCKS_PER_YEAR / blocks_per_round
 }
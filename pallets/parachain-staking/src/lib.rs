@@ -47,6 +47,13 @@
 //!
 //! To revoke a delegation, call `revoke_delegation` with the collator candidate's account.
 //! To leave the set of delegators and revoke all delegations, call `leave_delegators`.
+//! To move bond between two collator candidates without unbonding, call `redelegate`. A delegator
+//! may redelegate at most once every `T::RedelegationCooldown` rounds.
+//!
+//! Selected collators that are not awarded any points for `SlashingInfo::window` consecutive rounds
+//! lose `SlashingInfo::fraction` of their self bond and of each of their delegations to
+//! `T::SlashDestination`, and are forced offline. Slashing is disabled until governance calls
+//! `set_slashing_config`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 // Needed due to empty sections raising the warning
@@ -56,6 +63,8 @@ mod auto_compound;
 mod delegation_requests;
 pub mod inflation;
 pub mod migrations;
+pub mod runtime_api;
+mod slashing;
 pub mod traits;
 pub mod types;
 pub mod weights;
@@ -75,6 +84,7 @@ pub use weights::WeightInfo;
 pub use auto_compound::{AutoCompoundConfig, AutoCompoundDelegations};
 pub use delegation_requests::{CancelledScheduledRequest, DelegationAction, ScheduledRequest};
 pub use pallet::*;
+pub use slashing::SlashingInfo;
 pub use traits::*;
 pub use types::*;
 pub use RoundIndex;
@@ -86,7 +96,7 @@ pub mod pallet {
 		set::OrderedSet,
 		traits::*,
 		types::*,
-		AutoCompoundConfig, AutoCompoundDelegations, InflationInfo, Range, WeightInfo,
+		AutoCompoundConfig, AutoCompoundDelegations, InflationInfo, Range, SlashingInfo, WeightInfo,
 	};
 	use frame_support::{
 		pallet_prelude::*,
@@ -97,6 +107,7 @@ pub mod pallet {
 		},
 	};
 	use frame_system::pallet_prelude::*;
+	use on_slash_vesting::OnSlash;
 	use sp_runtime::{
 		traits::{Saturating, Zero},
 		Perbill, Percent, Permill,
@@ -164,6 +175,10 @@ pub mod pallet {
 		/// Number of rounds after which block authors are rewarded
 		#[pallet::constant]
 		type RewardPaymentDelay: Get<RoundIndex>;
+		/// Number of rounds a delegator must wait between two redelegations. Zero disables the
+		/// cooldown.
+		#[pallet::constant]
+		type RedelegationCooldown: Get<RoundIndex>;
 		/// Minimum number of selected candidates every round
 		#[pallet::constant]
 		type MinSelectedCandidates: Get<u32>;
@@ -196,6 +211,12 @@ pub mod pallet {
 		/// Handler to notify the runtime when a new round begin.
 		/// If you don't need it, you can specify the type `()`.
 		type OnNewRound: OnNewRound;
+		/// The account receiving the funds slashed from collators that stopped producing blocks,
+		/// and from their delegators.
+		type SlashDestination: Get<Self::AccountId>;
+		/// Handler notified of every account slashed, with the amount slashed.
+		/// If you don't need it, you can specify the type `()`.
+		type OnSlash: OnSlash<Self::AccountId, BalanceOf<Self>>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -247,6 +268,8 @@ pub mod pallet {
 		TooLowDelegationCountToAutoCompound,
 		TooLowCandidateAutoCompoundingDelegationCountToAutoCompound,
 		TooLowCandidateAutoCompoundingDelegationCountToDelegate,
+		CannotRedelegateToSameCandidate,
+		RedelegationCooldownNotElapsed,
 	}
 
 	#[pallet::event]
@@ -462,6 +485,32 @@ pub mod pallet {
 			delegator: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// Slashing parameters set.
+		SlashingConfigSet {
+			old: SlashingInfo,
+			new: SlashingInfo,
+		},
+		/// Collator was slashed for not being awarded any points for `missed_rounds` consecutive
+		/// rounds, and forced offline.
+		CollatorSlashed {
+			candidate: T::AccountId,
+			missed_rounds: RoundIndex,
+			bond_slashed: BalanceOf<T>,
+			delegations_slashed: BalanceOf<T>,
+		},
+		/// Delegation was slashed because its collator was slashed.
+		DelegationSlashed {
+			delegator: T::AccountId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Delegator moved part or all of its bond from one candidate to another.
+		Redelegated {
+			delegator: T::AccountId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::hooks]
@@ -472,6 +521,8 @@ pub mod pallet {
 			let mut round = <Round<T>>::get();
 
 			if round.should_update(n) {
+				// slash the collators that did not produce any block in the round that just ended
+				weight = weight.saturating_add(Self::handle_missed_rounds(round.current));
 				// mutate round
 				round.update(n);
 				// notify that new round begin
@@ -639,6 +690,21 @@ pub mod pallet {
 	pub type AwardedPts<T: Config> =
 		StorageDoubleMap<_, Twox64Concat, RoundIndex, Twox64Concat, T::AccountId, RewardPoint, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn slashing_config)]
+	/// Slashing parameters for selected collators that do not produce blocks
+	pub(crate) type SlashingConfig<T: Config> = StorageValue<_, SlashingInfo, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn missed_rounds)]
+	/// Consecutive rounds in which a selected collator was not awarded any points
+	pub(crate) type MissedRounds<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, RoundIndex, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn last_redelegation)]
+	/// Round in which a delegator last redelegated
+	pub(crate) type LastRedelegation<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, RoundIndex, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		/// Initialize balance and register all as collators: `(collator AccountId, balance Amount)`
@@ -988,6 +1054,7 @@ pub mod pallet {
 						// since it is assumed that they were removed incrementally before only the
 						// last delegation was left.
 						<DelegatorState<T>>::remove(&bond.owner);
+						<LastRedelegation<T>>::remove(&bond.owner);
 						let total_bonded =
 							T::Currency::balance_on_hold(&HoldReason::StakingDelegator.into(), &bond.owner);
 						T::Currency::release(
@@ -1034,6 +1101,7 @@ pub mod pallet {
 			<AutoCompoundingDelegations<T>>::remove(&candidate);
 			<TopDelegations<T>>::remove(&candidate);
 			<BottomDelegations<T>>::remove(&candidate);
+			<MissedRounds<T>>::remove(&candidate);
 			let new_total_staked = <Total<T>>::get().saturating_sub(total_backing);
 			<Total<T>>::put(new_total_staked);
 			Self::deposit_event(Event::CandidateLeft {
@@ -1361,6 +1429,55 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set the slashing parameters for selected collators that are not awarded any points
+		/// - a `window` of zero disables slashing
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_slashing_config())]
+		pub fn set_slashing_config(origin: OriginFor<T>, new: SlashingInfo) -> DispatchResultWithPostInfo {
+			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+			let old = <SlashingConfig<T>>::get();
+			ensure!(old != new, Error::<T>::NoWritingSameValue);
+			<SlashingConfig<T>>::put(new);
+			Self::deposit_event(Event::SlashingConfigSet { old, new });
+			Ok(().into())
+		}
+
+		/// Move `amount` of an existing delegation from candidate `from` to candidate `to`,
+		/// without unbonding. The bond is kept on hold and the auto-compound setting of the
+		/// source delegation is carried over if a new delegation is created.
+		/// Neither delegation may have a pending revoke, and the source delegation may not have
+		/// any pending request.
+		/// `candidate_delegation_count` and `candidate_auto_compounding_delegation_count` must be at
+		/// least the delegation count and the auto-compounding delegation count of both candidates.
+		#[pallet::call_index(30)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::redelegate(
+				*candidate_delegation_count,
+				*candidate_auto_compounding_delegation_count,
+				*delegation_count
+			)
+		)]
+		pub fn redelegate(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: BalanceOf<T>,
+			candidate_delegation_count: u32,
+			candidate_auto_compounding_delegation_count: u32,
+			delegation_count: u32,
+		) -> DispatchResultWithPostInfo {
+			let delegator = ensure_signed(origin)?;
+			Self::delegation_redelegate(
+				delegator,
+				from,
+				to,
+				amount,
+				candidate_delegation_count,
+				candidate_auto_compounding_delegation_count,
+				delegation_count,
+			)
+		}
 	}
 
 	/// Represents a payout made via `pay_one_collator_reward`.
@@ -1417,7 +1534,7 @@ pub mod pallet {
 		}
 
 		/// Compute round issuance based on total staked for the given round
-		fn compute_issuance(staked: BalanceOf<T>) -> BalanceOf<T> {
+		pub(crate) fn compute_issuance(staked: BalanceOf<T>) -> BalanceOf<T> {
 			let config = <InflationConfig<T>>::get();
 			let round_issuance = crate::inflation::round_issuance_range::<T>(config.round);
 			// TODO: consider interpolation instead of bounded range
@@ -1430,6 +1547,35 @@ pub mod pallet {
 			}
 		}
 
+		/// Compute the payout of a round with `staked` at stake, using the current collator commission
+		pub(crate) fn compute_delayed_payout(staked: BalanceOf<T>) -> DelayedPayout<BalanceOf<T>> {
+			let total_issuance = Self::compute_issuance(staked);
+
+			// reserve portion of issuance for parachain bond account. In our situation the
+			// percentage will be 0% as we don't want to reserve any issuance for parachain
+			// bond so the logic is commented out
+
+			// let mut left_issuance = total_issuance;
+			// let bond_config = <ParachainBondInfo<T>>::get();
+			// let parachain_bond_reserve = bond_config.percent * total_issuance;
+			// if let Ok(amount_transferred) =
+			// 	T::Currency::transfer(&T::PayMaster::get(), &bond_config.account, parachain_bond_reserve, Preservation::Preserve)
+			// {
+			// 	// update round issuance iff transfer succeeds
+			// 	left_issuance = left_issuance.saturating_sub(amount_transferred);
+			// 	Self::deposit_event(Event::ReservedForParachainBond {
+			// 		account: bond_config.account,
+			// 		value: amount_transferred,
+			// 	});
+			// }
+
+			DelayedPayout {
+				round_issuance: total_issuance,
+				total_staking_reward: total_issuance,
+				collator_commission: <CollatorCommission<T>>::get(),
+			}
+		}
+
 		/// Remove delegation from candidate state
 		/// Amount input should be retrieved from delegator and it informs the storage lookups
 		pub(crate) fn delegator_leaves_candidate(
@@ -1464,31 +1610,7 @@ pub mod pallet {
 				return Weight::zero();
 			}
 			let total_staked = <Staked<T>>::take(round_to_payout);
-			let total_issuance = Self::compute_issuance(total_staked);
-
-			// reserve portion of issuance for parachain bond account. In our situation the
-			// percentage will be 0% as we don't want to reserve any issuance for parachain
-			// bond so the logic is commented out
-
-			// let mut left_issuance = total_issuance;
-			// let bond_config = <ParachainBondInfo<T>>::get();
-			// let parachain_bond_reserve = bond_config.percent * total_issuance;
-			// if let Ok(amount_transferred) =
-			// 	T::Currency::transfer(&T::PayMaster::get(), &bond_config.account, parachain_bond_reserve, Preservation::Preserve)
-			// {
-			// 	// update round issuance iff transfer succeeds
-			// 	left_issuance = left_issuance.saturating_sub(amount_transferred);
-			// 	Self::deposit_event(Event::ReservedForParachainBond {
-			// 		account: bond_config.account,
-			// 		value: amount_transferred,
-			// 	});
-			// }
-
-			let payout = DelayedPayout {
-				round_issuance: total_issuance,
-				total_staking_reward: total_issuance,
-				collator_commission: <CollatorCommission<T>>::get(),
-			};
+			let payout = Self::compute_delayed_payout(total_staked);
 
 			<DelayedPayouts<T>>::insert(round_to_payout, payout);
 			T::WeightInfo::prepare_staking_payouts()
@@ -1795,6 +1917,117 @@ pub mod pallet {
 			state.increase_delegation::<T>(candidate, more)
 		}
 
+		/// Moves `amount` of the delegator's bond from candidate `from` to candidate `to`.
+		/// The bond stays on hold, so the delegator total and the total locked only change if a
+		/// bottom delegation of `to` is kicked by the new delegation.
+		pub(crate) fn delegation_redelegate(
+			delegator: T::AccountId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: BalanceOf<T>,
+			candidate_delegation_count_hint: u32,
+			candidate_auto_compounding_delegation_count_hint: u32,
+			delegation_count_hint: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure!(from != to, Error::<T>::CannotRedelegateToSameCandidate);
+			let mut state = <DelegatorState<T>>::get(&delegator).ok_or(Error::<T>::DelegatorDNE)?;
+			ensure!(state.is_active(), Error::<T>::CannotDelegateIfLeaving);
+			ensure!(
+				delegation_count_hint >= state.delegations.0.len() as u32,
+				Error::<T>::TooLowDelegationCountToDelegate
+			);
+
+			let now = <Round<T>>::get().current;
+			if let Some(last) = <LastRedelegation<T>>::get(&delegator) {
+				ensure!(
+					now >= last.saturating_add(T::RedelegationCooldown::get()),
+					Error::<T>::RedelegationCooldownNotElapsed
+				);
+			}
+
+			// the pending request amounts are tied to the source bond, and a revoked target
+			// delegation must not receive more bond
+			ensure!(
+				!Self::delegation_request_exists(&from, &delegator),
+				Error::<T>::PendingDelegationRequestAlreadyExists
+			);
+			ensure!(!Self::delegation_request_revoke_exists(&to, &delegator), Error::<T>::PendingDelegationRevoke);
+
+			let bond = state.get_bond_amount(&from).ok_or(Error::<T>::DelegationDNE)?;
+			ensure!(!amount.is_zero() && amount <= bond, Error::<T>::InsufficientBalance);
+			let remaining = bond.saturating_sub(amount);
+			let moves_all = remaining.is_zero();
+			ensure!(moves_all || remaining >= T::MinDelegation::get(), Error::<T>::DelegationBelowMin);
+
+			let existing_target_bond = state.get_bond_amount(&to);
+			if existing_target_bond.is_none() {
+				ensure!(amount >= T::MinDelegation::get(), Error::<T>::DelegationBelowMin);
+				let delegation_count = state.delegations.0.len().saturating_sub(moves_all as usize) as u32;
+				ensure!(
+					delegation_count < T::MaxDelegationsPerDelegator::get(),
+					Error::<T>::ExceedMaxDelegationsPerDelegator
+				);
+			}
+
+			let mut from_state = <CandidateInfo<T>>::get(&from).ok_or(Error::<T>::CandidateDNE)?;
+			let mut to_state = <CandidateInfo<T>>::get(&to).ok_or(Error::<T>::CandidateDNE)?;
+			ensure!(
+				candidate_delegation_count_hint >= from_state.delegation_count.max(to_state.delegation_count),
+				Error::<T>::TooLowCandidateDelegationCountToDelegate
+			);
+			let from_auto_compounding_state = <AutoCompoundDelegations<T>>::get_storage(&from);
+			ensure!(
+				candidate_auto_compounding_delegation_count_hint >=
+					from_auto_compounding_state.len().max(<AutoCompoundDelegations<T>>::get_storage(&to).len()),
+				Error::<T>::TooLowCandidateAutoCompoundingDelegationCountToDelegate
+			);
+			let auto_compound = from_auto_compounding_state.get_for_delegator(&delegator).unwrap_or_else(Percent::zero);
+
+			// take the bond out of the source candidate
+			if moves_all {
+				from_state.rm_delegation_if_exists::<T>(&from, delegator.clone(), bond)?;
+				<AutoCompoundDelegations<T>>::remove_auto_compound(&from, &delegator);
+				state.delegations.remove(&Bond::from_owner(from.clone()));
+			} else {
+				from_state.decrease_delegation::<T>(&from, delegator.clone(), bond, amount)?;
+				if let Some(x) = state.delegations.0.iter_mut().find(|x| x.owner == from) {
+					x.amount = remaining;
+				}
+			}
+
+			// and put it into the target candidate
+			let mut less_total_staked = None;
+			if let Some(target_bond) = existing_target_bond {
+				to_state.increase_delegation::<T>(&to, delegator.clone(), target_bond, amount)?;
+				if let Some(x) = state.delegations.0.iter_mut().find(|x| x.owner == to) {
+					x.amount = target_bond.saturating_add(amount);
+				}
+			} else {
+				let (_, kicked) = to_state.add_delegation::<T>(&to, Bond { owner: delegator.clone(), amount })?;
+				less_total_staked = kicked;
+				state.delegations.insert(Bond { owner: to.clone(), amount });
+
+				// carry the auto-compound config over to the new delegation, the target state is read
+				// again as adding the delegation may have kicked an auto-compounding delegation
+				if !auto_compound.is_zero() {
+					let mut auto_compounding_state = <AutoCompoundDelegations<T>>::get_storage(&to);
+					auto_compounding_state.set_for_delegator(delegator.clone(), auto_compound)?;
+					auto_compounding_state.set_storage(&to);
+				}
+			}
+
+			if let Some(less) = less_total_staked {
+				<Total<T>>::put(<Total<T>>::get().saturating_sub(less));
+			}
+			<CandidateInfo<T>>::insert(&from, from_state);
+			<CandidateInfo<T>>::insert(&to, to_state);
+			<DelegatorState<T>>::insert(&delegator, state);
+			<LastRedelegation<T>>::insert(&delegator, now);
+			Self::deposit_event(Event::Redelegated { delegator, from, to, amount });
+
+			Ok(().into())
+		}
+
 		/// Mint a specified reward amount to the beneficiary account. Emits the [Rewarded] event.
 		pub fn mint(amt: BalanceOf<T>, to: T::AccountId) {
 			if let Ok(amount_transferred) =
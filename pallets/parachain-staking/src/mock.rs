@@ -124,6 +124,7 @@ parameter_types! {
 	pub const RevokeDelegationDelay: u32 = 2;
 	pub const DelegationBondLessDelay: u32 = 2;
 	pub const RewardPaymentDelay: u32 = 2;
+	pub const RedelegationCooldown: u32 = 2;
 	pub const MinSelectedCandidates: u32 = GENESIS_NUM_SELECTED_CANDIDATES;
 	pub const MaxTopDelegationsPerCandidate: u32 = 4;
 	pub const MaxBottomDelegationsPerCandidate: u32 = 4;
@@ -132,6 +133,7 @@ parameter_types! {
 	pub const MinDelegatorStk: u128 = 5;
 	pub const MinDelegation: u128 = 3;
 	pub const BlockchainOperationTreasury: AccountId = 1337;
+	pub const SlashDestination: AccountId = 1338;
 }
 impl_opaque_keys! {
 	pub struct MockSessionKeys {
@@ -176,12 +178,15 @@ impl Config for Test {
 	type MonetaryGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
 	type OnCollatorPayout = ();
 	type OnNewRound = ();
+	type OnSlash = ();
 	type PayMaster = BlockchainOperationTreasury;
 	type PayoutCollatorReward = ();
+	type RedelegationCooldown = RedelegationCooldown;
 	type RevokeDelegationDelay = RevokeDelegationDelay;
 	type RewardPaymentDelay = RewardPaymentDelay;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type SlashDestination = SlashDestination;
 	type WeightInfo = ();
 }
 
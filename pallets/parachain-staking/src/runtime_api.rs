@@ -0,0 +1,181 @@
+// Copyright 2019-2022 PureStake Inc.
+
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API exposing staking projections and positions
+
+use crate::{
+	delegation_requests::ScheduledRequest,
+	inflation::rounds_per_year,
+	pallet::{
+		AccountIdOf, AtStake, AwardedPts, BalanceOf, CandidateInfo, Config, DelayedPayouts, Pallet, Points, Staked,
+	},
+	types::{CollatorStatus, DelegatorStatus},
+	RoundIndex,
+};
+use frame_support::traits::Get;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	FixedPointNumber, FixedU128, Perbill, Percent, RuntimeDebug, SaturatedConversion,
+};
+use sp_std::prelude::*;
+
+/// Projected yearly returns of a collator candidate, assuming every selected collator is awarded
+/// the same amount of points and the current stake, inflation and commission settings hold.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CandidateApr<AccountId, Balance> {
+	pub candidate: AccountId,
+	pub status: CollatorStatus,
+	/// Self bond of the candidate
+	pub bond: Balance,
+	/// Self bond + sum of top delegations
+	pub total_counted: Balance,
+	pub delegation_count: u32,
+	/// Whether the candidate is selected to author blocks in the current round
+	pub is_selected: bool,
+	/// Projected yearly return on the self bond, commission included, e.g. `0.12` for 12%
+	pub collator_apr: FixedU128,
+	/// Projected yearly return on a top delegation, after commission
+	pub delegator_apr: FixedU128,
+}
+pub type CandidateAprOf<T> = CandidateApr<AccountIdOf<T>, BalanceOf<T>>;
+
+/// A single delegation of a delegator.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DelegationPosition<AccountId, Balance> {
+	pub candidate: AccountId,
+	pub amount: Balance,
+	pub auto_compound: Percent,
+	/// Pending revoke or decrease request, with the round at which it becomes executable
+	pub scheduled_request: Option<ScheduledRequest<AccountId, Balance>>,
+	/// The earliest round not yet paid out for this delegation, and the estimated reward for it
+	pub next_payout: Option<(RoundIndex, Balance)>,
+}
+
+/// All the staking positions of a delegator.
+#[derive(Clone, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DelegatorInfo<AccountId, Balance> {
+	/// Total balance held for this delegator
+	pub total: Balance,
+	/// Sum of pending revocation amounts + bond less amounts
+	pub less_total: Balance,
+	pub status: DelegatorStatus,
+	pub positions: Vec<DelegationPosition<AccountId, Balance>>,
+}
+pub type DelegatorInfoOf<T> = DelegatorInfo<AccountIdOf<T>, BalanceOf<T>>;
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait ParachainStakingApi<T: Config> {
+		/// Get the projected yearly returns of every collator candidate.
+		fn candidates_apr() -> Vec<CandidateAprOf<T>>;
+
+		/// Get the projected yearly returns of a single collator candidate.
+		fn candidate_apr(candidate: AccountIdOf<T>) -> Option<CandidateAprOf<T>>;
+
+		/// Get the delegations of `delegator`, their pending requests, auto-compound settings and next payouts.
+		fn delegator_info(delegator: AccountIdOf<T>) -> Option<DelegatorInfoOf<T>>;
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn candidates_apr() -> Vec<CandidateAprOf<T>> {
+		<CandidateInfo<T>>::iter_keys().filter_map(Self::candidate_apr).collect()
+	}
+
+	pub fn candidate_apr(candidate: AccountIdOf<T>) -> Option<CandidateAprOf<T>> {
+		let state = Self::candidate_info(&candidate)?;
+
+		// Reward of a single collator and its delegators for one round, as computed by `pay_one_collator_reward`
+		let payout = Self::compute_delayed_payout(<Staked<T>>::get(Self::round().current));
+		let selected = (Self::selected_candidates().len() as u32).max(1);
+		let pct_due = Perbill::from_rational(1u32, selected);
+		let commission = pct_due * (payout.collator_commission * payout.round_issuance);
+		let amt_due = (pct_due * payout.total_staking_reward).saturating_sub(commission);
+
+		let rounds: BalanceOf<T> = rounds_per_year::<T>().into();
+		let annual_apr = |reward: BalanceOf<T>, stake: BalanceOf<T>| {
+			FixedU128::checked_from_rational(
+				reward.saturating_mul(rounds).saturated_into::<u128>(),
+				stake.saturated_into::<u128>(),
+			)
+			.unwrap_or_default()
+		};
+		let collator_reward = Perbill::from_rational(state.bond, state.total_counted) * amt_due;
+
+		Some(CandidateApr {
+			is_selected: Self::is_selected_candidate(&candidate),
+			collator_apr: annual_apr(collator_reward.saturating_add(commission), state.bond),
+			delegator_apr: annual_apr(amt_due, state.total_counted),
+			candidate,
+			status: state.status,
+			bond: state.bond,
+			total_counted: state.total_counted,
+			delegation_count: state.delegation_count,
+		})
+	}
+
+	pub fn delegator_info(delegator: AccountIdOf<T>) -> Option<DelegatorInfoOf<T>> {
+		let state = Self::delegator_state(&delegator)?;
+		let positions = state
+			.delegations
+			.0
+			.iter()
+			.map(|bond| DelegationPosition {
+				candidate: bond.owner.clone(),
+				amount: bond.amount,
+				auto_compound: Self::delegation_auto_compound(&bond.owner, &delegator),
+				scheduled_request: Self::delegation_scheduled_requests(&bond.owner)
+					.into_iter()
+					.find(|request| request.delegator == delegator),
+				next_payout: Self::next_payout(&bond.owner, &delegator),
+			})
+			.collect();
+
+		Some(DelegatorInfo { total: state.total, less_total: state.less_total, status: state.status, positions })
+	}
+
+	/// Find the earliest completed round in which `delegator` was rewardable through `collator` and
+	/// that was not paid out yet, and estimate the reward due. Rounds whose payout is not prepared yet
+	/// are estimated with the current commission.
+	fn next_payout(collator: &AccountIdOf<T>, delegator: &AccountIdOf<T>) -> Option<(RoundIndex, BalanceOf<T>)> {
+		let now = Self::round().current;
+		let first_unpaid = now.saturating_sub(T::RewardPaymentDelay::get()).max(1);
+
+		(first_unpaid..now).find_map(|round| {
+			if !<AtStake<T>>::contains_key(round, collator) {
+				return None;
+			}
+			let snapshot = <AtStake<T>>::get(round, collator);
+			let amount = snapshot.delegations.iter().find(|bond| &bond.owner == delegator)?.amount;
+			let total_points = <Points<T>>::get(round);
+			if total_points.is_zero() {
+				return None;
+			}
+
+			let payout = <DelayedPayouts<T>>::get(round)
+				.unwrap_or_else(|| Self::compute_delayed_payout(<Staked<T>>::get(round)));
+			let pct_due = Perbill::from_rational(<AwardedPts<T>>::get(round, collator), total_points);
+			let commission = pct_due * (payout.collator_commission * payout.round_issuance);
+			let amt_due = (pct_due * payout.total_staking_reward).saturating_sub(commission);
+
+			Some((round, Perbill::from_rational(amount, snapshot.total) * amt_due))
+		})
+	}
+}
@@ -0,0 +1,221 @@
+// Copyright 2019-2022 PureStake Inc.
+
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Slashing of selected collators that stop producing blocks
+
+use crate::{
+	delegation_requests::DelegationAction,
+	pallet::{
+		AwardedPts, BalanceOf, BottomDelegations, CandidateInfo, CandidatePool, Config, DelegationScheduledRequests,
+		DelegatorState, Event, HoldReason, MissedRounds, Pallet, SlashingConfig, TopDelegations, Total,
+	},
+	types::Bond,
+	weights::WeightInfo,
+	RoundIndex,
+};
+use frame_support::{
+	pallet_prelude::Weight,
+	traits::{
+		fungible::MutateHold,
+		tokens::{Fortitude, Precision, Restriction},
+		Get,
+	},
+};
+use on_slash_vesting::OnSlash;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Perbill, RuntimeDebug,
+};
+use sp_std::prelude::*;
+
+/// Slashing parameters for selected collators that are not awarded any points.
+/// Slashing is disabled while `window` is zero.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+pub struct SlashingInfo {
+	/// Number of consecutive rounds a selected collator can go without being awarded any points
+	/// before being slashed. Since a newly selected collator only starts authoring once its session
+	/// keys are active, this should be at least 2.
+	pub window: RoundIndex,
+	/// Fraction of the collator self bond and of each of its delegations that is slashed.
+	pub fraction: Perbill,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Track the collators selected for `round` that were not awarded any points in it, and slash
+	/// the ones that reached `SlashingInfo::window` consecutive missed rounds.
+	/// The count of a collator that is not selected for `round` is reset, so only misses in
+	/// consecutive rounds add up.
+	/// Must be called before `AwardedPts` of `round` are paid out and before the next selection.
+	pub(crate) fn handle_missed_rounds(round: RoundIndex) -> Weight {
+		let selected = Self::selected_candidates();
+		// only collators selected in the previous round can have a count, so this stays bounded
+		// by `TotalSelected`
+		let not_selected =
+			<MissedRounds<T>>::iter_keys().filter(|collator| !selected.contains(collator)).collect::<Vec<_>>();
+		//   read:  SelectedCandidates, SlashingConfig, MissedRounds keys
+		//   write: MissedRounds of each collator not selected anymore
+		let mut weight =
+			T::DbWeight::get().reads_writes(2u64.saturating_add(not_selected.len() as u64), not_selected.len() as u64);
+		for collator in not_selected {
+			<MissedRounds<T>>::remove(&collator);
+		}
+
+		let config = <SlashingConfig<T>>::get();
+		if config.window.is_zero() {
+			return weight;
+		}
+
+		for collator in selected {
+			//   read:  AwardedPts, MissedRounds
+			//   write: MissedRounds
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+			if !<AwardedPts<T>>::get(round, &collator).is_zero() {
+				<MissedRounds<T>>::remove(&collator);
+				continue;
+			}
+			let missed_rounds = <MissedRounds<T>>::get(&collator).saturating_add(1);
+			if missed_rounds < config.window {
+				<MissedRounds<T>>::insert(&collator, missed_rounds);
+				continue;
+			}
+			<MissedRounds<T>>::remove(&collator);
+			weight = weight.saturating_add(Self::slash_collator(&collator, config.fraction, missed_rounds));
+		}
+		weight
+	}
+
+	/// Slash `fraction` of the self bond of `collator` and of every delegation it holds, move the
+	/// slashed funds to `T::SlashDestination` and force the collator offline.
+	/// Pending bond less, revoke and decrease requests are clamped to what is left after the slash.
+	pub(crate) fn slash_collator(collator: &T::AccountId, fraction: Perbill, missed_rounds: RoundIndex) -> Weight {
+		let Some(mut state) = <CandidateInfo<T>>::get(collator) else {
+			return T::WeightInfo::slash_collator(0);
+		};
+
+		let bond_slashed = Self::slash_held(collator, HoldReason::StakingCollator, fraction * state.bond);
+		state.bond = state.bond.saturating_sub(bond_slashed);
+		state.total_counted = state.total_counted.saturating_sub(bond_slashed);
+		// a pending bond less request cannot release more than what is left on hold
+		if let Some(request) = state.request.as_mut() {
+			request.amount = request.amount.min(state.bond);
+		}
+		T::OnSlash::on_slash(collator, bond_slashed);
+
+		let delegations = <TopDelegations<T>>::get(collator)
+			.into_iter()
+			.chain(<BottomDelegations<T>>::get(collator))
+			.flat_map(|delegations| delegations.delegations)
+			.collect::<Vec<_>>();
+		let weight = T::WeightInfo::slash_collator(delegations.len() as u32);
+		let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(collator);
+		let mut scheduled_requests_clamped = false;
+		let mut delegations_slashed = BalanceOf::<T>::zero();
+		for Bond { owner: delegator, amount } in delegations {
+			let slashed = Self::slash_held(&delegator, HoldReason::StakingDelegator, fraction * amount);
+			if slashed.is_zero() {
+				continue;
+			}
+			if let Err(error) = state.decrease_delegation::<T>(collator, delegator.clone(), amount, slashed) {
+				log::error!("Slashed delegation of {:?} could not be decreased: {:?}", delegator, error);
+			}
+			if let Some(mut delegator_state) = <DelegatorState<T>>::get(&delegator) {
+				let mut bond_left = BalanceOf::<T>::zero();
+				for bond in delegator_state.delegations.0.iter_mut().filter(|bond| &bond.owner == collator) {
+					bond.amount = bond.amount.saturating_sub(slashed);
+					bond_left = bond.amount;
+				}
+				delegator_state.total = delegator_state.total.saturating_sub(slashed);
+
+				// a pending request must still be executable once the delegation is slashed
+				if let Some(index) = scheduled_requests.iter().position(|request| request.delegator == delegator) {
+					let requested = scheduled_requests[index].action.amount();
+					let action = match scheduled_requests[index].action {
+						DelegationAction::Revoke(_) => DelegationAction::Revoke(requested.min(bond_left)),
+						DelegationAction::Decrease(_) => {
+							let other_requests = delegator_state.less_total.saturating_sub(requested);
+							let max_decrease = bond_left.saturating_sub(T::MinDelegation::get()).min(
+								delegator_state
+									.total()
+									.saturating_sub(other_requests)
+									.saturating_sub(T::MinDelegatorStk::get()),
+							);
+							DelegationAction::Decrease(requested.min(max_decrease))
+						},
+					};
+					let clamped = requested.saturating_sub(action.amount());
+					delegator_state.less_total = delegator_state.less_total.saturating_sub(clamped);
+					scheduled_requests_clamped = true;
+					if matches!(action, DelegationAction::Decrease(amount) if amount.is_zero()) {
+						scheduled_requests.remove(index);
+					} else {
+						scheduled_requests[index].action = action;
+					}
+				}
+				<DelegatorState<T>>::insert(&delegator, delegator_state);
+			}
+			delegations_slashed = delegations_slashed.saturating_add(slashed);
+			T::OnSlash::on_slash(&delegator, slashed);
+			Self::deposit_event(Event::DelegationSlashed { delegator, candidate: collator.clone(), amount: slashed });
+		}
+		if scheduled_requests_clamped {
+			<DelegationScheduledRequests<T>>::insert(collator, scheduled_requests);
+		}
+
+		<Total<T>>::mutate(|total| *total = total.saturating_sub(bond_slashed.saturating_add(delegations_slashed)));
+		Self::deposit_event(Event::CollatorSlashed {
+			candidate: collator.clone(),
+			missed_rounds,
+			bond_slashed,
+			delegations_slashed,
+		});
+
+		if state.is_active() {
+			state.go_offline();
+			let mut candidates = <CandidatePool<T>>::get();
+			if candidates.remove(&Bond::from_owner(collator.clone())) {
+				<CandidatePool<T>>::put(candidates);
+			}
+			Self::deposit_event(Event::CandidateWentOffline { candidate: collator.clone() });
+		}
+		<CandidateInfo<T>>::insert(collator, state);
+		weight
+	}
+
+	/// Move up to `amount` held under `reason` from `who` to `T::SlashDestination`.
+	/// Returns the amount actually slashed.
+	fn slash_held(who: &T::AccountId, reason: HoldReason, amount: BalanceOf<T>) -> BalanceOf<T> {
+		if amount.is_zero() {
+			return Zero::zero();
+		}
+		T::Currency::transfer_on_hold(
+			&reason.into(),
+			who,
+			&T::SlashDestination::get(),
+			amount,
+			Precision::BestEffort,
+			Restriction::Free,
+			Fortitude::Force,
+		)
+		.unwrap_or_else(|error| {
+			log::error!("Failed to slash {:?} from {:?}: {:?}", amount, who, error);
+			Zero::zero()
+		})
+	}
+}
@@ -20,7 +20,7 @@
 
 use crate::{
 	auto_compound::AutoCompoundDelegations, set::OrderedSet, BalanceOf, BottomDelegations, CandidateInfo, Config,
-	DelegatorState, Error, Event, HoldReason, Pallet, Round, RoundIndex, TopDelegations, Total,
+	DelegatorState, Error, Event, HoldReason, LastRedelegation, Pallet, Round, RoundIndex, TopDelegations, Total,
 };
 use frame_support::{
 	pallet_prelude::*,
@@ -649,6 +649,7 @@ impl<
 				});
 				if leaving {
 					<DelegatorState<T>>::remove(&lowest_bottom_to_be_kicked.owner);
+					<LastRedelegation<T>>::remove(&lowest_bottom_to_be_kicked.owner);
 					Pallet::<T>::deposit_event(Event::DelegatorLeft {
 						delegator: lowest_bottom_to_be_kicked.owner,
 						unstaked_amount: lowest_bottom_to_be_kicked.amount,
@@ -59,6 +59,8 @@ pub trait WeightInfo {
 	fn set_parachain_bond_reserve_percent() -> Weight;
 	fn set_total_selected() -> Weight;
 	fn set_collator_commission() -> Weight;
+	fn set_slashing_config() -> Weight;
+	fn redelegate(x: u32, y: u32, z: u32, ) -> Weight;
 	fn set_blocks_per_round() -> Weight;
 	fn join_candidates(x: u32, ) -> Weight;
 	fn schedule_leave_candidates(x: u32, ) -> Weight;
@@ -89,6 +91,7 @@ pub trait WeightInfo {
 	fn set_auto_compound(x: u32, y: u32, ) -> Weight;
 	fn delegate_with_auto_compound(x: u32, y: u32, z: u32, ) -> Weight;
 	fn mint_collator_reward() -> Weight;
+	fn slash_collator(x: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_parachain_staking` using the Substrate node and recommended hardware.
@@ -164,6 +167,60 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `ParachainStaking::SlashingConfig` (r:1 w:1)
+	/// Proof: `ParachainStaking::SlashingConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_slashing_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196`
+		//  Estimated: `1681`
+		// Minimum execution time: 7_720_000 picoseconds.
+		Weight::from_parts(8_120_000, 1681)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::DelegatorState` (r:2 w:2)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::LastRedelegation` (r:1 w:1)
+	/// Proof: `ParachainStaking::LastRedelegation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:2 w:0)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:2 w:2)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::AutoCompoundingDelegations` (r:2 w:2)
+	/// Proof: `ParachainStaking::AutoCompoundingDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:2 w:2)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::BottomDelegations` (r:2 w:2)
+	/// Proof: `ParachainStaking::BottomDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 350]`.
+	/// The range of component `y` is `[1, 350]`.
+	/// The range of component `z` is `[1, 99]`.
+	fn redelegate(x: u32, y: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3190 + x * (166 ±0) + y * (33 ±0) + z * (52 ±0)`
+		//  Estimated: `8468 + x * (168 ±0) + y * (44 ±0) + z * (53 ±0)`
+		// Minimum execution time: 118_420_000 picoseconds.
+		Weight::from_parts(104_927_311, 8468)
+			// Standard Error: 1_731
+			.saturating_add(Weight::from_parts(216_482, 0).saturating_mul(x.into()))
+			// Standard Error: 1_731
+			.saturating_add(Weight::from_parts(37_025, 0).saturating_mul(y.into()))
+			// Standard Error: 5_902
+			.saturating_add(Weight::from_parts(64_113, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+			.saturating_add(Weight::from_parts(0, 168).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 44).saturating_mul(y.into()))
+			.saturating_add(Weight::from_parts(0, 53).saturating_mul(z.into()))
+	}
 	/// Storage: `ParachainStaking::Round` (r:1 w:1)
 	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `ParachainStaking::TotalSelected` (r:1 w:0)
@@ -774,6 +831,39 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:351 w:351)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:352 w:352)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::BottomDelegations` (r:1 w:1)
+	/// Proof: `ParachainStaking::BottomDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:350 w:350)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[0, 350]`.
+	fn slash_collator(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1318 + x * (534 ±0)`
+		//  Estimated: `6196 + x * (3424 ±0)`
+		// Minimum execution time: 71_830_000 picoseconds.
+		Weight::from_parts(74_215_406, 6196)
+			// Standard Error: 27_913
+			.saturating_add(Weight::from_parts(44_127_390, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3424).saturating_mul(x.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -848,6 +938,60 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `ParachainStaking::SlashingConfig` (r:1 w:1)
+	/// Proof: `ParachainStaking::SlashingConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_slashing_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196`
+		//  Estimated: `1681`
+		// Minimum execution time: 7_720_000 picoseconds.
+		Weight::from_parts(8_120_000, 1681)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::DelegatorState` (r:2 w:2)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::LastRedelegation` (r:1 w:1)
+	/// Proof: `ParachainStaking::LastRedelegation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:2 w:0)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:2 w:2)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::AutoCompoundingDelegations` (r:2 w:2)
+	/// Proof: `ParachainStaking::AutoCompoundingDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:2 w:2)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::BottomDelegations` (r:2 w:2)
+	/// Proof: `ParachainStaking::BottomDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 350]`.
+	/// The range of component `y` is `[1, 350]`.
+	/// The range of component `z` is `[1, 99]`.
+	fn redelegate(x: u32, y: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3190 + x * (166 ±0) + y * (33 ±0) + z * (52 ±0)`
+		//  Estimated: `8468 + x * (168 ±0) + y * (44 ±0) + z * (53 ±0)`
+		// Minimum execution time: 118_420_000 picoseconds.
+		Weight::from_parts(104_927_311, 8468)
+			// Standard Error: 1_731
+			.saturating_add(Weight::from_parts(216_482, 0).saturating_mul(x.into()))
+			// Standard Error: 1_731
+			.saturating_add(Weight::from_parts(37_025, 0).saturating_mul(y.into()))
+			// Standard Error: 5_902
+			.saturating_add(Weight::from_parts(64_113, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+			.saturating_add(Weight::from_parts(0, 168).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 44).saturating_mul(y.into()))
+			.saturating_add(Weight::from_parts(0, 53).saturating_mul(z.into()))
+	}
 	/// Storage: `ParachainStaking::Round` (r:1 w:1)
 	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `ParachainStaking::TotalSelected` (r:1 w:0)
@@ -1458,4 +1602,37 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:351 w:351)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:352 w:352)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::BottomDelegations` (r:1 w:1)
+	/// Proof: `ParachainStaking::BottomDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:350 w:350)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[0, 350]`.
+	fn slash_collator(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1318 + x * (534 ±0)`
+		//  Estimated: `6196 + x * (3424 ±0)`
+		// Minimum execution time: 71_830_000 picoseconds.
+		Weight::from_parts(74_215_406, 6196)
+			// Standard Error: 27_913
+			.saturating_add(Weight::from_parts(44_127_390, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3424).saturating_mul(x.into()))
+	}
 }
\ No newline at end of file
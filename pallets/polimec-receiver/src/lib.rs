@@ -22,22 +22,21 @@
 /// Learn more about FRAME and the core library of Substrate FRAME pallets:
 /// <https://docs.substrate.io/v3/runtime/frame>
 pub use pallet::*;
+
+pub mod receivers;
+
 #[frame_support::pallet]
 pub mod pallet {
+	use crate::receivers::ReceiveMigration;
 	use cumulus_pallet_xcm::{ensure_sibling_para, Origin as ParachainOrigin};
-	use frame_support::{
-		pallet_prelude::*,
-		traits::{tokens::Balance, Currency, ExistenceRequirement::KeepAlive, VestingSchedule},
-	};
+	use frame_support::{pallet_prelude::*, traits::tokens::Balance};
 	use frame_system::pallet_prelude::*;
-	use polimec_common::migration_types::{Migration, MigrationInfo, MigrationOrigin, Migrations, ParticipationType};
+	use polimec_common::migration_types::{Migration, MigrationOrigin, Migrations, ParticipationType};
 	use polkadot_parachain_primitives::primitives::{Id as ParaId, Sibling};
-	use sp_runtime::traits::{AccountIdConversion, Convert};
+	use sp_runtime::traits::AccountIdConversion;
 	use sp_std::prelude::*;
 	use xcm::v4::{Junction::AccountId32, Location};
 
-	type MomentOf<T> = <<T as Config>::Vesting as VestingSchedule<<T as frame_system::Config>::AccountId>>::Moment;
-
 	#[pallet::config]
 	pub trait Config: frame_system::Config
 	where
@@ -48,14 +47,21 @@ pub mod pallet {
 		type PolimecParaId: Get<ParaId>;
 		type RuntimeOrigin: IsType<<Self as frame_system::Config>::RuntimeOrigin>
 			+ Into<Result<ParachainOrigin, <Self as Config>::RuntimeOrigin>>;
-		type Vesting: VestingSchedule<Self::AccountId, Currency = Self::Balances>;
-		type Balances: Currency<Self::AccountId, Balance = Self::Balance>;
 		type Balance: Balance + From<u128> + MaybeSerializeDeserialize;
-		type GenesisMoment: Get<MomentOf<Self>>;
-		type MigrationInfoToPerBlockBalance: Convert<MigrationInfo, Self::Balance>;
+		/// Pays out and vests the contribution tokens of each migration. See [`crate::receivers`].
+		type MigrationReceiver: ReceiveMigration<Self::AccountId>;
 		type MaxMigrations: Get<u128>;
 	}
 
+	/// Reasons the contribution tokens are held for, when paid out with a
+	/// [`ReleaseScheduleReceiver`](crate::receivers::ReleaseScheduleReceiver).
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		Evaluation,
+		Bid,
+		Contribution,
+	}
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -107,11 +113,8 @@ pub mod pallet {
 
 			ensure!(para_id == polimec_id, "Only Polimec Parachain can call migrations");
 
-			for migration @ Migration {
-				origin: MigrationOrigin { user, id, participation_type },
-				info: MigrationInfo { contribution_token_amount, .. },
-				..
-			} in migrations.inner().iter()
+			for migration @ Migration { origin: MigrationOrigin { user, id, participation_type }, .. } in
+				migrations.inner().iter()
 			{
 				let user_32 = match user.unpack() {
 					(0, [AccountId32 { id, .. }]) => Ok(*id),
@@ -123,18 +126,7 @@ pub mod pallet {
 					continue;
 				}
 
-				T::Balances::transfer(
-					&polimec_sovereign_account,
-					&user_32.into(),
-					(*contribution_token_amount).into(),
-					KeepAlive,
-				)?;
-				T::Vesting::add_vesting_schedule(
-					&user_32.into(),
-					(*contribution_token_amount).into(),
-					T::MigrationInfoToPerBlockBalance::convert(migration.info.clone()),
-					T::GenesisMoment::get(),
-				)?;
+				T::MigrationReceiver::receive(&polimec_sovereign_account, &user_32.into(), migration)?;
 				ExecutedMigrations::<T>::insert((&user, &participation_type, &id), true);
 				Self::deposit_event(Event::MigrationExecuted { migration: migration.clone() });
 			}
@@ -0,0 +1,124 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Ways of paying out and vesting the contribution tokens of the migrations sent by Polimec.
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{
+		fungible::{Inspect, MutateHold},
+		tokens::{Fortitude, Precision, Preservation},
+		Currency,
+		ExistenceRequirement::KeepAlive,
+		VestingSchedule,
+	},
+};
+use polimec_common::{
+	migration_types::{Migration, MigrationInfo, ParticipationType},
+	ReleaseSchedule,
+};
+use sp_runtime::traits::{BlockNumberProvider, Convert};
+use sp_std::marker::PhantomData;
+
+/// Pays out the contribution tokens of a migration to its beneficiary on the receiving chain.
+pub trait ReceiveMigration<AccountId> {
+	/// Moves the contribution tokens of `migration` from `source` to `beneficiary`, and vests them.
+	fn receive(source: &AccountId, beneficiary: &AccountId, migration: &Migration) -> DispatchResult;
+}
+
+/// Transfers the contribution tokens and locks them with a [`VestingSchedule`]. All the schedules start at
+/// `GenesisMoment`, and release `PerBlock` tokens per block.
+pub struct VestingScheduleReceiver<Balances, Vesting, GenesisMoment, PerBlock>(
+	PhantomData<(Balances, Vesting, GenesisMoment, PerBlock)>,
+);
+
+impl<AccountId, Balances, Vesting, GenesisMoment, PerBlock> ReceiveMigration<AccountId>
+	for VestingScheduleReceiver<Balances, Vesting, GenesisMoment, PerBlock>
+where
+	Balances: Currency<AccountId>,
+	Balances::Balance: From<u128>,
+	Vesting: VestingSchedule<AccountId, Currency = Balances>,
+	GenesisMoment: Get<Vesting::Moment>,
+	PerBlock: Convert<MigrationInfo, Balances::Balance>,
+{
+	fn receive(source: &AccountId, beneficiary: &AccountId, migration: &Migration) -> DispatchResult {
+		let amount = migration.info.contribution_token_amount.into();
+		Balances::transfer(source, beneficiary, amount, KeepAlive)?;
+		Vesting::add_vesting_schedule(
+			beneficiary,
+			amount,
+			PerBlock::convert(migration.info.clone()),
+			GenesisMoment::get(),
+		)
+	}
+}
+
+/// Transfers the contribution tokens on hold, and releases them with a [`ReleaseSchedule`] such as
+/// `pallet_linear_release`.
+///
+/// Each migration gets its own schedule, starting at the block in which it is executed and lasting its
+/// `vesting_time`. The tokens of each [`ParticipationType`] are held under the reason returned by `ParticipationReason`,
+/// so the schedules of evaluations, bids and contributions can be inspected and vested separately.
+pub struct ReleaseScheduleReceiver<Release, Reason, ParticipationReason, PerBlock, Now>(
+	PhantomData<(Release, Reason, ParticipationReason, PerBlock, Now)>,
+);
+
+impl<AccountId, Release, Reason, ParticipationReason, PerBlock, Now> ReceiveMigration<AccountId>
+	for ReleaseScheduleReceiver<Release, Reason, ParticipationReason, PerBlock, Now>
+where
+	Release: ReleaseSchedule<AccountId, Reason>,
+	Release::Currency: MutateHold<AccountId, Reason = Reason>,
+	Reason: Clone,
+	<Release::Currency as Inspect<AccountId>>::Balance: From<u128>,
+	ParticipationReason: Convert<ParticipationType, Reason>,
+	PerBlock: Convert<MigrationInfo, <Release::Currency as Inspect<AccountId>>::Balance>,
+	Now: BlockNumberProvider<BlockNumber = Release::Moment>,
+{
+	fn receive(source: &AccountId, beneficiary: &AccountId, migration: &Migration) -> DispatchResult {
+		let reason = ParticipationReason::convert(migration.origin.participation_type);
+		let amount = migration.info.contribution_token_amount.into();
+		let per_block = PerBlock::convert(migration.info.clone());
+
+		Release::can_add_release_schedule(beneficiary, amount, per_block, Now::current_block_number(), reason.clone())?;
+		let amount = Release::Currency::transfer_and_hold(
+			&reason,
+			source,
+			beneficiary,
+			amount,
+			Precision::Exact,
+			Preservation::Preserve,
+			Fortitude::Polite,
+		)?;
+		Release::set_release_schedule(beneficiary, amount, per_block, Now::current_block_number(), reason)
+	}
+}
+
+/// Holds the contribution tokens of each [`ParticipationType`] under its own [`HoldReason`](crate::HoldReason)
+/// of this pallet. Meant as the `ParticipationReason` of a [`ReleaseScheduleReceiver`].
+pub struct ParticipationHoldReason<RuntimeHoldReason>(PhantomData<RuntimeHoldReason>);
+
+impl<RuntimeHoldReason: From<crate::HoldReason>> Convert<ParticipationType, RuntimeHoldReason>
+	for ParticipationHoldReason<RuntimeHoldReason>
+{
+	fn convert(participation_type: ParticipationType) -> RuntimeHoldReason {
+		match participation_type {
+			ParticipationType::Evaluation => crate::HoldReason::Evaluation,
+			ParticipationType::Bid => crate::HoldReason::Bid,
+			ParticipationType::Contribution => crate::HoldReason::Contribution,
+		}
+		.into()
+	}
+}
@@ -21,8 +21,8 @@
 extern crate alloc;
 
 use core::ops::RangeInclusive;
-use cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
-use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
+use cumulus_pallet_parachain_system::{RelayChainStateProof, RelayNumberMonotonicallyIncreases};
+use cumulus_primitives_core::{relay_chain::well_known_keys, AggregateMessageOrigin, ParaId, PersistedValidationData};
 use frame_support::{
 	construct_runtime,
 	genesis_builder_helper::{build_state, get_preset},
@@ -31,7 +31,7 @@ use frame_support::{
 		fungible::{Credit, HoldConsideration, Inspect},
 		tokens::{self, PayFromAccount, UnityAssetBalanceConversion},
 		AsEnsureOriginWithArg, ConstU32, Contains, EitherOfDiverse, InstanceFilter, LinearStoragePrice, PrivilegeCmp,
-		TransformOrigin,
+		Randomness, TransformOrigin,
 	},
 	weights::{ConstantMultiplier, Weight},
 };
@@ -39,15 +39,19 @@ use frame_system::{EnsureRoot, EnsureRootWithSuccess, EnsureSigned, EnsureSigned
 use pallet_aura::Authorities;
 use pallet_democracy::GetElectorate;
 use pallet_funding::{
-	runtime_api::ProjectParticipationIds, types::AcceptedFundingAsset, BidInfoOf, ContributionInfoOf, DaysToBlocks,
-	EvaluationInfoOf, ProjectDetailsOf, ProjectId, ProjectMetadataOf,
+	runtime_api::{
+		ParticipationFilter, ParticipationPage, ParticipationSimulation, ProjectParticipationIds, ProjectTimeline,
+	},
+	types::AcceptedFundingAsset,
+	BidInfoOf, ContributionInfoOf, DaysToBlocks, EvaluationInfoOf, MultiplierOf, PriceOf, ProjectDetailsOf, ProjectId,
+	ProjectMetadataOf,
 };
 use parachains_common::{
 	message_queue::{NarrowOriginToSibling, ParaIdToSibling},
 	AssetIdForTrustBackedAssets as AssetId,
 };
 use parity_scale_codec::Encode;
-use polimec_common::credentials::{Did, EnsureInvestor};
+use polimec_common::credentials::{Did, EnsureInvestor, InvestorType};
 use polkadot_runtime_common::{BlockHashCount, CurrencyToVote, SlowAdjustingFeeUpdate};
 use shared_configuration::proxy;
 use sp_api::impl_runtime_apis;
@@ -56,10 +60,10 @@ use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
 		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, Convert, ConvertBack, ConvertInto,
-		IdentifyAccount, IdentityLookup, OpaqueKeys, Verify,
+		Hash as HashT, IdentifyAccount, IdentityLookup, OpaqueKeys, Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, FixedU128, MultiSignature, SaturatedConversion,
+	ApplyExtrinsicResult, DispatchError, FixedU128, MultiSignature, SaturatedConversion,
 };
 use sp_std::{cmp::Ordering, prelude::*};
 use sp_version::RuntimeVersion;
@@ -150,6 +154,7 @@ pub type SignedExtra = (
 		pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 	>,
 	frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
+	pallet_dispenser::extensions::CheckDispenseRate<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -170,6 +175,7 @@ pub mod migrations {
 	pub type Unreleased = (
 		cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
 		crate::custom_migrations::funding_holds::FromFundingV4Migration,
+		pallet_funding::storage_migrations::v6::MigrationToV6<Runtime, crate::xcm_config::InitialFundingAssets>,
 	);
 }
 
@@ -219,7 +225,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 0_008_000,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 5,
+	transaction_version: 6,
 	state_version: 1,
 };
 
@@ -251,6 +257,8 @@ impl Contains<RuntimeCall> for BaseCallFilter {
 							pallet_funding::Call::evaluate { .. } |
 							pallet_funding::Call::end_evaluation { .. } |
 							pallet_funding::Call::bid { .. } |
+							pallet_funding::Call::cancel_bid { .. } |
+							pallet_funding::Call::amend_bid { .. } |
 							pallet_funding::Call::end_auction { .. } |
 							pallet_funding::Call::contribute { .. } |
 							pallet_funding::Call::end_funding { .. } |
@@ -459,7 +467,7 @@ impl cumulus_pallet_parachain_system::Config for Runtime {
 	type CheckAssociatedRelayNumber = RelayNumberMonotonicallyIncreases;
 	type ConsensusHook = ConsensusHook;
 	type DmpQueue = frame_support::traits::EnqueueWithOrigin<MessageQueue, RelayOrigin>;
-	type OnSystemEvent = ();
+	type OnSystemEvent = RelayChainRandomness;
 	type OutboundXcmpMessageSource = XcmpQueue;
 	type ReservedDmpWeight = ReservedDmpWeight;
 	type ReservedXcmpWeight = ReservedXcmpWeight;
@@ -791,13 +799,16 @@ impl pallet_parachain_staking::Config for Runtime {
 	type MonetaryGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
 	type OnCollatorPayout = ();
 	type OnNewRound = ();
+	type OnSlash = Vesting;
 	type PayMaster = BlockchainOperationTreasury;
 	// We use the default implementation, so we leave () here.
 	type PayoutCollatorReward = ();
+	type RedelegationCooldown = RedelegationCooldown;
 	type RevokeDelegationDelay = RevokeDelegationDelay;
 	type RewardPaymentDelay = RewardPaymentDelay;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type SlashDestination = TreasuryAccount;
 	type WeightInfo = weights::pallet_parachain_staking::WeightInfo<Runtime>;
 }
 
@@ -820,38 +831,65 @@ parameter_types! {
 	pub const ExpiresIn: Moment = 1000 * 60; // 1 mins
 	pub const MaxHasDispatchedSize: u32 = 20;
 	pub RootOperatorAccountId: AccountId = AccountId::from([0xffu8; 32]);
-	pub const MaxFeedValues: u32 = 4; // max 4 values allowd to feed in one call (USDT, USDC, DOT, PLMC).
+	pub const MaxFeedValues: u32 = 16; // max 16 values allowed to feed in one call (PLMC and the funding assets).
+	pub const MaxFundingAssets: u32 = MaxFeedValues::get() - 1; // one of the fed values is the PLMC price.
 }
 
 impl orml_oracle::Config for Runtime {
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
-	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, ()>;
+	type CombineData = DeviationGuardedCombineData<
+		orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, ()>,
+		Timestamp,
+		MaxPriceAge,
+		MaxPriceDeviation,
+	>;
 	type MaxFeedValues = MaxFeedValues;
 	type MaxHasDispatchedSize = MaxHasDispatchedSize;
 	type Members = OracleProvidersMembership;
-	type OnNewData = ();
+	type OnNewData = OracleOffchainWorker;
 	type OracleKey = AssetId;
 	type OracleValue = Price;
 	type RootOperatorAccountId = RootOperatorAccountId;
 	type RuntimeEvent = RuntimeEvent;
 	type Time = Timestamp;
 	// TODO Add weight info
-	type WeightInfo = ();
+	type WeightInfo = pallet_oracle_ocw::OracleWeightWithTwap<Runtime, ()>;
 }
 
 parameter_types! {
 	pub const FetchInterval: u32 = 50;
 	pub const FetchWindow: u32 = 5;
+	pub OracleAggregationStrategy: pallet_oracle_ocw::types::AggregationStrategy =
+		pallet_oracle_ocw::types::AggregationStrategy::VolumeWeightedWithoutOutliers {
+			max_deviations: FixedU128::from_u32(3),
+		};
+	pub const OracleMinimumSources: u32 = 2;
+	pub const TwapWindow: Moment = 2 * 60 * 60 * 1000; // 2 hours
+	// Up to one observation per oracle member feed, for a fetch every `FetchInterval` blocks
+	pub const MaxTwapObservations: u32 = 64;
 }
 
 impl pallet_oracle_ocw::Config for Runtime {
+	type AggregationStrategy = OracleAggregationStrategy;
 	type AppCrypto = pallet_oracle_ocw::crypto::Polimec;
-	type ConvertAssetPricePair = AssetPriceConverter;
+	type ConvertAssetPricePair = AssetPriceConverter<Runtime>;
 	type FetchInterval = FetchInterval;
 	type FetchWindow = FetchWindow;
+	type MaxTwapObservations = MaxTwapObservations;
 	type Members = OracleProvidersMembership;
+	type MinimumSources = OracleMinimumSources;
+	type PriceSources = (
+		pallet_oracle_ocw::types::KrakenFetcher,
+		pallet_oracle_ocw::types::BitFinexFetcher,
+		pallet_oracle_ocw::types::BitStampFetcher,
+		pallet_oracle_ocw::types::CoinbaseFetcher,
+		pallet_oracle_ocw::types::XTFetcher,
+		pallet_oracle_ocw::types::MexcFetcher,
+	);
 	type RuntimeEvent = RuntimeEvent;
+	type TrackedAssets = TrackedAssetNames<Runtime>;
+	type TwapWindow = TwapWindow;
 }
 
 impl frame_system::offchain::SigningTypes for Runtime {
@@ -903,6 +941,7 @@ where
 				pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
 			),
 			frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(true),
+			pallet_dispenser::extensions::CheckDispenseRate::<Runtime>::new(),
 		);
 		let raw_payload = generic::SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -1015,7 +1054,7 @@ parameter_types! {
 	pub RequiredMaxCapacity: u32 = 1000;
 	pub RequiredMaxMessageSize: u32 = 102_400;
 	pub MinUsdPerEvaluation: Balance = 100 * USD_UNIT;
-
+	pub MaxTransitionWeightPerBlock: Weight = Perbill::from_percent(25) * RuntimeBlockWeights::get().max_block;
 }
 
 pub struct ConvertSelf;
@@ -1032,6 +1071,13 @@ impl ConvertBack<AccountId, [u8; 32]> for ConvertSelf {
 
 impl pallet_funding::Config for Runtime {
 	type AccountId32Conversion = ConvertSelf;
+	type AdminOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		EitherOfDiverse<
+			pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>,
+			pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 2, 3>,
+		>,
+	>;
 	#[cfg(any(test, feature = "runtime-benchmarks", feature = "std"))]
 	type AllPalletsWithoutSystem =
 		(Balances, ContributionTokens, ForeignAssets, Oracle, Funding, LinearRelease, Random);
@@ -1042,31 +1088,51 @@ impl pallet_funding::Config for Runtime {
 	type ContributionTokenCurrency = ContributionTokens;
 	type ContributionTreasury = ContributionTreasuryAccount;
 	type DaysToBlocks = DaysToBlocks;
+	type DefaultFundingDecision = DefaultFundingDecision;
 	type EvaluationRoundDuration = EvaluationRoundDuration;
 	type EvaluationSuccessThreshold = EarlyEvaluationThreshold;
 	type EvaluatorSlash = EvaluatorSlash;
 	type FeeBrackets = FeeBrackets;
+	type FundingAssetOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>,
+	>;
 	type FundingCurrency = ForeignAssets;
+	type FundingAutoSuccessThreshold = FundingAutoSuccessThreshold;
 	type FundingSuccessThreshold = FundingSuccessThreshold;
 	type InvestorOrigin = EnsureInvestor<Runtime>;
+	type ManualAcceptanceDuration = ManualAcceptanceDuration;
 	type MaxBidsPerProject = ConstU32<512>;
 	type MaxBidsPerUser = ConstU32<16>;
 	type MaxCapacityThresholds = MaxCapacityThresholds;
 	type MaxContributionsPerUser = ConstU32<16>;
 	type MaxEvaluationsPerProject = ConstU32<512>;
 	type MaxEvaluationsPerUser = ConstU32<16>;
+	type MaxFundingAssets = MaxFundingAssets;
 	type MaxMessageSizeThresholds = MaxMessageSizeThresholds;
+	type MaxProjectsToUpdateInsertionAttempts = ConstU32<100>;
+	type MaxProjectsToUpdatePerBlock = ConstU32<1000>;
+	type MaxTransitionWeightPerBlock = MaxTransitionWeightPerBlock;
+	type MilestoneOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>,
+	>;
+	type MilestoneVotingDuration = MilestoneVotingDuration;
 	type MinUsdPerEvaluation = MinUsdPerEvaluation;
 	type Multiplier = pallet_funding::types::Multiplier;
 	type NativeCurrency = Balances;
 	type OnSlash = Vesting;
 	type PalletId = FundingPalletId;
 	type Price = Price;
-	type PriceProvider = OraclePriceProvider<AssetId, Price, Oracle>;
-	type Randomness = Random;
+	type PriceProvider = OraclePriceProvider<AssetId, Price, Oracle, Timestamp, MaxPriceAge, OracleOffchainWorker>;
+	type Randomness = RelayChainRandomness;
 	type RemainderRoundDuration = RemainderRoundDuration;
 	type RequiredMaxCapacity = RequiredMaxCapacity;
 	type RequiredMaxMessageSize = RequiredMaxMessageSize;
+	type RewardParametersOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>,
+	>;
 	type RuntimeCall = RuntimeCall;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -1100,6 +1166,51 @@ impl pallet_linear_release::Config for Runtime {
 
 impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
 
+#[frame_support::storage_alias]
+type RelayEpochRandomness = StorageValue<RelayChainRandomness, ([u8; 32], BlockNumber)>;
+
+/// Randomness of the relay chain BABE epochs, read from the relay state proof of every block. It is known since the
+/// first parachain block that saw it, which is returned as `known_since`. Before any epoch was seen, the
+/// randomness is known since block zero, so it cannot pass as fresh.
+pub struct RelayChainRandomness;
+impl cumulus_pallet_parachain_system::OnSystemEvent for RelayChainRandomness {
+	fn on_validation_data(_data: &PersistedValidationData) {
+		// Read the root and the proof from storage, so they always belong to the same relay parent
+		let (Some(validation_data), Some(proof)) = (
+			cumulus_pallet_parachain_system::ValidationData::<Runtime>::get(),
+			cumulus_pallet_parachain_system::RelayStateProof::<Runtime>::get(),
+		) else {
+			return;
+		};
+		let Ok(proof) =
+			RelayChainStateProof::new(ParachainInfo::parachain_id(), validation_data.relay_parent_storage_root, proof)
+		else {
+			return;
+		};
+		let Ok(randomness) = proof.read_entry::<[u8; 32]>(well_known_keys::ONE_EPOCH_AGO_RANDOMNESS, None) else {
+			return;
+		};
+		// The randomness changes once per epoch, so it is known since the first block that saw the new value
+		if RelayEpochRandomness::get().map_or(true, |(last_randomness, _)| last_randomness != randomness) {
+			RelayEpochRandomness::put((randomness, System::block_number()));
+		}
+	}
+
+	fn on_validation_code_applied() {}
+}
+impl Randomness<Hash, BlockNumber> for RelayChainRandomness {
+	fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+		match RelayEpochRandomness::get() {
+			Some((randomness, known_since)) => (BlakeTwo256::hash_of(&(subject, randomness)), known_since),
+			// Benchmarks run without relay chain data
+			#[cfg(feature = "runtime-benchmarks")]
+			None => (BlakeTwo256::hash_of(&subject), System::block_number()),
+			#[cfg(not(feature = "runtime-benchmarks"))]
+			None => (BlakeTwo256::hash_of(&subject), 0),
+		}
+	}
+}
+
 ord_parameter_types! {
 	pub const DispenserAdminAccount: AccountId = AccountId::from(hex_literal::hex!("d85a4f58eb7dba17bc436b16f394b242271237021f7880e1ccaf36cd9a616c99"));
 }
@@ -1115,10 +1226,14 @@ ord_parameter_types! {
 impl pallet_dispenser::Config for Runtime {
 	type AdminOrigin = EnsureSignedBy<DispenserAdminAccount, AccountId>;
 	type BlockNumberToBalance = ConvertInto;
+	type DispensePriority = DispensePriority;
+	type DispenseRateWindow = DispenseRateWindow;
 	type FreeDispenseAmount = FreeDispenseAmount;
 	type InitialDispenseAmount = InitialDispenseAmount;
 	type InvestorOrigin = EnsureInvestor<Runtime>;
 	type LockPeriod = DispenserLockPeriod;
+	type MaxDispensesPerBlock = MaxDispensesPerBlock;
+	type MaxDispensesPerIssuer = MaxDispensesPerIssuer;
 	type PalletId = DispenserId;
 	type RuntimeEvent = RuntimeEvent;
 	type VerifierPublicKey = VerifierPublicKey;
@@ -1179,7 +1294,7 @@ construct_runtime!(
 		// Oracle
 		Oracle: orml_oracle::{Pallet, Call, Storage, Event<T>} = 70,
 		OracleProvidersMembership: pallet_membership::<Instance1> = 71,
-		OracleOffchainWorker: pallet_oracle_ocw::{Pallet, Event<T>} = 72,
+		OracleOffchainWorker: pallet_oracle_ocw::{Pallet, Storage, Event<T>} = 72,
 
 		Funding: pallet_funding = 80,
 		LinearRelease: pallet_linear_release = 81,
@@ -1416,6 +1531,18 @@ impl_runtime_apis! {
 		fn all_project_participations_by_did(project_id: ProjectId, did: Did) -> Vec<ProjectParticipationIds<Runtime>> {
 			Funding::all_project_participations_by_did(project_id, did)
 		}
+
+		fn evaluations_page(project_id: ProjectId, filter: ParticipationFilter<AccountId, BlockNumber>, cursor: Option<Vec<u8>>, limit: u32) -> ParticipationPage<EvaluationInfoOf<Runtime>> {
+			Funding::evaluations_page(project_id, filter, cursor, limit)
+		}
+
+		fn bids_page(project_id: ProjectId, filter: ParticipationFilter<AccountId, BlockNumber>, cursor: Option<Vec<u8>>, limit: u32) -> ParticipationPage<BidInfoOf<Runtime>> {
+			Funding::bids_page(project_id, filter, cursor, limit)
+		}
+
+		fn contributions_page(project_id: ProjectId, filter: ParticipationFilter<AccountId, BlockNumber>, cursor: Option<Vec<u8>>, limit: u32) -> ParticipationPage<ContributionInfoOf<Runtime>> {
+			Funding::contributions_page(project_id, filter, cursor, limit)
+		}
 	}
 
 	impl pallet_funding::runtime_api::ProjectInformation<Block, Runtime> for Runtime {
@@ -1426,6 +1553,10 @@ impl_runtime_apis! {
 		fn projects_by_did(did: Did) -> Vec<ProjectId> {
 			Funding::projects_by_did(did)
 		}
+
+		fn project_timeline(project_id: ProjectId) -> Option<ProjectTimeline<BlockNumber>> {
+			Funding::project_timeline(project_id)
+		}
 	}
 
 	impl pallet_funding::runtime_api::ExtrinsicHelpers<Block, Runtime> for Runtime {
@@ -1435,6 +1566,52 @@ impl_runtime_apis! {
 		fn get_next_vesting_schedule_merge_candidates(account: AccountId, hold_reason: RuntimeHoldReason, end_max_delta: Balance) -> Option<(u32, u32)> {
 			Funding::get_next_vesting_schedule_merge_candidates(account, hold_reason, end_max_delta)
 		}
+
+		fn simulate_bid(account: AccountId, did: Did, investor_type: InvestorType, project_id: ProjectId, ct_amount: Balance, multiplier: MultiplierOf<Runtime>, funding_asset: AcceptedFundingAsset) -> Result<ParticipationSimulation<PriceOf<Runtime>>, DispatchError> {
+			Funding::simulate_bid(account, did, investor_type, project_id, ct_amount, multiplier, funding_asset)
+		}
+
+		fn simulate_contribute(account: AccountId, did: Did, investor_type: InvestorType, project_id: ProjectId, ct_amount: Balance, multiplier: MultiplierOf<Runtime>, funding_asset: AcceptedFundingAsset) -> Result<ParticipationSimulation<PriceOf<Runtime>>, DispatchError> {
+			Funding::simulate_contribute(account, did, investor_type, project_id, ct_amount, multiplier, funding_asset)
+		}
+
+		fn simulate_evaluate(account: AccountId, did: Did, project_id: ProjectId, usd_amount: Balance) -> Result<ParticipationSimulation<PriceOf<Runtime>>, DispatchError> {
+			Funding::simulate_evaluate(account, did, project_id, usd_amount)
+		}
+	}
+
+	impl pallet_parachain_staking::runtime_api::ParachainStakingApi<Block, Runtime> for Runtime {
+		fn candidates_apr() -> Vec<pallet_parachain_staking::runtime_api::CandidateAprOf<Runtime>> {
+			ParachainStaking::candidates_apr()
+		}
+
+		fn candidate_apr(candidate: AccountId) -> Option<pallet_parachain_staking::runtime_api::CandidateAprOf<Runtime>> {
+			ParachainStaking::candidate_apr(candidate)
+		}
+
+		fn delegator_info(delegator: AccountId) -> Option<pallet_parachain_staking::runtime_api::DelegatorInfoOf<Runtime>> {
+			ParachainStaking::delegator_info(delegator)
+		}
+	}
+
+	impl pallet_oracle_ocw::runtime_api::OracleTwapApi<Block, Runtime> for Runtime {
+		fn twap(key: AssetId) -> Option<FixedU128> {
+			OracleOffchainWorker::twap(&key)
+		}
+
+		fn prices() -> Vec<pallet_oracle_ocw::runtime_api::AssetPrices<AssetId>> {
+			OracleOffchainWorker::prices()
+		}
+	}
+
+	impl pallet_linear_release::runtime_api::LinearReleaseApi<Block, Runtime> for Runtime {
+		fn vesting_schedules(account: AccountId, at: BlockNumber) -> Vec<(RuntimeHoldReason, Vec<pallet_linear_release::runtime_api::ScheduleStatusOf<Runtime>>)> {
+			LinearRelease::vesting_schedules(account, at)
+		}
+
+		fn unlock_curve(account: AccountId, reason: RuntimeHoldReason, at: BlockNumber) -> Vec<(BlockNumber, Balance)> {
+			LinearRelease::unlock_curve(account, reason, at)
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
@@ -65,4 +65,93 @@ impl<T: frame_system::Config> pallet_dispenser::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Dispenser::NextCampaignId` (r:1 w:1)
+	/// Proof: `Dispenser::NextCampaignId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::Campaigns` (r:0 w:1)
+	/// Proof: `Dispenser::Campaigns` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	fn create_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 8_771_000 picoseconds.
+		Weight::from_parts(9_091_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Dispenser::Campaigns` (r:1 w:1)
+	/// Proof: `Dispenser::Campaigns` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::CampaignDispensed` (r:0 w:1)
+	/// Proof: `Dispenser::CampaignDispensed` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::ClaimsToClear` (r:0 w:1)
+	/// Proof: `Dispenser::ClaimsToClear` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn close_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `323`
+		//  Estimated: `3631`
+		// Minimum execution time: 11_960_000 picoseconds.
+		Weight::from_parts(12_381_000, 0)
+			.saturating_add(Weight::from_parts(0, 3631))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Dispenser::ClaimsToClear` (r:1 w:1)
+	/// Proof: `Dispenser::ClaimsToClear` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::CampaignClaims` (r:0 w:1000)
+	/// Proof: `Dispenser::CampaignClaims` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 1000]`.
+	fn clear_campaign_claims(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `158 + c * (86 ±0)`
+		//  Estimated: `3597 + c * (2561 ±0)`
+		// Minimum execution time: 17_210_000 picoseconds.
+		Weight::from_parts(12_688_430, 0)
+			.saturating_add(Weight::from_parts(0, 3597))
+			// Standard Error: 2_047
+			.saturating_add(Weight::from_parts(1_436_552, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2561).saturating_mul(c.into()))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::Campaigns` (r:1 w:0)
+	/// Proof: `Dispenser::Campaigns` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::CampaignClaims` (r:1 w:1)
+	/// Proof: `Dispenser::CampaignClaims` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::CampaignDispensed` (r:1 w:1)
+	/// Proof: `Dispenser::CampaignDispensed` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(481), added: 2956, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	fn dispense_from_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `457`
+		//  Estimated: `4764`
+		// Minimum execution time: 183_912_000 picoseconds.
+		Weight::from_parts(185_603_000, 0)
+			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Dispenser::DispensesInBlock` (r:1 w:1)
+	/// Proof: `Dispenser::DispensesInBlock` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dispenser::IssuerDispenses` (r:1 w:1)
+	/// Proof: `Dispenser::IssuerDispenses` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn check_dispense_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `172`
+		//  Estimated: `3578`
+		// Minimum execution time: 63_942_000 picoseconds.
+		Weight::from_parts(65_381_000, 0)
+			.saturating_add(Weight::from_parts(0, 3578))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -167,6 +167,8 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 	/// Proof: `Funding::Bids` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::NextBidId` (r:1 w:1)
 	/// Proof: `Funding::NextBidId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::LastBidIds` (r:0 w:1)
+	/// Proof: `Funding::LastBidIds` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::AuctionBoughtUSD` (r:1 w:1)
 	/// Proof: `Funding::AuctionBoughtUSD` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
 	/// Storage: `Oracle::Values` (r:2 w:0)
@@ -196,7 +198,7 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(68_717_795, 0).saturating_mul(y.into()))
 			.saturating_add(T::DbWeight::get().reads(16))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
-			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().writes(9))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(y.into())))
 			.saturating_add(Weight::from_parts(0, 2748).saturating_mul(x.into()))
 	}
@@ -210,25 +212,86 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 	/// Proof: `Funding::DidWithWinningBids` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::CandleAuctionEnds` (r:0 w:1)
+	/// Proof: `Funding::CandleAuctionEnds` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[10, 25]`.
 	/// The range of component `y` is `[0, 8]`.
 	fn end_auction(x: u32, y: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1112 + x * (212 ±0) + y * (192 ±0)`
 		//  Estimated: `3967 + x * (2748 ±13) + y * (2748 ±0)`
-		// Minimum execution time: 184_100_000 picoseconds.
+		// Minimum execution time: 210_435_000 picoseconds.
 		Weight::from_parts(28_884_206, 0)
 			.saturating_add(Weight::from_parts(0, 3967))
 			// Standard Error: 13_889
-			.saturating_add(Weight::from_parts(10_019_424, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(11_341_780, 0).saturating_mul(x.into()))
 			// Standard Error: 25_145
-			.saturating_add(Weight::from_parts(7_838_626, 0).saturating_mul(y.into()))
+			.saturating_add(Weight::from_parts(9_068_411, 0).saturating_mul(y.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 2748).saturating_mul(x.into()))
 			.saturating_add(Weight::from_parts(0, 2748).saturating_mul(y.into()))
 	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Bids` (r:1 w:1)
+	/// Proof: `Funding::Bids` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::LastBidIds` (r:1 w:0)
+	/// Proof: `Funding::LastBidIds` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Buckets` (r:1 w:1)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::BidCounts` (r:1 w:1)
+	/// Proof: `Funding::BidCounts` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionBoughtUSD` (r:1 w:1)
+	/// Proof: `Funding::AuctionBoughtUSD` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DidWithWinningBids` (r:0 w:1)
+	/// Proof: `Funding::DidWithWinningBids` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1149), added: 3624, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn cancel_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2431`
+		//  Estimated: `6208`
+		// Minimum execution time: 121_470_000 picoseconds.
+		Weight::from_parts(123_810_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Bids` (r:1 w:1)
+	/// Proof: `Funding::Bids` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::LastBidIds` (r:1 w:0)
+	/// Proof: `Funding::LastBidIds` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Buckets` (r:1 w:1)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionBoughtUSD` (r:1 w:1)
+	/// Proof: `Funding::AuctionBoughtUSD` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1149), added: 3624, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn amend_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2398`
+		//  Estimated: `6208`
+		// Minimum execution time: 108_230_000 picoseconds.
+		Weight::from_parts(110_540_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
@@ -290,6 +353,188 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
 	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	fn decide_project_outcome() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `964`
+		//  Estimated: `3967`
+		// Minimum execution time: 27_310_000 picoseconds.
+		Weight::from_parts(28_440_000, 3967)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectEscrows` (r:1 w:0)
+	/// Proof: `Funding::ProjectEscrows` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneStatuses` (r:2 w:1)
+	/// Proof: `Funding::MilestoneStatuses` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn submit_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `742`
+		//  Estimated: `3967`
+		// Minimum execution time: 41_230_000 picoseconds.
+		Weight::from_parts(42_410_000, 3967)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::MilestoneStatuses` (r:1 w:1)
+	/// Proof: `Funding::MilestoneStatuses` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EscrowShares` (r:1 w:0)
+	/// Proof: `Funding::EscrowShares` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneVotes` (r:1 w:1)
+	/// Proof: `Funding::MilestoneVotes` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	fn vote_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `613`
+		//  Estimated: `3522`
+		// Minimum execution time: 28_910_000 picoseconds.
+		Weight::from_parts(29_870_000, 3522)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Funding::MilestoneStatuses` (r:1 w:1)
+	/// Proof: `Funding::MilestoneStatuses` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EscrowedFunds` (r:4 w:3)
+	/// Proof: `Funding::EscrowedFunds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:3 w:3)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:6 w:6)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn resolve_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1512`
+		//  Estimated: `11436`
+		// Minimum execution time: 115_020_000 picoseconds.
+		Weight::from_parts(117_730_000, 11436)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Funding::ProjectEscrows` (r:1 w:0)
+	/// Proof: `Funding::ProjectEscrows` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EscrowShares` (r:1 w:1)
+	/// Proof: `Funding::EscrowShares` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EscrowDeposits` (r:4 w:3)
+	/// Proof: `Funding::EscrowDeposits` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EscrowedFunds` (r:3 w:0)
+	/// Proof: `Funding::EscrowedFunds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:3 w:3)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:6 w:6)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn claim_escrow_refund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1437`
+		//  Estimated: `11436`
+		// Minimum execution time: 109_360_000 picoseconds.
+		Weight::from_parts(112_050_000, 11436)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Funding::ActiveRewardParameters` (r:0 w:1)
+	/// Proof: `Funding::ActiveRewardParameters` (`max_values`: Some(1), `max_size`: Some(203), added: 698, mode: `MaxEncodedLen`)
+	fn set_reward_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_930_000 picoseconds.
+		Weight::from_parts(9_310_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FrozenProjects` (r:1 w:1)
+	/// Proof: `Funding::FrozenProjects` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn freeze_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `3807`
+		// Minimum execution time: 14_610_000 picoseconds.
+		Weight::from_parts(15_120_000, 3807)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FrozenProjects` (r:1 w:1)
+	/// Proof: `Funding::FrozenProjects` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsToUpdate` (r:1 w:1)
+	/// Proof: `Funding::ProjectsToUpdate` (`max_values`: None, `max_size`: Some(522), added: 2997, mode: `MaxEncodedLen`)
+	fn unfreeze_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `468`
+		//  Estimated: `3987`
+		// Minimum execution time: 21_870_000 picoseconds.
+		Weight::from_parts(22_540_000, 3987)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DidWithActiveProjects` (r:1 w:1)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(78), added: 2553, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FrozenProjects` (r:0 w:1)
+	/// Proof: `Funding::FrozenProjects` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectRewardParameters` (r:0 w:1)
+	/// Proof: `Funding::ProjectRewardParameters` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsToUpdate` (r:1 w:1)
+	/// Proof: `Funding::ProjectsToUpdate` (`max_values`: None, `max_size`: Some(522), added: 2997, mode: `MaxEncodedLen`)
+	fn force_fail_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `468`
+		//  Estimated: `3987`
+		// Minimum execution time: 29_870_000 picoseconds.
+		Weight::from_parts(30_910_000, 3987)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Funding::ParticipationsPaused` (r:0 w:1)
+	/// Proof: `Funding::ParticipationsPaused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_participations_paused() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_480_000 picoseconds.
+		Weight::from_parts(6_790_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::FundingAssets` (r:1 w:1)
+	/// Proof: `Funding::FundingAssets` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FundingAssetLocations` (r:1 w:1)
+	/// Proof: `Funding::FundingAssetLocations` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FundingAssetOracleKeys` (r:1 w:1)
+	/// Proof: `Funding::FundingAssetOracleKeys` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::CounterForFundingAssets` (r:1 w:1)
+	/// Proof: `Funding::CounterForFundingAssets` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn register_funding_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `4097`
+		// Minimum execution time: 13_920_000 picoseconds.
+		Weight::from_parts(14_480_000, 4097)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Funding::FundingAssets` (r:1 w:1)
+	/// Proof: `Funding::FundingAssets` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
+	fn set_funding_asset_enabled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `177`
+		//  Estimated: `4097`
+		// Minimum execution time: 12_110_000 picoseconds.
+		Weight::from_parts(12_650_000, 4097)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
 	/// Storage: `ContributionTokens::Asset` (r:1 w:1)
 	/// Proof: `ContributionTokens::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `ContributionTokens::Metadata` (r:1 w:1)
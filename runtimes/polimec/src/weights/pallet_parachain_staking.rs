@@ -106,6 +106,62 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `ParachainStaking::SlashingConfig` (r:1 w:1)
+	/// Proof: `ParachainStaking::SlashingConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_slashing_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `263`
+		//  Estimated: `1748`
+		// Minimum execution time: 7_130_000 picoseconds.
+		Weight::from_parts(7_470_000, 0)
+			.saturating_add(Weight::from_parts(0, 1748))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ParachainStaking::DelegatorState` (r:2 w:2)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::LastRedelegation` (r:1 w:1)
+	/// Proof: `ParachainStaking::LastRedelegation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:2 w:0)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:2 w:2)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::AutoCompoundingDelegations` (r:2 w:2)
+	/// Proof: `ParachainStaking::AutoCompoundingDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:2 w:2)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::BottomDelegations` (r:2 w:2)
+	/// Proof: `ParachainStaking::BottomDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 350]`.
+	/// The range of component `y` is `[1, 350]`.
+	/// The range of component `z` is `[1, 99]`.
+	fn redelegate(x: u32, y: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3257 + x * (166 ±0) + y * (33 ±0) + z * (52 ±0)`
+		//  Estimated: `8535 + x * (168 ±0) + y * (44 ±0) + z * (53 ±0)`
+		// Minimum execution time: 121_830_000 picoseconds.
+		Weight::from_parts(107_604_252, 0)
+			.saturating_add(Weight::from_parts(0, 8535))
+			// Standard Error: 1_694
+			.saturating_add(Weight::from_parts(211_937, 0).saturating_mul(x.into()))
+			// Standard Error: 1_694
+			.saturating_add(Weight::from_parts(36_418, 0).saturating_mul(y.into()))
+			// Standard Error: 5_781
+			.saturating_add(Weight::from_parts(61_204, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(14))
+			.saturating_add(Weight::from_parts(0, 168).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 44).saturating_mul(y.into()))
+			.saturating_add(Weight::from_parts(0, 53).saturating_mul(z.into()))
+	}
 	/// Storage: `ParachainStaking::Round` (r:1 w:1)
 	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `ParachainStaking::TotalSelected` (r:1 w:0)
@@ -746,4 +802,40 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:351 w:351)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:352 w:352)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Vesting` (r:351 w:0)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(481), added: 2956, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::BottomDelegations` (r:1 w:1)
+	/// Proof: `ParachainStaking::BottomDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:350 w:350)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[0, 350]`.
+	fn slash_collator(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1355 + x * (534 ±0)`
+		//  Estimated: `6196 + x * (3424 ±0)`
+		// Minimum execution time: 75_410_000 picoseconds.
+		Weight::from_parts(77_902_118, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 29_306
+			.saturating_add(Weight::from_parts(47_356_842, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(9))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3424).saturating_mul(x.into()))
+	}
 }
@@ -24,11 +24,16 @@ use cumulus_primitives_core::ParaId;
 use frame_support::{
 	ensure, parameter_types,
 	traits::{ConstU32, Contains, ContainsPair, Everything, Nothing, ProcessMessageError},
-	weights::Weight,
+	weights::{
+		constants::{WEIGHT_PROOF_SIZE_PER_MB, WEIGHT_REF_TIME_PER_SECOND},
+		Weight,
+	},
 };
+use pallet_funding::{AcceptedFundingAsset, FundingAssetInfo, FundingAssetLocations, FundingAssets, PriceKind};
 use pallet_xcm::XcmPassthrough;
 #[cfg(feature = "runtime-benchmarks")]
 use polimec_common::DummyXcmSender;
+use polimec_common::USD_UNIT;
 use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery;
 use sp_runtime::traits::MaybeEquivalence;
@@ -36,29 +41,19 @@ use xcm::v4::prelude::*;
 use xcm_builder::{
 	AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses, AllowSubscriptionsFrom,
 	AllowTopLevelPaidExecutionFrom, CreateMatcher, DenyReserveTransferToRelayChain, DenyThenTry, EnsureXcmOrigin,
-	FixedRateOfFungible, FixedWeightBounds, FrameTransactionalProcessor, FungibleAdapter, FungiblesAdapter, IsConcrete,
-	MatchXcm, MatchedConvertedConcreteId, MintLocation, NoChecking, ParentIsPreset, RelayChainAsNative,
-	SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32,
-	SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId, UsingComponents, WithComputedOrigin,
+	FixedWeightBounds, FrameTransactionalProcessor, FungibleAdapter, FungiblesAdapter, IsConcrete, MatchXcm,
+	MatchedConvertedConcreteId, MintLocation, NoChecking, ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative,
+	SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation,
+	TakeRevenue, TakeWeightCredit, TrailingSetTopicAsId, UsingComponents, WithComputedOrigin,
 };
 use xcm_executor::{
-	traits::{JustTry, Properties, ShouldExecute},
-	XcmExecutor,
+	traits::{JustTry, Properties, ShouldExecute, WeightTrader},
+	AssetsInHolding, XcmExecutor,
 };
 
-// DOT from Polkadot Asset Hub
-const DOT_PER_SECOND_EXECUTION: u128 = 0_2_000_000_000; // 0.2 DOT per second of execution time
-const DOT_PER_MB_PROOF: u128 = 0_2_000_000_000; // 0.0000001 DOT per Megabyte of proof size
-
-// USDT from Polkadot Asset Hub
-const USDT_PER_SECOND_EXECUTION: u128 = 1_000_000; // 1 USDT per second of execution time
-const USDT_PER_MB_PROOF: u128 = 1_000_000; // 1 USDT per Megabyte of proof size
-const USDT_JUNCTION: &[Junction] = &[Parachain(1000), PalletInstance(50), GeneralIndex(1984)];
-
-// USDC from Polkadot Asset Hub
-const USDC_PER_SECOND_EXECUTION: u128 = 1_000_000; // 1 USDC per second of execution time
-const USDC_PER_MB_PROOF: u128 = 1_000_000; // 1 USDC per Megabyte of proof size
-const USDC_JUNCTION: &[Junction] = &[Parachain(1000), PalletInstance(50), GeneralIndex(1337)];
+// Execution paid with a funding asset is priced in USD and converted with the oracle price of the asset.
+const USD_PER_SECOND_EXECUTION: u128 = USD_UNIT; // 1 USD per second of execution time
+const USD_PER_MB_PROOF: u128 = USD_UNIT; // 1 USD per Megabyte of proof size
 
 parameter_types! {
 	pub const RelayLocation: Location = Location::parent();
@@ -78,9 +73,12 @@ parameter_types! {
 	pub DotLocation: Location = RelayLocation::get();
 	pub UsdtLocation: Location = Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)]);
 	pub UsdcLocation: Location =  Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1337)]);
-	pub DotTraderParams: (AssetId, u128, u128) = (DotLocation::get().into(), DOT_PER_SECOND_EXECUTION, DOT_PER_MB_PROOF);
-	pub UsdtTraderParams: (AssetId, u128, u128) = (UsdtLocation::get().into(), USDT_PER_SECOND_EXECUTION, USDT_PER_MB_PROOF);
-	pub UsdcTraderParams: (AssetId, u128, u128) = (UsdcLocation::get().into(), USDC_PER_SECOND_EXECUTION, USDC_PER_MB_PROOF);
+	/// The funding assets registered by the storage migration that introduced the funding asset registry.
+	pub InitialFundingAssets: Vec<(AcceptedFundingAsset, FundingAssetInfo)> = sp_std::vec![
+		(AcceptedFundingAsset::USDT, FundingAssetInfo { location: UsdtLocation::get(), decimals: 6, oracle_key: *b"USDT\0\0\0\0", enabled: true }),
+		(AcceptedFundingAsset::USDC, FundingAssetInfo { location: UsdcLocation::get(), decimals: 6, oracle_key: *b"USDC\0\0\0\0", enabled: true }),
+		(AcceptedFundingAsset::DOT, FundingAssetInfo { location: DotLocation::get(), decimals: 10, oracle_key: *b"DOT\0\0\0\0\0", enabled: true }),
+	];
 }
 
 /// Type for specifying how a `Location` can be converted into an `AccountId`. This is used
@@ -110,36 +108,27 @@ pub type FungibleTransactor = FungibleAdapter<
 >;
 
 // The `AssetIdPalletAssets` ids that are supported by this chain.
-// Currently, we only support DOT (10), USDT (1984) and USDC (1337).
+// These are the funding assets registered in `pallet_funding`. Disabled assets are still supported, so that
+// participants can move them out of the chain.
 pub struct SupportedAssets;
 impl frame_support::traits::Contains<Location> for SupportedAssets {
 	fn contains(l: &Location) -> bool {
-		matches!(l.unpack(), (1, []) | (1, USDC_JUNCTION) | (1, USDT_JUNCTION))
+		FundingAssetLocations::<Runtime>::contains_key(l)
 	}
 }
 
 impl MaybeEquivalence<Location, AssetIdPalletAssets> for SupportedAssets {
 	fn convert(asset_id: &Location) -> Option<AssetIdPalletAssets> {
-		match asset_id.unpack() {
-			(1, []) => Some(10),
-			(1, USDC_JUNCTION) => Some(1337),
-			(1, USDT_JUNCTION) => Some(1984),
-			_ => None,
-		}
+		FundingAssetLocations::<Runtime>::get(asset_id).map(|asset| asset.id())
 	}
 
 	fn convert_back(asset_id: &AssetIdPalletAssets) -> Option<Location> {
-		match asset_id {
-			10 => Some(DotLocation::get()),
-			1337 => Some(UsdcLocation::get()),
-			1984 => Some(UsdtLocation::get()),
-			_ => None,
-		}
+		FundingAssets::<Runtime>::get(AcceptedFundingAsset::from_id(*asset_id)).map(|info| info.location)
 	}
 }
 
 /// Foreign assets adapter for supporting assets from other chains. Currently the only
-/// supported assets are DOT, USDT, and USDC.
+/// supported assets are the registered funding assets.
 pub type ForeignAssetsAdapter = FungiblesAdapter<
 	// Use this fungibles implementation:
 	ForeignAssets,
@@ -150,7 +139,7 @@ pub type ForeignAssetsAdapter = FungiblesAdapter<
 	// Our chain's account ID type (we can't get away without mentioning it explicitly):
 	AccountId,
 	// We do not allow teleportation of foreign assets. We only allow the reserve-based
-	// transfer of USDT, USDC and DOT.
+	// transfer of the registered funding assets.
 	NoChecking,
 	// The account to use for tracking teleports.
 	CheckAccount,
@@ -251,13 +240,13 @@ pub type Barrier = TrailingSetTopicAsId<
 >;
 
 /// Trusted reserve locations for reserve assets. For now we only trust the AssetHub parachain
-/// for the following assets: DOT, USDT and USDC.
+/// for the registered funding assets.
 pub type Reserves = AssetHubAssetsAsReserve;
 
 /// Means for transacting assets on this chain.
 /// FungibleTransactor is a FungibleAdapter that allows for transacting PLMC.
 /// ForeignAssetsAdapter is a FungiblesAdapter that allows for transacting foreign assets.
-/// Currently we only support DOT, USDT and USDC.
+/// Currently we only support the registered funding assets.
 pub type AssetTransactors = (FungibleTransactor, ForeignAssetsAdapter);
 
 pub type TakeRevenueToTreasury =
@@ -298,9 +287,7 @@ impl xcm_executor::Config for XcmConfig {
 	type Trader = (
 		// TODO: `WeightToFee` has to be carefully considered. For now use default
 		UsingComponents<WeightToFee, HereLocation, AccountId, Balances, ToTreasury>,
-		FixedRateOfFungible<DotTraderParams, TakeRevenueToTreasury>,
-		FixedRateOfFungible<UsdcTraderParams, TakeRevenueToTreasury>,
-		FixedRateOfFungible<UsdtTraderParams, TakeRevenueToTreasury>,
+		FundingAssetTrader<TakeRevenueToTreasury>,
 	);
 	type TransactionalProcessor = FrameTransactionalProcessor;
 	type UniversalAliases = Nothing;
@@ -471,6 +458,77 @@ impl<T: Contains<Location>> ShouldExecute for AllowHrmpNotifications<T> {
 	}
 }
 
+/// Buys execution with any registered funding asset. The weight is priced in USD and converted to the
+/// asset with its oracle price and registered decimals, so new funding assets need no trader of their own.
+pub struct FundingAssetTrader<R: TakeRevenue> {
+	weight: Weight,
+	asset: Option<AssetId>,
+	amount: u128,
+	_phantom: PhantomData<R>,
+}
+
+impl<R: TakeRevenue> FundingAssetTrader<R> {
+	fn fee(asset: &AssetId, weight: Weight) -> Option<u128> {
+		let funding_asset = FundingAssetLocations::<Runtime>::get(&asset.0)?;
+		let usd_fee = USD_PER_SECOND_EXECUTION.saturating_mul(weight.ref_time() as u128) /
+			(WEIGHT_REF_TIME_PER_SECOND as u128) +
+			USD_PER_MB_PROOF.saturating_mul(weight.proof_size() as u128) / (WEIGHT_PROOF_SIZE_PER_MB as u128);
+		Funding::calculate_funding_asset_amount(usd_fee, funding_asset, PriceKind::Spot).ok()
+	}
+}
+
+impl<R: TakeRevenue> WeightTrader for FundingAssetTrader<R> {
+	fn new() -> Self {
+		Self { weight: Weight::zero(), asset: None, amount: 0, _phantom: PhantomData }
+	}
+
+	fn buy_weight(
+		&mut self,
+		weight: Weight,
+		payment: AssetsInHolding,
+		_context: &XcmContext,
+	) -> Result<AssetsInHolding, XcmError> {
+		// Once an asset was used to buy weight, the same asset must be used for any further purchase.
+		let id = match &self.asset {
+			Some(id) => id.clone(),
+			None => payment
+				.fungible
+				.keys()
+				.find(|id| FundingAssetLocations::<Runtime>::contains_key(&id.0))
+				.cloned()
+				.ok_or(XcmError::AssetNotFound)?,
+		};
+		let amount = Self::fee(&id, weight).ok_or(XcmError::TooExpensive)?;
+		if amount == 0 {
+			return Ok(payment);
+		}
+		let unused = payment.checked_sub((id.clone(), amount).into()).map_err(|_| XcmError::TooExpensive)?;
+		self.weight = self.weight.saturating_add(weight);
+		self.amount = self.amount.saturating_add(amount);
+		self.asset = Some(id);
+		Ok(unused)
+	}
+
+	fn refund_weight(&mut self, weight: Weight, _context: &XcmContext) -> Option<Asset> {
+		let id = self.asset.clone()?;
+		let weight = weight.min(self.weight);
+		let amount = Self::fee(&id, weight)?.min(self.amount);
+		self.weight = self.weight.saturating_sub(weight);
+		self.amount = self.amount.saturating_sub(amount);
+		(amount > 0).then(|| (id, amount).into())
+	}
+}
+
+impl<R: TakeRevenue> Drop for FundingAssetTrader<R> {
+	fn drop(&mut self) {
+		if let Some(id) = self.asset.take() {
+			if self.amount > 0 {
+				R::take_revenue((id, self.amount).into());
+			}
+		}
+	}
+}
+
 impl cumulus_pallet_xcmp_queue::migration::v5::V5Config for Runtime {
 	// This must be the same as the `ChannelInfo` from the `Config`:
 	type ChannelList = ParachainSystem;
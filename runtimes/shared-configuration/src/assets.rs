@@ -19,12 +19,16 @@ use crate::{
 	Balance,
 };
 use core::marker::PhantomData;
-use frame_support::{parameter_types, PalletId};
-use orml_traits::DataProvider;
-use pallet_funding::traits::ProvideAssetPrice;
-use parachains_common::DAYS;
+use frame_support::{parameter_types, traits::Time, PalletId};
+use orml_oracle::TimestampedValue;
+use orml_traits::{parameter_type_with_key, CombineData, DataProviderExtended, GetByKey};
+use pallet_funding::{traits::ProvideAssetPrice, AcceptedFundingAsset, PLMC_FOREIGN_ID};
+use pallet_oracle_ocw::traits::ProvideTwap;
+use parachains_common::{DAYS, HOURS};
 use polimec_common::credentials::Cid;
-use sp_arithmetic::FixedPointNumber;
+use sp_arithmetic::{traits::Saturating, FixedPointNumber, Percent};
+use sp_runtime::transaction_validity::TransactionPriority;
+use sp_std::vec::Vec;
 
 parameter_types! {
 	pub const AssetDeposit: Balance = 10  * PLMC;
@@ -37,18 +41,105 @@ parameter_types! {
 	pub const ZeroDeposit: Balance = 0;
 }
 
-pub struct OraclePriceProvider<AssetId, Price, Oracle>(PhantomData<(AssetId, Price, Oracle)>);
+const MINUTE_IN_MILLIS: u64 = 60 * 1000;
 
-impl<AssetId, Price, Oracle> ProvideAssetPrice for OraclePriceProvider<AssetId, Price, Oracle>
+// Oracle prices older than this are not used to value new participations.
+parameter_type_with_key! {
+	pub MaxPriceAge: |asset_id: u32| -> u64 {
+		match *asset_id {
+			PLMC_FOREIGN_ID => 30 * MINUTE_IN_MILLIS,
+			_ => 60 * MINUTE_IN_MILLIS,
+		}
+	};
+}
+
+// How far a newly combined oracle price can move away from the previous one in a single update.
+parameter_type_with_key! {
+	pub MaxPriceDeviation: |asset_id: u32| -> Percent {
+		const USDT: u32 = AcceptedFundingAsset::USDT.id();
+		const USDC: u32 = AcceptedFundingAsset::USDC.id();
+		const DOT: u32 = AcceptedFundingAsset::DOT.id();
+		match *asset_id {
+			USDT | USDC => Percent::from_percent(5),
+			DOT => Percent::from_percent(20),
+			PLMC_FOREIGN_ID => Percent::from_percent(30),
+			_ => Percent::from_percent(10),
+		}
+	};
+}
+
+pub struct OraclePriceProvider<AssetId, Price, Oracle, Clock, MaxAge, Twap>(
+	PhantomData<(AssetId, Price, Oracle, Clock, MaxAge, Twap)>,
+);
+
+impl<AssetId, Price, Oracle, Clock, MaxAge, Twap> ProvideAssetPrice
+	for OraclePriceProvider<AssetId, Price, Oracle, Clock, MaxAge, Twap>
 where
 	Price: FixedPointNumber,
-	Oracle: DataProvider<AssetId, Price>,
+	Oracle: DataProviderExtended<AssetId, TimestampedValue<Price, Clock::Moment>>,
+	Clock: Time,
+	MaxAge: GetByKey<AssetId, Clock::Moment>,
+	Twap: ProvideTwap<AssetId, Price>,
 {
 	type AssetId = AssetId;
+	type Moment = Clock::Moment;
 	type Price = Price;
 
-	fn get_price(asset_id: AssetId) -> Option<Price> {
-		Oracle::get(&asset_id)
+	fn get_timestamped_price(asset_id: AssetId) -> Option<(Price, Clock::Moment)> {
+		Oracle::get_no_op(&asset_id).map(|timestamped| (timestamped.value, timestamped.timestamp))
+	}
+
+	fn get_twap(asset_id: AssetId) -> Option<Price> {
+		Twap::twap(&asset_id)
+	}
+
+	fn now() -> Clock::Moment {
+		Clock::now()
+	}
+
+	fn max_price_age(asset_id: &AssetId) -> Clock::Moment {
+		MaxAge::get(asset_id)
+	}
+}
+
+/// Wraps the oracle [`CombineData`] implementation, and keeps the previous price when the newly combined one deviates
+/// from it by more than `MaxDeviation`. A previous price older than `MaxAge` is not trusted as a reference anymore, so
+/// the next combined price is accepted regardless of the deviation. Until then, the asset's price becomes stale.
+pub struct DeviationGuardedCombineData<Inner, Clock, MaxAge, MaxDeviation>(
+	PhantomData<(Inner, Clock, MaxAge, MaxDeviation)>,
+);
+
+impl<Key, Price, Inner, Clock, MaxAge, MaxDeviation> CombineData<Key, TimestampedValue<Price, Clock::Moment>>
+	for DeviationGuardedCombineData<Inner, Clock, MaxAge, MaxDeviation>
+where
+	Price: FixedPointNumber,
+	Inner: CombineData<Key, TimestampedValue<Price, Clock::Moment>>,
+	Clock: Time,
+	MaxAge: GetByKey<Key, Clock::Moment>,
+	MaxDeviation: GetByKey<Key, Percent>,
+{
+	fn combine_data(
+		key: &Key,
+		values: Vec<TimestampedValue<Price, Clock::Moment>>,
+		prev_value: Option<TimestampedValue<Price, Clock::Moment>>,
+	) -> Option<TimestampedValue<Price, Clock::Moment>> {
+		let combined = Inner::combine_data(key, values, prev_value.clone())?;
+		let Some(prev_value) = prev_value else { return Some(combined) };
+		if Clock::now().saturating_sub(prev_value.timestamp) > MaxAge::get(key) {
+			return Some(combined);
+		}
+
+		let deviation = if combined.value > prev_value.value {
+			combined.value.saturating_sub(prev_value.value)
+		} else {
+			prev_value.value.saturating_sub(combined.value)
+		};
+		let max_deviation =
+			prev_value.value.saturating_mul(Price::saturating_from_rational(MaxDeviation::get(key).deconstruct(), 100));
+		if deviation > max_deviation {
+			return Some(prev_value);
+		}
+		Some(combined)
 	}
 }
 
@@ -60,4 +151,8 @@ parameter_types! {
 	pub const DispenserLockPeriod: u32 = DAYS * 365 * 2; // 2 years
 	pub const DispenserVestPeriod: u32 = DAYS * 365 * 2; // 2 years
 	pub DispenserWhitelistedPolicy: Cid = (*b"QmVdGSxuWcamYEmYJjR3gvZucqQpp4Jnf6tqJABHwKZVo3").to_vec().try_into().unwrap();
+	pub const MaxDispensesPerBlock: u32 = 20;
+	pub const MaxDispensesPerIssuer: u32 = 1_000;
+	pub const DispenseRateWindow: u32 = HOURS;
+	pub const DispensePriority: TransactionPriority = 1_000;
 }
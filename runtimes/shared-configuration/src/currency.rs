@@ -15,11 +15,13 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::Balance;
-use frame_support::parameter_types;
-use pallet_funding::AcceptedFundingAsset;
+use core::marker::PhantomData;
+use frame_support::{parameter_types, traits::Get};
+use pallet_funding::{FundingAssets, PLMC_FOREIGN_ID};
 use pallet_oracle_ocw::types::AssetName;
 use parachains_common::AssetIdForTrustBackedAssets as AssetId;
 use sp_runtime::{traits::Convert, FixedU128};
+use sp_std::{vec, vec::Vec};
 
 /// One PLMC
 pub const PLMC: Balance = 10u128.pow(10);
@@ -81,14 +83,26 @@ pub type Price = FixedU128;
 
 pub type Moment = u64;
 
-pub struct AssetPriceConverter;
-impl Convert<(AssetName, FixedU128), (AssetId, Price)> for AssetPriceConverter {
-	fn convert((asset, price): (AssetName, FixedU128)) -> (AssetId, Price) {
-		match asset {
-			AssetName::DOT => (AcceptedFundingAsset::DOT.id(), price),
-			AssetName::USDC => (AcceptedFundingAsset::USDC.id(), price),
-			AssetName::USDT => (AcceptedFundingAsset::USDT.id(), price),
-			AssetName::PLMC => (pallet_funding::PLMC_FOREIGN_ID, price),
+/// Maps the asset names of the oracle offchain worker to oracle keys. Funding assets are found
+/// through the oracle key they were registered with.
+pub struct AssetPriceConverter<T>(PhantomData<T>);
+impl<T: pallet_funding::Config> Convert<(AssetName, FixedU128), Option<(AssetId, Price)>> for AssetPriceConverter<T> {
+	fn convert((asset, price): (AssetName, FixedU128)) -> Option<(AssetId, Price)> {
+		if asset == AssetName::PLMC {
+			return Some((PLMC_FOREIGN_ID, price));
 		}
+		pallet_funding::Pallet::<T>::funding_asset_by_oracle_key(asset.0)
+			.map(|funding_asset| (funding_asset.id(), price))
+	}
+}
+
+/// PLMC and every registered funding asset. Disabled funding assets are still priced, as existing
+/// participations in them have to be settled.
+pub struct TrackedAssetNames<T>(PhantomData<T>);
+impl<T: pallet_funding::Config> Get<Vec<AssetName>> for TrackedAssetNames<T> {
+	fn get() -> Vec<AssetName> {
+		let mut names = vec![AssetName::PLMC];
+		names.extend(FundingAssets::<T>::iter_values().map(|info| AssetName(info.oracle_key)));
+		names
 	}
 }
@@ -16,7 +16,7 @@
 
 use crate::{Balance, BlockNumber};
 use frame_support::{parameter_types, PalletId};
-use pallet_funding::types::AcceptedFundingAsset;
+use pallet_funding::types::{AcceptedFundingAsset, FundingOutcomeDecision};
 use parachains_common::AssetIdForTrustBackedAssets;
 use polimec_common::USD_UNIT;
 use sp_arithmetic::{FixedU128, Percent};
@@ -44,6 +44,20 @@ pub const COMMUNITY_ROUND_DURATION: BlockNumber = 5 * crate::MINUTES;
 #[cfg(not(any(feature = "fast-mode", feature = "instant-mode")))]
 pub const COMMUNITY_ROUND_DURATION: BlockNumber = 5 * crate::DAYS;
 
+#[cfg(feature = "instant-mode")]
+pub const MANUAL_ACCEPTANCE_DURATION: BlockNumber = 3;
+#[cfg(feature = "fast-mode")]
+pub const MANUAL_ACCEPTANCE_DURATION: BlockNumber = 3 * crate::MINUTES;
+#[cfg(not(any(feature = "fast-mode", feature = "instant-mode")))]
+pub const MANUAL_ACCEPTANCE_DURATION: BlockNumber = 3 * crate::DAYS;
+
+#[cfg(feature = "instant-mode")]
+pub const MILESTONE_VOTING_DURATION: BlockNumber = 4;
+#[cfg(feature = "fast-mode")]
+pub const MILESTONE_VOTING_DURATION: BlockNumber = 4 * crate::MINUTES;
+#[cfg(not(any(feature = "fast-mode", feature = "instant-mode")))]
+pub const MILESTONE_VOTING_DURATION: BlockNumber = 7 * crate::DAYS;
+
 #[cfg(feature = "instant-mode")]
 pub const REMAINDER_ROUND_DURATION: BlockNumber = 2;
 #[cfg(feature = "fast-mode")]
@@ -58,6 +72,8 @@ parameter_types! {
 	pub const AuctionRoundDuration: BlockNumber = AUCTION_ROUND_DURATION;
 	pub const CommunityRoundDuration: BlockNumber = COMMUNITY_ROUND_DURATION;
 	pub const RemainderRoundDuration: BlockNumber = REMAINDER_ROUND_DURATION;
+	pub const ManualAcceptanceDuration: BlockNumber = MANUAL_ACCEPTANCE_DURATION;
+	pub const MilestoneVotingDuration: BlockNumber = MILESTONE_VOTING_DURATION;
 	pub const FundingPalletId: PalletId = PalletId(*b"plmc/fun");
 	pub PriceMap: BTreeMap<AssetIdForTrustBackedAssets, FixedU128> = BTreeMap::from_iter(vec![
 		(AcceptedFundingAsset::DOT.id(), FixedU128::from_rational(69, 1)), // DOT
@@ -73,4 +89,6 @@ parameter_types! {
 	pub EarlyEvaluationThreshold: Percent = Percent::from_percent(10);
 	pub EvaluatorSlash: Percent = Percent::from_percent(20);
 	pub FundingSuccessThreshold: Perquintill = Perquintill::from_percent(33);
+	pub FundingAutoSuccessThreshold: Perquintill = Perquintill::from_percent(90);
+	pub const DefaultFundingDecision: FundingOutcomeDecision = FundingOutcomeDecision::AcceptFunding;
 }
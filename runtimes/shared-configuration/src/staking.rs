@@ -28,6 +28,7 @@ parameter_types! {
 	pub const RevokeDelegationDelay: u32 = WEEK_IN_ROUNDS;
 	pub const DelegationBondLessDelay: u32 = WEEK_IN_ROUNDS;
 	pub const RewardPaymentDelay: u32 = 2;
+	pub const RedelegationCooldown: u32 = WEEK_IN_ROUNDS;
 	pub const MinSelectedCandidates: u32 = 5;
 	pub const MaxTopDelegationsPerCandidate: u32 = 300;
 	pub const MaxBottomDelegationsPerCandidate: u32 = 50;